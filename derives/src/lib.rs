@@ -2,395 +2,1453 @@ extern crate proc_macro;
 
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::parse::Parser;
+use syn::parse::{Parse, ParseStream, Parser};
 use syn::Ident;
 use syn::{punctuated::Punctuated, Token};
 
-/// Derives useful functions for aged objects
+/// Derives `Aged` trait for the objects that record their fetch time
 ///
-/// # Panics
-/// if the type in question is not valid
-#[proc_macro_derive(Aged)]
+/// Trait is expected to be found at `super::Aged`. Fetch time is taken from `fetched_at` field by default,
+/// other field can be selected with `#[aged(field = "...")]` attribute.
+/// Inherent `get_age` method is generated as well, so that trait import is not required.
+///
+/// ```
+/// mod object {
+///     pub trait Aged {
+///         fn fetched_at(&self) -> time::OffsetDateTime;
+///         fn get_age(&self) -> time::Duration {
+///             time::OffsetDateTime::now_utc() - self.fetched_at()
+///         }
+///     }
+///
+///     pub mod cached {
+///         #[derive(derives::Aged)]
+///         #[aged(field = "retrieved")]
+///         pub struct Cached {
+///             pub retrieved: time::OffsetDateTime,
+///         }
+///     }
+/// }
+///
+/// let cached = object::cached::Cached { retrieved: time::OffsetDateTime::now_utc() };
+/// assert!(cached.get_age() < time::Duration::SECOND);
+/// ```
+///
+/// Field should exist:
+/// ```compile_fail
+/// # mod object {
+/// #     pub trait Aged {
+/// #         fn fetched_at(&self) -> time::OffsetDateTime;
+/// #         fn get_age(&self) -> time::Duration {
+/// #             time::OffsetDateTime::now_utc() - self.fetched_at()
+/// #         }
+/// #     }
+/// pub mod cached {
+///     #[derive(derives::Aged)]
+///     #[aged(field = "retrieved")]
+///     pub struct Cached {
+///         pub fetched_at: time::OffsetDateTime,
+///     }
+/// }
+/// # }
+/// ```
+///
+/// And it should be an `OffsetDateTime`:
+/// ```compile_fail
+/// # mod object {
+/// #     pub trait Aged {
+/// #         fn fetched_at(&self) -> time::OffsetDateTime;
+/// #         fn get_age(&self) -> time::Duration {
+/// #             time::OffsetDateTime::now_utc() - self.fetched_at()
+/// #         }
+/// #     }
+/// pub mod cached {
+///     #[derive(derives::Aged)]
+///     pub struct Cached {
+///         pub fetched_at: u64,
+///     }
+/// }
+/// # }
+/// ```
+///
+/// And it can't be an enum:
+/// ```compile_fail
+/// # mod object {
+/// #     pub trait Aged {
+/// #         fn fetched_at(&self) -> time::OffsetDateTime;
+/// #         fn get_age(&self) -> time::Duration {
+/// #             time::OffsetDateTime::now_utc() - self.fetched_at()
+/// #         }
+/// #     }
+/// pub mod cached {
+///     #[derive(derives::Aged)]
+///     pub enum Cached {
+///         Fresh { fetched_at: time::OffsetDateTime },
+///     }
+/// }
+/// # }
+/// ```
+#[proc_macro_derive(Aged, attributes(aged))]
 pub fn aged_derive(input: TokenStream) -> TokenStream {
     // Construct a representation of Rust code as a syntax tree
     // that we can manipulate
-    let ast = syn::parse(input).unwrap();
+    let ast = syn::parse_macro_input!(input as syn::DeriveInput);
 
     // Build the trait implementation
     impl_aged_macro(&ast)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
 }
 
-fn impl_aged_macro(ast: &syn::DeriveInput) -> TokenStream {
+fn impl_aged_macro(ast: &syn::DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
     let name = &ast.ident;
-    quote! {
+    let mut field_name = Ident::new("fetched_at", proc_macro2::Span::call_site());
+    for attr in ast.attrs.iter().filter(|attr| attr.path().is_ident("aged")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("field") {
+                let value: syn::LitStr = meta.value()?.parse()?;
+                field_name = value.parse()?;
+                Ok(())
+            } else {
+                Err(meta.error("Unsupported aged attribute, expected `field`"))
+            }
+        })?;
+    }
+
+    let syn::Data::Struct(syn::DataStruct { fields, .. }) = &ast.data else {
+        return Err(syn::Error::new_spanned(
+            name,
+            "Aged can only be derived for structs",
+        ));
+    };
+    let field = fields
+        .iter()
+        .find(|field| field.ident.as_ref() == Some(&field_name))
+        .ok_or_else(|| {
+            syn::Error::new_spanned(
+                name,
+                format!("Aged requires a `{field_name}: OffsetDateTime` field"),
+            )
+        })?;
+    let is_date_time = matches!(
+        &field.ty,
+        syn::Type::Path(path) if path.path.segments.last().is_some_and(|segment| segment.ident == "OffsetDateTime")
+    );
+    if !is_date_time {
+        return Err(syn::Error::new_spanned(
+            name,
+            format!("Aged requires `{field_name}` field to be an OffsetDateTime"),
+        ));
+    }
+
+    Ok(quote! {
+      impl super::Aged for #name {
+        fn fetched_at(&self) -> ::time::OffsetDateTime {
+          self.#field_name
+        }
+      }
+
       impl #name {
         /// Provides a way to know a `time::Duration` since the object was fetched
         ///
         /// May be used to, for example, re-fetch it once it gets too old, and thus probably outdated
         pub fn get_age(&self) -> ::time::Duration {
-          ::time::OffsetDateTime::now_utc() - self.fetched_at
+          super::Aged::get_age(self)
         }
       }
-    }
-    .into()
+    })
 }
 
 /// Derives useful functions for hex ids
 ///
-/// # Panics
-/// if the type in question is not a tuple with first element being a 12-byte array
+/// Along with hex display and byte accessors, implements `From<[u8; 12]>`, `FromStr` and `TryFrom<&str>`,
+/// latter two failing with `super::HexIdParseError`.
+///
+/// Id is (de)serialized as a 24-character lowercase hex string, same as the site does.
+///
+/// Type in question should be a tuple struct with a single 12-byte array:
+/// ```compile_fail
+/// # mod object {
+/// #     #[derive(Debug)]
+/// #     pub struct HexIdParseError;
+/// pub mod comment {
+///     #[derive(derives::HexId)]
+///     pub struct Id {
+///         bytes: [u8; 12],
+///     }
+/// }
+/// # }
+/// ```
 #[proc_macro_derive(HexId)]
 pub fn hex_derive(input: TokenStream) -> TokenStream {
     // Construct a representation of Rust code as a syntax tree
     // that we can manipulate
-    let ast: syn::DeriveInput = syn::parse(input).unwrap();
+    let ast = syn::parse_macro_input!(input as syn::DeriveInput);
 
     // Build the trait implementation
+    impl_hex_id_macro(&ast)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn impl_hex_id_macro(ast: &syn::DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
     let name = &ast.ident;
-    quote! {
+    let is_single_tuple = matches!(
+        &ast.data,
+        syn::Data::Struct(syn::DataStruct { fields: syn::Fields::Unnamed(fields), .. }) if fields.unnamed.len() == 1
+    );
+    if !is_single_tuple {
+        return Err(syn::Error::new_spanned(
+            name,
+            "HexId can only be derived for tuple structs with a single `[u8; 12]` field",
+        ));
+    }
+    Ok(quote! {
       impl #name {
+        /// Constructs id from it's raw bytes
+        pub const fn from_bytes(bytes: [u8; 12]) -> Self {
+            Self(bytes)
+        }
+
+        /// Raw bytes of the id
+        pub fn as_bytes(&self) -> &[u8; 12] {
+            &self.0
+        }
+
         /// Displays id as a hex string
         fn display_as_hex(&self) -> String {
-            let mut res = String::with_capacity(24);
-            for b in self.0 {
-                // if byte's value is less than 16, this string will be only 1 character long
-                let s = if b < 16u8 {
-                    format!("0{:x}", b)
-                } else {
-                    format!("{:x}", b)
+            // zero-padded, so that every byte takes exactly two characters
+            self.0.iter().map(|b| format!("{b:02x}")).collect()
+        }
+      }
+
+      impl ::core::convert::From<[u8; 12]> for #name {
+        fn from(bytes: [u8; 12]) -> Self {
+            Self::from_bytes(bytes)
+        }
+      }
+
+      impl ::core::str::FromStr for #name {
+        type Err = super::HexIdParseError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let length = s.chars().count();
+            if length != 24 {
+                return Err(super::HexIdParseError::WrongLength(length));
+            }
+            let mut bytes = [0u8; 12];
+            for (position, character) in s.chars().enumerate() {
+                let Some(digit) = character.to_digit(16) else {
+                    return Err(super::HexIdParseError::InvalidCharacter { character, position });
                 };
-                res.push_str(&s);
+                // first digit of the pair is the high one
+                bytes[position / 2] |= (digit as u8) << (4 * (1 - position % 2));
             }
-            res
+            Ok(Self(bytes))
+        }
+      }
+
+      impl ::core::convert::TryFrom<&str> for #name {
+        type Error = super::HexIdParseError;
+
+        fn try_from(s: &str) -> Result<Self, Self::Error> {
+            s.parse()
         }
       }
+
+      impl ::serde::Serialize for #name {
+        fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(&self.display_as_hex())
+        }
+      }
+
+      impl<'de> ::serde::Deserialize<'de> for #name {
+        fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            // owned string, so that ids could be taken from `serde_json::Value` and alike
+            let hex = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+            hex.parse().map_err(::serde::de::Error::custom)
+        }
+      }
+    })
+}
+
+/// Input of the [`id_hex!`] macro: an optional id type, followed by a hex string literal
+struct IdHexInput {
+    id_type: Option<syn::Path>,
+    hex: syn::LitStr,
+}
+
+impl Parse for IdHexInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let id_type = if input.peek(syn::LitStr) {
+            None
+        } else {
+            let id_type = input.parse()?;
+            input.parse::<Token![,]>()?;
+            Some(id_type)
+        };
+        let hex = input.parse()?;
+        input.parse::<Option<Token![,]>>()?;
+        Ok(Self { id_type, hex })
+    }
+}
+
+/// Id literal, checked at compile time
+///
+/// Takes a 24-character hex string, same as the site shows ids. With an id type in front, expands to a `const`-friendly
+/// `from_bytes` call on that type, otherwise expands to the raw `[u8; 12]` array.
+///
+/// ```
+/// # mod object {
+/// #     #[derive(Debug, PartialEq)]
+/// #     pub struct UserId([u8; 12]);
+/// #     impl UserId {
+/// #         pub const fn from_bytes(bytes: [u8; 12]) -> Self {
+/// #             Self(bytes)
+/// #         }
+/// #     }
+/// # }
+/// use object::UserId;
+///
+/// const ID: UserId = derives::id_hex!(UserId, "643af9fc1272bd9066a1ffdb");
+/// let bytes = derives::id_hex!("643AF9FC1272BD9066A1FFDB");
+///
+/// assert_eq!(ID, UserId::from_bytes(bytes));
+/// assert_eq!(bytes[0], 0x64);
+/// ```
+///
+/// Literal should be exactly 24 characters long:
+/// ```compile_fail
+/// let bytes = derives::id_hex!("643af9fc1272bd9066a1ff");
+/// ```
+///
+/// And should only contain hex digits:
+/// ```compile_fail
+/// let bytes = derives::id_hex!("643af9fc1272bd9066a1ffdg");
+/// ```
+#[proc_macro]
+pub fn id_hex(input: TokenStream) -> TokenStream {
+    let IdHexInput { id_type, hex } = syn::parse_macro_input!(input as IdHexInput);
+    let bytes = match parse_hex_id(&hex) {
+        Ok(bytes) => bytes,
+        Err(err) => return err.into_compile_error().into(),
+    };
+    let array = quote!([#(#bytes),*]);
+    match id_type {
+        Some(id_type) => quote!(#id_type::from_bytes(#array)),
+        None => array,
     }
     .into()
 }
 
+fn parse_hex_id(hex: &syn::LitStr) -> syn::Result<[u8; 12]> {
+    let value = hex.value();
+    let length = value.chars().count();
+    if length != 24 {
+        return Err(syn::Error::new(
+            hex.span(),
+            format!("Id should be 24 hex digits long, got {length} characters"),
+        ));
+    }
+    let mut bytes = [0u8; 12];
+    for (position, character) in value.chars().enumerate() {
+        let Some(digit) = character.to_digit(16) else {
+            return Err(syn::Error::new(
+                hex.span(),
+                format!(
+                    "Id should only contain hex digits, got {character:?} at position {position}"
+                ),
+            ));
+        };
+        // first digit of the pair is the high one
+        bytes[position / 2] |= (digit as u8) << (4 * (1 - position % 2));
+    }
+    Ok(bytes)
+}
+
+/// Input of the [`data_type!`] macro
+///
+/// Consists of an optional doc string and the type name, followed by field keywords, optionally followed by
+/// `; from` and a list of richer types this type can be downgraded from.
+struct DataTypeInput {
+    doc: Option<syn::LitStr>,
+    lenient: bool,
+    name: Ident,
+    fields: Vec<FieldInput>,
+    downgrades_from: Vec<Ident>,
+}
+
+/// Field keyword, optionally followed by a parenthesized list of modifiers
+struct FieldInput {
+    keyword: Ident,
+    modifiers: Vec<FieldModifier>,
+}
+
+/// Adjustment to the field generated for a keyword
+enum FieldModifier {
+    /// `optional`: wraps field's type into `Option` (unless it is one already), implies `default`
+    Optional,
+    /// `rename = "..."`: overrides field's `#[serde(rename)]`
+    Rename(syn::LitStr),
+    /// `default`: field may be missing from the JSON
+    Default,
+}
+
+impl Parse for FieldInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let keyword = input.parse()?;
+        let mut modifiers = Vec::new();
+        if input.peek(syn::token::Paren) {
+            let content;
+            syn::parenthesized!(content in input);
+            modifiers = Punctuated::<FieldModifier, Token![,]>::parse_terminated(&content)?
+                .into_iter()
+                .collect();
+        }
+        Ok(Self { keyword, modifiers })
+    }
+}
+
+impl Parse for FieldModifier {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let modifier: Ident = input.parse()?;
+        if modifier == "optional" {
+            Ok(Self::Optional)
+        } else if modifier == "default" {
+            Ok(Self::Default)
+        } else if modifier == "rename" {
+            input.parse::<Token![=]>()?;
+            Ok(Self::Rename(input.parse()?))
+        } else {
+            Err(syn::Error::new(
+                modifier.span(),
+                "Expected one of `optional`, `default`, `rename = \"...\"`",
+            ))
+        }
+    }
+}
+
+impl Parse for DataTypeInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut doc = None;
+        if input.peek(syn::LitStr) {
+            doc = Some(input.parse()?);
+            input.parse::<Token![,]>()?;
+        }
+        let mut lenient = false;
+        for attr in syn::Attribute::parse_outer(input)? {
+            if matches!(&attr.meta, syn::Meta::Path(path) if path.is_ident("lenient")) {
+                lenient = true;
+            } else {
+                return Err(syn::Error::new_spanned(attr, "Expected `#[lenient]`"));
+            }
+        }
+        let name = input.parse()?;
+        let mut fields = Vec::new();
+        while input.peek(Token![,]) {
+            input.parse::<Token![,]>()?;
+            if input.peek(Ident) {
+                fields.push(input.parse()?);
+            }
+        }
+        let mut downgrades_from = Vec::new();
+        if input.peek(Token![;]) {
+            input.parse::<Token![;]>()?;
+            let keyword: Ident = input.parse()?;
+            if keyword != "from" {
+                return Err(syn::Error::new(keyword.span(), "Expected `from`"));
+            }
+            downgrades_from = Punctuated::<Ident, Token![,]>::parse_terminated(input)?
+                .into_iter()
+                .collect();
+        }
+        Ok(Self {
+            doc,
+            lenient,
+            name,
+            fields,
+            downgrades_from,
+        })
+    }
+}
+
+/// Defines a data type, as returned by the site's API
+///
+/// Input is an optional doc string and the type name, followed by field keywords, optionally followed by
+/// `; from` and a list of richer types this type can be downgraded from. Generated fields are documented
+/// according to their keyword. Each keyword may be followed by a parenthesized list of modifiers:
+/// - `optional`: wraps field's type into `Option`, allowing it to be missing
+/// - `default`: allows field to be missing, taking it's `Default` value
+/// - `rename = "..."`: overrides field's name in JSON
+///
+/// Type name may be preceded by `#[lenient]`, so that the type collects unknown fields even without
+/// `lenient` feature. Intended for types the site changes often.
+///
+/// Generated struct is expected to be defined in a submodule of the module holding `Aged` trait.
+///
+/// ```
+/// mod object {
+///     pub trait Aged {
+///         fn fetched_at(&self) -> time::OffsetDateTime;
+///         fn get_age(&self) -> time::Duration {
+///             time::OffsetDateTime::now_utc() - self.fetched_at()
+///         }
+///     }
+///
+///     pub mod tag {
+///         pub type Title = String;
+///         pub type Description = String;
+///         pub type SeoTitle = String;
+///
+///         derives::data_type!(
+///             "A tag, as returned by the API",
+///             Full,
+///             title(rename = "name"),
+///             description(optional),
+///             seo_title(rename = "metaTitle", default)
+///         );
+///     }
+/// }
+///
+/// let tag: object::tag::Full = serde_json::from_str(r#"{"name": "Rust"}"#).unwrap();
+/// assert_eq!(tag.title(), "Rust");
+/// assert_eq!(tag.description(), &None);
+/// assert_eq!(tag.seo_title(), "");
+///
+/// let tag: object::tag::Full = serde_json::from_str(
+///     r#"{"name": "Rust", "description": "Blazingly fast", "metaTitle": "Rust language"}"#,
+/// )
+/// .unwrap();
+/// assert_eq!(tag.description().as_deref(), Some("Blazingly fast"));
+/// assert_eq!(tag.seo_title(), "Rust language");
+/// ```
+///
+/// Unknown modifiers are rejected:
+/// ```compile_fail
+/// # mod object {
+/// #     pub trait Aged {
+/// #         fn fetched_at(&self) -> time::OffsetDateTime;
+/// #         fn get_age(&self) -> time::Duration {
+/// #             time::OffsetDateTime::now_utc() - self.fetched_at()
+/// #         }
+/// #     }
+/// pub mod tag {
+///     pub type Title = String;
+///
+///     derives::data_type!(Full, title(required));
+/// }
+/// # }
+/// ```
+///
+/// Types listed after `; from` get `From` implementations, moving the shared fields (fetch time included)
+/// through `super::Project`. Each field should be present in the source type:
+/// ```compile_fail
+/// # mod object {
+/// #     pub trait Aged {
+/// #         fn fetched_at(&self) -> time::OffsetDateTime;
+/// #         fn get_age(&self) -> time::Duration {
+/// #             time::OffsetDateTime::now_utc() - self.fetched_at()
+/// #         }
+/// #     }
+/// #     pub trait Project<T> {
+/// #         fn project(self) -> T;
+/// #     }
+/// #     impl<T> Project<T> for T {
+/// #         fn project(self) -> T {
+/// #             self
+/// #         }
+/// #     }
+/// pub mod tag {
+///     pub type Title = String;
+///     pub type Description = String;
+///
+///     derives::data_type!(Full, title);
+///     derives::data_type!(Short, title, description; from Full);
+/// }
+/// # }
+/// ```
+///
+/// As are unknown field keywords:
+/// ```compile_fail
+/// # mod object {
+/// #     pub trait Aged {
+/// #         fn fetched_at(&self) -> time::OffsetDateTime;
+/// #         fn get_age(&self) -> time::Duration {
+/// #             time::OffsetDateTime::now_utc() - self.fetched_at()
+/// #         }
+/// #     }
+/// pub mod tag {
+///     pub type Title = String;
+///
+///     derives::data_type!(Full, title, subtitle);
+/// }
+/// # }
+/// ```
 #[proc_macro]
 pub fn data_type(input: TokenStream) -> TokenStream {
-    let parser = Punctuated::<syn::Ident, Token![,]>::parse_terminated;
-    let identifiers = parser
-        .parse(input)
-        .expect("Macro input should be a list of identifiers");
-    let mut identifiers = identifiers.into_iter();
-    let name = identifiers
-        .next()
-        .expect("At least one identifier is required");
-    let fields: proc_macro2::TokenStream = identifiers.map(data_field).collect();
-    quote! {
-        #[derive(Debug, ::serde::Deserialize, ::derive_getters::Getters, ::derives::Aged, Clone)]
-        #[cfg_attr(test, serde(deny_unknown_fields))]
+    let DataTypeInput {
+        doc,
+        lenient,
+        name,
+        fields,
+        downgrades_from,
+    } = match syn::parse(input) {
+        Ok(input) => input,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    let fields = fields
+        .into_iter()
+        .map(|FieldInput { keyword, modifiers }| {
+            let span = keyword.span();
+            let field = data_field(keyword, &name)?;
+            let field = if modifiers.is_empty() {
+                field
+            } else {
+                modify_fields(field, &modifiers)
+            };
+            // so that errors about the field (like it's absence in downgrade source) point to the keyword
+            Ok(respan(field, span))
+        })
+        .collect::<syn::Result<proc_macro2::TokenStream>>();
+    let fields = match fields {
+        Ok(fields) => fields,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    let doc = doc.iter();
+    // lenient types collect unknown fields regardless of the feature
+    let (deny_unknown_fields, lenient_cfg) = if lenient {
+        (quote!(), quote!())
+    } else {
+        (
+            quote!(#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]),
+            quote!(#[cfg(feature = "lenient")]),
+        )
+    };
+    let definition = quote! {
+        #[derive(Debug, ::serde::Deserialize, ::serde::Serialize, ::derive_getters::Getters, ::derives::Aged, Clone)]
+        #deny_unknown_fields
+        #(#[doc = #doc])*
         pub struct #name {
             #fields
             #[serde(skip, default = "::time::OffsetDateTime::now_utc")]
             #[getter(skip)]
             fetched_at: ::time::OffsetDateTime,
+            #[cfg(feature = "raw-json")]
+            #[serde(skip)]
+            #[getter(skip)]
+            raw: Option<::std::sync::Arc<str>>,
+            #lenient_cfg
+            #[serde(flatten)]
+            #[getter(skip)]
+            extra: ::std::collections::BTreeMap<String, ::serde_json::Value>,
+        }
+    };
+    let lenient = quote! {
+        #lenient_cfg
+        impl #name {
+            /// Fields that were present in the JSON, but are not known to this crate
+            ///
+            /// Probably, site's API has changed, so you may want to log these.
+            pub fn unknown_fields(&self) -> &::std::collections::BTreeMap<String, ::serde_json::Value> {
+                &self.extra
+            }
         }
+    };
+    let raw_json = quote! {
+        #[cfg(feature = "raw-json")]
+        impl #name {
+            /// JSON this object was parsed from, if it was fetched by a client
+            ///
+            /// Objects nested into other objects do not have it.
+            pub fn raw_json(&self) -> Option<&str> {
+                self.raw.as_deref()
+            }
+        }
+
+        #[cfg(feature = "raw-json")]
+        impl super::AttachRaw for #name {
+            fn attach_raw(&mut self, raw: &str) {
+                self.raw = Some(raw.into());
+            }
+        }
+    };
+    // parse generated struct back, to know the resulting field list
+    let parsed: syn::DeriveInput =
+        syn::parse2(definition.clone()).expect("Generated struct should be valid");
+    let syn::Data::Struct(syn::DataStruct { fields, .. }) = &parsed.data else {
+        unreachable!("data_type always generates a struct")
+    };
+    let partial_eq = data_partial_eq(&name, fields);
+    let accessors = data_accessors(&name, fields);
+    let test_constructor = data_test_constructor(&name, fields);
+    let introspection = data_introspection(&name, fields);
+    let downgrades = downgrades_from
+        .iter()
+        .map(|source| data_downgrade(&name, source, fields));
+    quote! {
+        #definition
+        #raw_json
+        #lenient
+        #partial_eq
+        #accessors
+        #test_constructor
+        #introspection
+        #(#downgrades)*
     }
     .into()
 }
 
+/// Implements `HasId`, `HasSlug`, `HasCreatedAt` and `HasTags` traits, if the corresponding fields are present
+///
+/// Optional fields are not considered. Also adds convenience getters for optional ids and canonical urls,
+/// and schema version recording.
+fn data_accessors(name: &Ident, fields: &syn::Fields) -> proc_macro2::TokenStream {
+    let field_type = |field_name: &str| {
+        fields
+            .iter()
+            .find(|field| {
+                field
+                    .ident
+                    .as_ref()
+                    .is_some_and(|ident| ident == field_name)
+            })
+            .map(|field| {
+                let ty = &field.ty;
+                quote!(#ty).to_string()
+            })
+    };
+    let mut accessors = proc_macro2::TokenStream::new();
+    if field_type("id").as_deref() == Some("Id") {
+        accessors.extend(quote! {
+            impl super::HasId for #name {
+                fn raw_id(&self) -> &[u8; 12] {
+                    self.id.as_bytes()
+                }
+            }
+        });
+    }
+    if field_type("id").as_deref() == Some("Option < Id >") {
+        accessors.extend(quote! {
+            impl #name {
+                /// Object's id, if it was present
+                pub fn id(&self) -> Option<&Id> {
+                    self.id.as_ref()
+                }
+            }
+        });
+    }
+    if field_type("canonical").is_some() {
+        accessors.extend(quote! {
+            impl #name {
+                /// Canonical url of this object, if it is present and valid
+                pub fn canonical_url(&self) -> Option<&::url::Url> {
+                    match self.canonical.as_ref()? {
+                        super::MaybeUrl::Url(url) => Some(url),
+                        super::MaybeUrl::BadUrl(..) => None,
+                    }
+                }
+            }
+        });
+    }
+    if field_type("__v").is_some() {
+        accessors.extend(quote! {
+            impl #name {
+                fn deserialize_schema_version<'de, D: ::serde::Deserializer<'de>>(
+                    deserializer: D,
+                ) -> Result<usize, D::Error> {
+                    let version = <usize as ::serde::Deserialize>::deserialize(deserializer)?;
+                    super::schema::record(concat!(module_path!(), "::", stringify!(#name)), version);
+                    Ok(version)
+                }
+            }
+        });
+    }
+    let slug = match (
+        field_type("slug").as_deref(),
+        field_type("username").as_deref(),
+    ) {
+        (Some("Slug"), _) => Some(quote!(slug)),
+        (_, Some("super :: UserName")) => Some(quote!(username)),
+        _ => None,
+    };
+    if let Some(slug) = slug {
+        accessors.extend(quote! {
+            impl super::HasSlug for #name {
+                fn slug_str(&self) -> &str {
+                    &self.#slug.0
+                }
+            }
+        });
+    }
+    if field_type("created_at").as_deref() == Some(":: time :: OffsetDateTime") {
+        accessors.extend(quote! {
+            impl super::HasCreatedAt for #name {
+                fn created_at(&self) -> ::time::OffsetDateTime {
+                    self.created_at
+                }
+            }
+        });
+    }
+    if field_type("main_tag_id").as_deref() == Some("super :: TagId") {
+        let listed = match field_type("tags").as_deref() {
+            Some("Vec < super :: TagId >") => quote!(self.tags.iter().collect()),
+            Some(_) => quote!(self.tags.iter().map(|tag| tag.id()).collect()),
+            None => quote!(::std::vec::Vec::new()),
+        };
+        accessors.extend(quote! {
+            impl super::HasTags for #name {
+                fn main_tag_id(&self) -> &super::TagId {
+                    &self.main_tag_id
+                }
+
+                fn listed_tag_ids(&self) -> ::std::vec::Vec<&super::TagId> {
+                    #listed
+                }
+            }
+        });
+    }
+    accessors
+}
+
+/// Implements `From<source>`, taking every field of the resulting type from the source
+///
+/// Fields of different types are converted with `Project` trait (for example, owner object is projected onto it's id).
+/// Fetch time, raw JSON and unknown fields are preserved.
+fn data_downgrade(name: &Ident, source: &Ident, fields: &syn::Fields) -> proc_macro2::TokenStream {
+    let taken = fields.iter().filter_map(|field| {
+        let ident = field.ident.as_ref()?;
+        let cfgs = cfg_attributes(field);
+        Some(quote! {
+            #(#cfgs)*
+            #ident: super::Project::project(source.#ident),
+        })
+    });
+    quote! {
+        impl ::core::convert::From<#source> for #name {
+            fn from(source: #source) -> Self {
+                Self {
+                    #(#taken)*
+                }
+            }
+        }
+    }
+}
+
+/// Implements `PartialEq`, comparing the actual data only
+///
+/// Fetch time and raw JSON are not compared, since two fetches of the same object should be equal.
+/// Secrets do not implement `PartialEq` on purpose, and so are not compared too.
+fn data_partial_eq(name: &Ident, fields: &syn::Fields) -> proc_macro2::TokenStream {
+    let compared = fields
+        .iter()
+        .filter(|field| {
+            let ty = &field.ty;
+            field
+                .ident
+                .as_ref()
+                .is_some_and(|ident| ident != "fetched_at" && ident != "raw")
+                && !quote!(#ty).to_string().contains("SecretString")
+        })
+        .map(|field| {
+            let ident = &field.ident;
+            let cfgs = cfg_attributes(field);
+            quote! {
+                #(#cfgs)*
+                if self.#ident != other.#ident {
+                    return false;
+                }
+            }
+        });
+    quote! {
+        impl ::core::cmp::PartialEq for #name {
+            fn eq(&self, other: &Self) -> bool {
+                #(#compared)*
+                true
+            }
+        }
+    }
+}
+
+/// Applies modifiers to the fields generated for a keyword
+fn modify_fields(
+    fields: proc_macro2::TokenStream,
+    modifiers: &[FieldModifier],
+) -> proc_macro2::TokenStream {
+    let mut fields = (|input: ParseStream| {
+        Punctuated::<syn::Field, Token![,]>::parse_terminated_with(input, syn::Field::parse_named)
+    })
+    .parse2(fields)
+    .expect("Generated fields should be valid");
+    for field in &mut fields {
+        for modifier in modifiers {
+            match modifier {
+                FieldModifier::Optional => {
+                    let ty = &field.ty;
+                    if !quote!(#ty).to_string().starts_with("Option <") {
+                        field.ty = syn::parse_quote!(Option<#ty>);
+                    }
+                    remove_serde_arg(field, "default");
+                    field.attrs.push(syn::parse_quote!(#[serde(default)]));
+                }
+                FieldModifier::Default => {
+                    remove_serde_arg(field, "default");
+                    field.attrs.push(syn::parse_quote!(#[serde(default)]));
+                }
+                FieldModifier::Rename(rename) => {
+                    remove_serde_arg(field, "rename");
+                    field
+                        .attrs
+                        .push(syn::parse_quote!(#[serde(rename = #rename)]));
+                }
+            }
+        }
+    }
+    let fields = fields.iter();
+    quote!(#(#fields,)*)
+}
+
+/// Removes an argument from field's `#[serde(...)]` attributes, so that it could be overridden
+fn remove_serde_arg(field: &mut syn::Field, arg: &str) {
+    field.attrs.retain_mut(|attr| {
+        if !attr.path().is_ident("serde") {
+            return true;
+        }
+        let args = attr
+            .parse_args_with(Punctuated::<syn::Meta, Token![,]>::parse_terminated)
+            .expect("Generated serde attributes should be valid");
+        let args: Punctuated<syn::Meta, Token![,]> = args
+            .into_iter()
+            .filter(|meta| !meta.path().is_ident(arg))
+            .collect();
+        if args.is_empty() {
+            return false;
+        }
+        *attr = syn::parse_quote!(#[serde(#args)]);
+        true
+    });
+}
+
+/// Generates `FIELDS` constant, listing Rust and JSON names of the (de)serialized fields
+fn data_introspection(name: &Ident, fields: &syn::Fields) -> proc_macro2::TokenStream {
+    let names = fields.iter().filter_map(|field| {
+        let ident = field.ident.as_ref()?.to_string();
+        let mut serde_name = ident.clone();
+        let mut serialized = true;
+        for attr in field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("serde"))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    serde_name = meta.value()?.parse::<syn::LitStr>()?.value();
+                } else if meta.path.is_ident("skip") || meta.path.is_ident("flatten") {
+                    serialized = false;
+                } else if meta.input.peek(Token![=]) {
+                    // other arguments are not relevant, but their values should be consumed
+                    meta.value()?.parse::<syn::Lit>()?;
+                }
+                Ok(())
+            })
+            .expect("Generated serde attributes should be valid");
+        }
+        serialized.then(|| quote!((#ident, #serde_name)))
+    });
+    quote! {
+        impl #name {
+            /// Rust and JSON names of the object's fields, in order of definition
+            pub const FIELDS: &'static [(&'static str, &'static str)] = &[#(#names),*];
+
+            /// Number of the object's fields, same as `FIELDS.len()`
+            pub const fn field_count() -> usize {
+                Self::FIELDS.len()
+            }
+        }
+    }
+}
+
+/// Generates `new_for_tests` constructor, taking every field except for the fetch time
+///
+/// Feature-gated fields and unknown fields are defaulted.
+fn data_test_constructor(name: &Ident, fields: &syn::Fields) -> proc_macro2::TokenStream {
+    let (gated, taken): (Vec<_>, Vec<_>) = fields
+        .iter()
+        .filter(|field| {
+            field
+                .ident
+                .as_ref()
+                .is_some_and(|ident| ident != "fetched_at")
+        })
+        .partition(|field| {
+            cfg_attributes(field).next().is_some()
+                || field.ident.as_ref().is_some_and(|ident| ident == "extra")
+        });
+    let params = taken.iter().map(|field| {
+        let ident = &field.ident;
+        let ty = &field.ty;
+        quote!(#ident: #ty)
+    });
+    let taken = taken.iter().map(|field| &field.ident);
+    let gated = gated.iter().map(|field| {
+        let ident = &field.ident;
+        let cfgs = cfg_attributes(field);
+        quote! {
+            #(#cfgs)*
+            #ident: ::core::default::Default::default(),
+        }
+    });
+    quote! {
+        #[cfg(feature = "test-constructors")]
+        impl #name {
+            /// Constructs the object from it's fields, as if it was just fetched
+            ///
+            /// Intended for tests, so that objects could be built without JSON.
+            #[allow(clippy::too_many_arguments)]
+            pub fn new_for_tests(#(#params),*) -> Self {
+                Self {
+                    #(#taken,)*
+                    #(#gated)*
+                    fetched_at: ::time::OffsetDateTime::now_utc(),
+                }
+            }
+        }
+    }
+}
+
+/// Sets span of every token in the stream
+fn respan(tokens: proc_macro2::TokenStream, span: proc_macro2::Span) -> proc_macro2::TokenStream {
+    tokens
+        .into_iter()
+        .map(|mut token| {
+            if let proc_macro2::TokenTree::Group(group) = &token {
+                let mut respanned =
+                    proc_macro2::Group::new(group.delimiter(), respan(group.stream(), span));
+                respanned.set_span(span);
+                token = proc_macro2::TokenTree::Group(respanned);
+            } else {
+                token.set_span(span);
+            }
+            token
+        })
+        .collect()
+}
+
+/// Field's `#[cfg(...)]` attributes, to be repeated wherever the field is used
+fn cfg_attributes(field: &syn::Field) -> impl Iterator<Item = &syn::Attribute> {
+    field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("cfg"))
+}
+
 #[allow(clippy::too_many_lines)]
-fn data_field(name: Ident) -> proc_macro2::TokenStream {
+fn data_field(name: Ident, type_name: &Ident) -> syn::Result<proc_macro2::TokenStream> {
     let s = name.to_string();
-    match s.as_str() {
+    Ok(match s.as_str() {
         // cave story madness
         "id" => quote! {
+            /// Object's id
             #[serde(rename = "_id")]
             id: Id,
         },
         "opt_id" => quote! {
+            /// Object's id, missing in some responses
             #[serde(rename = "_id", default)]
+            #[getter(skip)]
             id: Option<Id>,
         },
         "article_id" => quote! {
+            /// Id of the article
             article: super::ArticleId,
         },
         "list_id" => quote! {
+            /// Id of the list
             list: super::ListId,
         },
         "title" => quote! {
+            /// Title, as shown to readers
             title: Title,
         },
         "seo_title" => quote! {
+            /// Title for search engines
             #[serde(rename = "seoTitle")]
             seo_title: SeoTitle,
         },
         "name" => quote! {
+            /// Name, as shown to readers
             name: Name,
         },
         "article_name" => quote! {
+            /// Title of the article
             name: super::ArticleTitle,
         },
         "description" => quote! {
+            /// Description, as shown to readers
             description: Description,
         },
-        "user_description" => quote! {
-            description: Option<Description>,
-        },
         "user_articles" => quote! {
+            /// Articles written by the user
             articles: Vec<super::AuthorArticle>,
         },
         "short_description" => quote! {
+            /// Short description, shown next to the name
             #[serde(rename = "descriptionShort")]
             short_description: Option<ShortDescription>,
         },
         "slug" => quote! {
+            /// Slug, used in the object's url
             slug: Slug,
         },
         "main_tag_name" => quote! {
+            /// Name of the article's main tag
             #[serde(rename = "mainTag")]
             main_tag: super::TagName,
         },
         "main_tag_id" => quote! {
+            /// Id of the article's main tag
             #[serde(rename = "mainTagId")]
             main_tag_id: super::TagId,
         },
         "main_tag_slug" => quote! {
+            /// Slug of the article's main tag
             #[serde(rename = "mainTagSlug")]
             main_tag_slug: super::TagSlug,
         },
         "thumb_picture" => quote! {
+            /// Url of the thumbnail picture
             #[serde(rename = "thumbPicture")]
             thumb_picture: Option<super::MaybeUrl>,
         },
         "picture" => quote! {
+            /// Url of the cover picture
             picture: Option<super::MaybeUrl>,
         },
         "owner_id" => quote! {
+            /// Id of the owner
             owner: super::UserId,
         },
         "owner_article" => quote! {
+            /// Author of the article
             owner: super::ArticleUser,
         },
         "owner_comment" => quote! {
+            /// Author of the comment
             owner: super::CommentUser,
         },
         "maybe_comment_owner" => quote! {
+            /// Author of the comment, if the account still exists
             owner: Option<super::CommentUser>, // TODO check for that.
         },
         "is_bookmarked" => quote! {
+            /// Whether authorized user had bookmarked the article
             #[serde(rename = "isBookmarked")]
             is_bookmarked: bool,
         },
         "read_time" => quote! {
+            /// Reading time, as estimated by the site
             #[serde(
                 rename = "readTime",
-                deserialize_with = "super::serde_utils::duration_from_seconds"
+                deserialize_with = "super::serde_utils::duration_from_seconds",
+                serialize_with = "super::serde_utils::duration_to_seconds"
             )]
             read_time: ::time::Duration,
         },
         "created_at" => quote! {
-            #[serde(rename = "createdAt", with = "time::serde::iso8601")]
+            /// Time the object was created at
+            #[serde(
+                rename = "createdAt",
+                deserialize_with = "super::serde_utils::flexible_iso8601::deserialize",
+                serialize_with = "time::serde::rfc3339::serialize"
+            )]
             created_at: ::time::OffsetDateTime,
         },
         "tag_ids" => quote! {
+            /// Ids of the tags
             tags: Vec<super::TagId>,
         },
         "tag_users" => quote! {
+            /// Tags
             tags: Vec<super::UserTag>,
         },
         "tag_articles" => quote! {
+            /// Tags
             tags: Vec<super::ArticleTag>,
         },
         "like_num" => quote! {
+            /// Number of likes
             #[serde(rename = "likeNum")]
             like_num: usize,
         },
         "likes_num" => quote! {
+            /// Number of likes
             #[serde(rename = "likesNum")]
             likes_num: usize, // yes, really
         },
         "comment_num" => quote! {
+            /// Number of comments
             #[serde(rename = "commentNum")]
             comment_num: usize,
         },
         "comment_dom" => quote! {
-            #[serde(deserialize_with = "super::serde_utils::html_from_str")]
-            comment: ::html_parser::Dom,
+            /// Comment's html, parsed on first access
+            comment: super::comment::LazyDom,
         },
         "comments" => quote! {
+            /// Comments, without replies
             comments: Vec<super::ArticleComment>,
         },
         "reply_num" => quote! {
+            /// Number of replies
             #[serde(rename = "replyNum")]
             reply_num: usize,
         },
         "articles_num" => quote! {
+            /// Number of articles
             #[serde(rename = "articlesNum")]
             articles_num: usize,
         },
         "mentions_num" => quote! {
+            /// Number of mentions
             #[serde(rename = "mentionsNum")]
             mentions_num: usize,
         },
         "sensitive" => quote! {
+            /// Whether the content is marked as sensitive
             sensitive: bool,
         },
         "relationships" => quote! {
+            /// Relationships with authorized user
+            relationships: super::Relationships,
+        },
+        "relationships_opt" => quote! {
+            /// Relationships with authorized user
+            ///
+            /// Site omits these for anonymous requests, so they are all-false then.
+            #[serde(default)]
             relationships: super::Relationships,
         },
         "ads" => quote! {
+            /// Whether ads are shown
             ads: Option<bool>, // TODO check if it's really optional
         },
         "index" => quote! {
+            /// Whether the object is indexed by search engines
             index: Option<bool>,
         },
         "is_liked" => quote! {
-            #[serde(
-                rename = "isLiked",
-                deserialize_with = "super::serde_utils::flag_from_number"
-            )]
-            is_liked: bool,
-        },
-        "is_liked_bool" => quote! {
-            #[serde(rename = "isLiked")]
+            /// Whether authorized user had liked the object
+            #[serde(rename = "isLiked", deserialize_with = "super::serde_utils::lenient_flag")]
             is_liked: bool,
         },
         "is_blocked" => quote! {
+            /// Whether the object was blocked by moderation
             #[serde(rename = "isBlocked")]
             is_blocked: bool,
         },
         "hidden_by_author" => quote! {
+            /// Whether the object was hidden by article's author
             #[serde(rename = "hiddenByAuthor")]
             hidden_by_author: bool,
         },
         "author_articles" => quote! {
+            /// Other articles by the same author
             #[serde(rename = "authorArticles")]
             author_articles: Vec<super::SearchArticle>,
         },
         "recommended_articles" => quote! {
+            /// Articles recommended to read next
             #[serde(rename = "recommendedArticles")]
             recommended_articles: Vec<super::RecommendedArticle>,
         },
         "article_tags" => quote! {
+            /// Articles with the tag
             articles: Vec<super::TagArticle>,
         },
         "content" => quote! {
-            content: ::serde_json::Value, // TODO perform proper content typing
+            /// Article's content
+            content: super::ArticleContent,
         },
         "reply_to_comment" => quote! {
+            /// Id of the comment this is a reply to
             #[serde(rename = "replyToComment")]
             reply_to_comment: Id,
         },
         "reply_to_user" => quote! {
+            /// Id of the user this is a reply to
             #[serde(rename = "replyToUser")]
             reply_to_user: super::UserId,
         },
         "root_comment" => quote! {
+            /// Id of the top-level comment of the thread
             #[serde(rename = "rootComment")]
             root_comment: Id,
         },
         "root_comment_owner" => quote! {
+            /// Id of the top-level comment's author
             #[serde(rename = "rootCommentOwner")]
             root_comment_owner: super::UserId,
         },
+        "notification_kind" => quote! {
+            /// Kind of the notification
+            #[serde(rename = "type")]
+            kind: super::NotificationKind,
+        },
+        "seen" => quote! {
+            /// Whether the notification was seen
+            seen: bool,
+        },
+        "notification_details" => quote! {
+            /// Kind-specific details, if any
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            details: Option<Details>,
+        },
+        "action_owner" => quote! {
+            /// User that caused the notification
+            #[serde(rename = "actionOwner", default, skip_serializing_if = "Option::is_none")]
+            action_owner: Option<super::CommentUser>,
+        },
         "default" => quote! {
+            /// Whether this is a default list
             #[serde(default)]
             default: bool,
         },
         "ignore" => quote! {
+            /// Whether the object is ignored
             #[serde(default)]
             ignore: bool,
         },
         "username" => quote! {
+            /// Username, used in the user's url
             username: super::UserName,
         },
         "opt_username" => quote! {
+            /// Username, if the account still exists
             #[serde(default)]
             username: Option<super::UserName>,
         },
         "display_name" => quote! {
+            /// Name, as shown to readers
             name: super::UserDisplayName,
         },
         "opt_display_name" => quote! {
+            /// Name, if the account still exists
             #[serde(default)]
             name: Option<super::UserDisplayName>,
         },
         "following_num" => quote! {
+            /// Number of users this user follows
             #[serde(rename = "followingNum")]
             following_num: usize,
         },
         "followers_num" => quote! {
+            /// Number of users following this user
             #[serde(rename = "followersNum")]
             followers_num: usize,
         },
         "email" => quote! {
-            email: String, // TODO check that
+            /// Email, if account has one
+            #[serde(default)]
+            #[getter(skip)]
+            email: Email,
         },
         "read_num" => quote! {
+            /// Number of reads
             #[serde(rename = "readNum")]
             read_num: usize,
         },
         "first_published_at" => quote! {
-            #[serde(rename = "firstPublishedAt")]
+            /// Time the article was first published at
+            #[serde(
+                default,
+                rename = "firstPublishedAt",
+                deserialize_with = "super::serde_utils::flexible_iso8601::option::deserialize",
+                serialize_with = "time::serde::rfc3339::option::serialize"
+            )]
             first_published_at: Option<::time::OffsetDateTime>,
         },
         "author_tags" => quote! {
+            /// Tags the user writes about
             #[serde(rename = "authorTags")]
             author_tags: Vec<super::UserTag>,
         },
         "notifications_num" => quote! {
+            /// Number of unseen notifications
             #[serde(rename = "notificationsNum")]
             notifications_num: usize,
         },
         "socials" => quote! {
+            /// Links to the social media
             #[serde(default)]
             socials: Socials,
         },
         "avatar" => quote! {
+            /// Url of the avatar
             #[serde(default)]
             avatar: Option<super::MaybeUrl>,
         },
         "donate_url" => quote! {
+            /// Url for donations
             #[serde(rename = "donateUrl", default)]
             donate_url: Option<super::MaybeUrl>,
         },
         "canonical" => quote! {
-            #[getter(skip)]
-            canonical: Option<url::Url>,
+            /// Canonical url, as provided by the author
+            #[serde(default)]
+            canonical: Option<super::MaybeUrl>,
         },
         "unused_pin_created_at" => quote! {
+            /// Time the object was pinned at
             #[serde(
-                skip_serializing,
                 default,
                 rename = "pinCreatedAt",
-                deserialize_with = "super::serde_utils::optional_iso_time"
+                deserialize_with = "super::serde_utils::flexible_iso8601::option::deserialize",
+                serialize_with = "time::serde::rfc3339::option::serialize",
+                skip_serializing_if = "Option::is_none"
             )]
             #[getter(skip)]
             #[allow(dead_code)]
             pin_created_at: Option<::time::OffsetDateTime>, // TODO unused
         },
-        "unused___v" => quote! {
-            #[serde(skip_serializing)]
-            #[getter(skip)]
-            #[allow(dead_code)]
-            __v: usize, // TODO unused
-        },
+        "unused___v" => {
+            // schema version is recorded per type, so deserializer should be the type's own
+            let deserialize_with = format!("{type_name}::deserialize_schema_version");
+            quote! {
+                /// Schema version
+                #[serde(deserialize_with = #deserialize_with)]
+                #[getter(skip)]
+                __v: usize,
+            }
+        }
         "unused_general" => quote! {
-            #[serde(skip_serializing)]
+            /// Unknown flag
             #[getter(skip)]
             #[allow(dead_code)]
             general: Option<bool>, // TODO unused
         },
         "unused_facebook_id" => quote! {
+            /// Facebook account id
             #[getter(skip)]
             #[allow(dead_code)]
             facebook_id: Option<String>, // TODO unused
         },
         "unused_google_id" => quote! {
+            /// Google account id
             #[getter(skip)]
             #[allow(dead_code)]
             google_id: Option<String>, // TODO unused
         },
         "unused_password" => quote! {
+            /// Password
             #[serde(skip_serializing)]
             #[getter(skip)]
             #[allow(dead_code)]
             password: Option<SecretString>, // TODO unused
         },
-        other => panic!("Unknown field: {other}"),
-    }
+        other => {
+            return Err(syn::Error::new_spanned(
+                &name,
+                format!("Unknown field keyword `{other}`"),
+            ))
+        }
+    })
 }
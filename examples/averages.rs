@@ -1,6 +1,4 @@
-use futures::StreamExt;
-use type_matrux::client::ReqwestApi;
-use type_matrux::DrukarniaApi;
+use type_matrux::prelude::*;
 
 #[tokio::main(flavor = "current_thread")]
 pub async fn main() {
@@ -0,0 +1,21 @@
+use type_matrux::{export::HtmlExportOptions, prelude::*};
+
+#[tokio::main(flavor = "current_thread")]
+pub async fn main() {
+    let client = DrukarniaClient::from(ReqwestApi::new());
+    let slug: ArticleSlug = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| {
+            "gitlab-istoriya-uspikhu-ukrayinskogo-konkurenta-github-t7agP".to_owned()
+        })
+        .parse()
+        .unwrap();
+    let article = client.get_article(&slug).await.unwrap();
+    let mut options = HtmlExportOptions::default();
+    if let Some(cover) = article.cover(&client).await.unwrap() {
+        options = options.with_cover(cover);
+    }
+    let path = format!("{slug}.html");
+    std::fs::write(&path, article.to_html(options)).unwrap();
+    println!("Article is written to {path}");
+}
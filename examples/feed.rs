@@ -1,8 +1,6 @@
 use std::num::NonZeroUsize;
 
-use futures::StreamExt;
-use type_matrux::client::ReqwestApi;
-use type_matrux::DrukarniaApi;
+use type_matrux::prelude::*;
 
 #[tokio::main(flavor = "current_thread")]
 pub async fn main() {
@@ -0,0 +1,19 @@
+use std::num::NonZeroUsize;
+
+use type_matrux::blocking::BlockingDrukarnia;
+
+pub fn main() {
+    let client = BlockingDrukarnia::new().expect("Should be able to start a runtime");
+    let page = client
+        .feed_page(NonZeroUsize::new(1).expect("1 != 0"))
+        .unwrap();
+    println!("first acticle is {:#?}", page[0]);
+
+    let mut page_iter = client.feed();
+    let eleventh_page = page_iter.nth(10).unwrap().unwrap();
+    println!("first article on eleventh page is {:#?}", eleventh_page[0]);
+
+    let mut article_iter = client.feed().flat();
+    let hundredth_article = article_iter.nth(99).unwrap().unwrap();
+    println!("hundredth article is {:#?}", hundredth_article);
+}
@@ -0,0 +1,16 @@
+use type_matrux::prelude::*;
+
+#[tokio::main(flavor = "current_thread")]
+pub async fn main() {
+    let client = DrukarniaClient::from(ReqwestApi::new());
+    let name = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "OstanniyCapitalist".to_owned())
+        .parse()
+        .unwrap();
+    let portfolio = client.get_author_portfolio(&name).await.unwrap();
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&portfolio).expect("Portfolio should be serializable")
+    );
+}
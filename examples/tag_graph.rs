@@ -0,0 +1,25 @@
+use std::collections::HashMap;
+
+use type_matrux::{analysis::TagGraph, prelude::*};
+
+#[tokio::main(flavor = "current_thread")]
+pub async fn main() {
+    let client = ReqwestApi::new();
+    let mut articles = client.feed().flat().take(200);
+    let mut graph = TagGraph::new();
+    let mut names = HashMap::new();
+    while let Some(Ok(article)) = articles.next().await {
+        graph.ingest(&article);
+        names.insert(
+            article.main_tag_id().clone(),
+            article.main_tag().to_string(),
+        );
+        for tag in article.tags() {
+            names.insert(tag.id().clone(), tag.name().to_string());
+        }
+    }
+    println!("{} articles processed", graph.articles());
+    for (first, second, count) in graph.top_pairs(10) {
+        println!("{} + {}: {}", names[&first], names[&second], count);
+    }
+}
@@ -0,0 +1,122 @@
+use std::{cmp::Reverse, collections::HashMap};
+
+use serde::{ser::SerializeStruct, Serialize, Serializer};
+
+use crate::object::{HasTags, TagId};
+
+/// Counts of tags appearing together on the same articles
+///
+/// Every ingested article adds a single co-occurrence to each pair of it's distinct tags, main tag included.
+/// Articles are not deduplicated, so each one should be ingested once.
+///
+/// Serializes as a number of ingested articles, along with all the pairs ordered as in [`TagGraph::top_pairs`]:
+/// ```json
+/// {"articles": 2, "pairs": [{"tags": ["64ff1f2a1272bd9066a1aa01", "64ff1f2a1272bd9066a1aa02"], "count": 2}]}
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TagGraph {
+    articles: usize,
+    edges: HashMap<TagId, HashMap<TagId, usize>>,
+}
+
+impl TagGraph {
+    /// Creates an empty graph
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records co-occurrences of the article's tags
+    pub fn ingest(&mut self, article: &impl HasTags) {
+        self.articles += 1;
+        let tags = article.tag_ids();
+        for (i, first) in tags.iter().enumerate() {
+            for second in &tags[i + 1..] {
+                self.add_edge(first, second);
+                self.add_edge(second, first);
+            }
+        }
+    }
+
+    fn add_edge(&mut self, from: &TagId, to: &TagId) {
+        *self
+            .edges
+            .entry(from.clone())
+            .or_default()
+            .entry(to.clone())
+            .or_default() += 1;
+    }
+
+    /// Number of articles ingested so far
+    pub fn articles(&self) -> usize {
+        self.articles
+    }
+
+    /// Tags that appeared together with the `tag`, along with the number of articles they shared
+    ///
+    /// Most frequent tags come first, ties are ordered by tag id.
+    pub fn co_occurrences(&self, tag: &TagId) -> Vec<(TagId, usize)> {
+        let mut neighbours: Vec<_> = self
+            .edges
+            .get(tag)
+            .into_iter()
+            .flatten()
+            .map(|(neighbour, &count)| (neighbour.clone(), count))
+            .collect();
+        neighbours.sort_by(|(a, a_count), (b, b_count)| {
+            (Reverse(a_count), a.as_bytes()).cmp(&(Reverse(b_count), b.as_bytes()))
+        });
+        neighbours
+    }
+
+    /// At most `n` most frequent tag pairs, along with the number of articles they shared
+    ///
+    /// Each pair is listed once, with the smaller tag id first. Ties are ordered by tag ids.
+    pub fn top_pairs(&self, n: usize) -> Vec<(TagId, TagId, usize)> {
+        self.pairs()
+            .into_iter()
+            .take(n)
+            .map(|(first, second, count)| (first.clone(), second.clone(), count))
+            .collect()
+    }
+
+    /// All of the pairs, ordered as in [`TagGraph::top_pairs`]
+    fn pairs(&self) -> Vec<(&TagId, &TagId, usize)> {
+        let mut pairs: Vec<_> = self
+            .edges
+            .iter()
+            .flat_map(|(first, neighbours)| {
+                neighbours
+                    .iter()
+                    .filter(|(second, _)| first.as_bytes() < second.as_bytes())
+                    .map(move |(second, &count)| (first, second, count))
+            })
+            .collect();
+        pairs.sort_by_key(|&(first, second, count)| {
+            (Reverse(count), first.as_bytes(), second.as_bytes())
+        });
+        pairs
+    }
+}
+
+impl Serialize for TagGraph {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        struct Pair<'a> {
+            tags: [&'a TagId; 2],
+            count: usize,
+        }
+
+        let pairs: Vec<_> = self
+            .pairs()
+            .into_iter()
+            .map(|(first, second, count)| Pair {
+                tags: [first, second],
+                count,
+            })
+            .collect();
+        let mut graph = serializer.serialize_struct("TagGraph", 2)?;
+        graph.serialize_field("articles", &self.articles)?;
+        graph.serialize_field("pairs", &pairs)?;
+        graph.end()
+    }
+}
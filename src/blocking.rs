@@ -0,0 +1,280 @@
+use std::num::NonZeroUsize;
+
+use tokio::runtime::{Builder, Runtime};
+use url::Url;
+
+use crate::{
+    client::{Error, FeedSession, ImageBytes, Page},
+    object::{
+        ArticleSlug, ArticleTitle, CommentId, CommentThread, FeedArticle, FollowerUser,
+        FullArticle, FullTag, FullUser, MaybeUrl, PlainShortUser, PopularTag, RecommendedArticle,
+        ReplyComment, ShortUser, TagSlug, UserId, UserName,
+    },
+    DrukarniaApi, DrukarniaClient,
+};
+
+type Res<T = ()> = Result<T, Error>;
+
+/// Synchronous API accessor, for the code that is not async
+///
+/// Wraps any [`DrukarniaApi`] accessor ([`DrukarniaClient`] by default) and drives it on an internal current-thread runtime.
+/// Thus, it should not be used from within async context - there, just use the wrapped accessor directly.
+///
+/// Methods mirror [`DrukarniaApi`]'s ones, with iterators in place of the streams.
+#[derive(Debug)]
+pub struct BlockingDrukarnia<A = DrukarniaClient> {
+    api: A,
+    runtime: Runtime,
+}
+
+impl BlockingDrukarnia {
+    /// Creates an accessor with default client
+    ///
+    /// # Errors
+    /// If runtime could not be started
+    pub fn new() -> std::io::Result<Self> {
+        Self::with_api(DrukarniaClient::from(reqwest::Client::new()))
+    }
+}
+
+impl<A: DrukarniaApi> BlockingDrukarnia<A> {
+    /// Wraps supplied accessor
+    ///
+    /// # Errors
+    /// If runtime could not be started
+    pub fn with_api(api: A) -> std::io::Result<Self> {
+        let runtime = Builder::new_current_thread().enable_all().build()?;
+        Ok(Self { api, runtime })
+    }
+
+    /// Wrapped accessor
+    pub fn api(&self) -> &A {
+        &self.api
+    }
+
+    /// See [`TagsApi::popular_tags`](crate::client::TagsApi::popular_tags)
+    pub fn popular_tags(&self) -> Res<Vec<PopularTag>> {
+        self.runtime.block_on(self.api.popular_tags())
+    }
+
+    /// See [`UsersApi::get_user`](crate::client::UsersApi::get_user)
+    pub fn get_user(&self, name: &UserName) -> Res<FullUser> {
+        self.runtime.block_on(self.api.get_user(name))
+    }
+
+    /// See [`UsersApi::search_user_page`](crate::client::UsersApi::search_user_page)
+    pub fn search_user_page(&self, name: &UserName, page: NonZeroUsize) -> Res<Vec<ShortUser>> {
+        self.runtime.block_on(self.api.search_user_page(name, page))
+    }
+
+    /// See [`UsersApi::search_user`](crate::client::UsersApi::search_user)
+    pub fn search_user(&self, name: UserName) -> PageIter<'_, ShortUser>
+    where
+        A: Sync,
+    {
+        PageIter::new(move |page| {
+            self.runtime
+                .block_on(self.api.search_user_page_meta(&name, page))
+        })
+    }
+
+    /// See [`TagsApi::get_tag`](crate::client::TagsApi::get_tag)
+    pub fn get_tag(&self, slug: &TagSlug) -> Res<FullTag> {
+        self.runtime.block_on(self.api.get_tag(slug))
+    }
+
+    /// See [`ArticlesApi::get_article`](crate::client::ArticlesApi::get_article)
+    pub fn get_article(&self, slug: &ArticleSlug) -> Res<FullArticle> {
+        self.runtime.block_on(self.api.get_article(slug))
+    }
+
+    /// See [`ArticlesApi::get_article_by_url`](crate::client::ArticlesApi::get_article_by_url)
+    pub fn get_article_by_url(&self, url: &Url) -> Res<FullArticle>
+    where
+        A: Sync,
+    {
+        self.runtime.block_on(self.api.get_article_by_url(url))
+    }
+
+    /// See [`ArticlesApi::search_article_page`](crate::client::ArticlesApi::search_article_page)
+    pub fn search_article_page(
+        &self,
+        name: &ArticleTitle,
+        page: NonZeroUsize,
+    ) -> Res<Vec<RecommendedArticle>> {
+        self.runtime
+            .block_on(self.api.search_article_page(name, page))
+    }
+
+    /// See [`ArticlesApi::search_article`](crate::client::ArticlesApi::search_article)
+    pub fn search_article(&self, name: ArticleTitle) -> PageIter<'_, RecommendedArticle>
+    where
+        A: Sync,
+    {
+        PageIter::new(move |page| {
+            self.runtime
+                .block_on(self.api.search_article_page_meta(&name, page))
+        })
+    }
+
+    /// See [`UsersApi::search_user_page_plain`](crate::client::UsersApi::search_user_page_plain)
+    pub fn search_user_page_plain(
+        &self,
+        name: &UserName,
+        page: NonZeroUsize,
+    ) -> Res<Vec<PlainShortUser>>
+    where
+        A: Sync,
+    {
+        self.runtime
+            .block_on(self.api.search_user_page_plain(name, page))
+    }
+
+    /// See [`UsersApi::search_user_plain`](crate::client::UsersApi::search_user_plain)
+    pub fn search_user_plain(&self, name: UserName) -> PageIter<'_, PlainShortUser>
+    where
+        A: Sync,
+    {
+        PageIter::new(move |page| {
+            self.runtime
+                .block_on(self.api.search_user_page_plain_meta(&name, page))
+        })
+    }
+
+    /// See [`UsersApi::get_followers_page`](crate::client::UsersApi::get_followers_page)
+    pub fn get_followers_page(&self, id: &UserId, page: NonZeroUsize) -> Res<Vec<FollowerUser>> {
+        self.runtime.block_on(self.api.get_followers_page(id, page))
+    }
+
+    /// See [`UsersApi::get_followers`](crate::client::UsersApi::get_followers)
+    pub fn get_followers(&self, id: UserId) -> PageIter<'_, FollowerUser>
+    where
+        A: Sync,
+    {
+        PageIter::new(move |page| {
+            self.runtime
+                .block_on(self.api.get_followers_page_meta(&id, page))
+        })
+    }
+
+    /// See [`CommentsApi::get_replies`](crate::client::CommentsApi::get_replies)
+    pub fn get_replies(&self, comment: &CommentId) -> Res<Vec<ReplyComment>> {
+        self.runtime.block_on(self.api.get_replies(comment))
+    }
+
+    /// See [`DrukarniaApi::get_article_thread`]
+    pub fn get_article_thread(&self, slug: &ArticleSlug) -> Res<(FullArticle, CommentThread)>
+    where
+        A: Sync,
+    {
+        self.runtime.block_on(self.api.get_article_thread(slug))
+    }
+
+    /// See [`ArticlesApi::download_image`](crate::client::ArticlesApi::download_image)
+    pub fn download_image(&self, url: &MaybeUrl) -> Res<ImageBytes> {
+        self.runtime.block_on(self.api.download_image(url))
+    }
+
+    /// See [`FeedApi::feed_page`](crate::client::FeedApi::feed_page)
+    pub fn feed_page(&self, page: NonZeroUsize) -> Res<Vec<FeedArticle>> {
+        self.runtime.block_on(self.api.feed_page(page))
+    }
+
+    /// See [`FeedApi::feed`](crate::client::FeedApi::feed)
+    pub fn feed(&self) -> PageIter<'_, FeedArticle>
+    where
+        A: Sync,
+    {
+        PageIter::new(move |page| self.runtime.block_on(self.api.feed_page_meta(page)))
+    }
+
+    /// See [`FeedApi::feed_session`](crate::client::FeedApi::feed_session)
+    pub fn feed_session(&self) -> Res<FeedSession>
+    where
+        A: Sync,
+    {
+        self.runtime.block_on(self.api.feed_session())
+    }
+
+    /// See [`FeedApi::feed_page_with`](crate::client::FeedApi::feed_page_with)
+    pub fn feed_page_with(&self, session: &FeedSession, page: NonZeroUsize) -> Res<Vec<FeedArticle>>
+    where
+        A: Sync,
+    {
+        self.runtime
+            .block_on(self.api.feed_page_with(session, page))
+    }
+
+    /// See [`FeedApi::feed_with`](crate::client::FeedApi::feed_with)
+    pub fn feed_with(&self, session: FeedSession) -> PageIter<'_, FeedArticle>
+    where
+        A: Sync,
+    {
+        PageIter::new(move |page| {
+            self.runtime
+                .block_on(self.api.feed_page_with_meta(&session, page))
+        })
+    }
+}
+
+/// Iterator over consequent pages, blocking counterpart of the streams, like [`FeedApi::feed`](crate::client::FeedApi::feed)'s one
+///
+/// Ends on the first empty page, or right after the page known to be the last one (see [`Page::has_more`]).
+/// Error is returned once, and iterator ends right after it.
+pub struct PageIter<'a, E> {
+    fetch: Box<dyn Fn(NonZeroUsize) -> Res<Page<E>> + 'a>,
+    page: NonZeroUsize,
+    ended: bool,
+}
+
+impl<'a, E> PageIter<'a, E> {
+    fn new(fetch: impl Fn(NonZeroUsize) -> Res<Page<E>> + 'a) -> Self {
+        Self {
+            fetch: Box::new(fetch),
+            page: NonZeroUsize::new(1).expect("1 != 0"),
+            ended: false,
+        }
+    }
+
+    /// Flattens pages into separate objects
+    pub fn flat(self) -> impl Iterator<Item = Res<E>> + 'a
+    where
+        E: 'a,
+    {
+        self.flat_map(|page| {
+            let (objects, err) = match page {
+                Ok(objects) => (objects, None),
+                Err(err) => (Vec::new(), Some(err)),
+            };
+            objects.into_iter().map(Ok).chain(err.map(Err))
+        })
+    }
+}
+
+impl<E> Iterator for PageIter<'_, E> {
+    type Item = Res<Vec<E>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.ended {
+            return None;
+        }
+        match (self.fetch)(self.page) {
+            Ok(page) if page.items().is_empty() => {
+                // Results had ended, and so is this iterator
+                self.ended = true;
+                None
+            }
+            Ok(page) => {
+                // no need to request the next page, if it's known to be empty
+                self.ended = page.is_last();
+                self.page = self.page.saturating_add(1);
+                Some(Ok(page.into_items()))
+            }
+            Err(err) => {
+                // Return the error now, but end on the next call
+                self.ended = true;
+                Some(Err(err))
+            }
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use std::sync::Arc;
+
+use futures::{future::BoxFuture, lock::Mutex};
+
+use crate::object::Credentials;
+
+use super::{AuthDrukarnia, BaseApi, Error, Res};
+
+/// Authorized API accessor, that logs in again once session expires
+///
+/// Since credentials are kept in memory for the whole wrapper's lifetime, it's up to you to decide, if that's acceptable.
+///
+/// Calls are performed through [`AutoAuth::call`], and are retried once after a successful re-login.
+/// Concurrent calls that hit an expired session share a single re-login.
+pub struct AutoAuth<A: BaseApi> {
+    api: A,
+    credentials: Credentials,
+    session: Mutex<Session<A::Auth>>,
+}
+
+/// Current authorized accessor, along with a number of re-logins performed to obtain it
+struct Session<Auth> {
+    auth: Arc<Auth>,
+    generation: usize,
+}
+
+impl<A> AutoAuth<A>
+where
+    A: BaseApi + Sync,
+    A::Auth: AuthDrukarnia + Send + Sync,
+{
+    /// Logs in with supplied credentials, keeping them for the later re-logins
+    ///
+    /// # Errors
+    /// Same as [`BaseApi::login`](super::BaseApi::login)
+    pub async fn login(api: A, credentials: Credentials) -> Res<Self> {
+        let auth = api.login(credentials.clone()).await?;
+        Ok(Self {
+            api,
+            credentials,
+            session: Mutex::new(Session {
+                auth: Arc::new(auth),
+                generation: 0,
+            }),
+        })
+    }
+
+    /// Unauthorized accessor this wrapper logs in with
+    pub fn api(&self) -> &A {
+        &self.api
+    }
+
+    /// Currently authorized accessor
+    ///
+    /// It might get replaced after a re-login, so it's better not to hold onto it.
+    pub async fn current(&self) -> Arc<A::Auth> {
+        self.session.lock().await.auth.clone()
+    }
+
+    /// Number of re-logins performed so far
+    pub async fn relogins(&self) -> usize {
+        self.session.lock().await.generation
+    }
+
+    /// Performs a call with currently authorized accessor
+    ///
+    /// If call fails with [`Error::SessionExpired`], logs in again and retries the call once.
+    ///
+    /// ```no_run
+    /// # use type_matrux::{client::{AutoAuth, AuthDrukarnia}, object::Credentials, ReqwestApi};
+    /// # async fn example(credentials: Credentials) -> Result<(), type_matrux::client::Error> {
+    /// let auth = AutoAuth::login(ReqwestApi::new(), credentials).await?;
+    /// let lists = auth.call(|auth| auth.get_bookmark_lists()).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// - Same as the call
+    /// - Same as [`BaseApi::login`](super::BaseApi::login), if re-login has failed
+    pub async fn call<T, F>(&self, call: F) -> Res<T>
+    where
+        F: for<'a> Fn(&'a A::Auth) -> BoxFuture<'a, Res<T>>,
+    {
+        let (auth, generation) = {
+            let session = self.session.lock().await;
+            (session.auth.clone(), session.generation)
+        };
+        match call(&auth).await {
+            Err(Error::SessionExpired) => {}
+            other => return other,
+        }
+        let auth = self.relogin(generation).await?;
+        call(&auth).await
+    }
+
+    /// Logs in again, unless someone else did that already after `generation`
+    async fn relogin(&self, generation: usize) -> Res<Arc<A::Auth>> {
+        let mut session = self.session.lock().await;
+        if session.generation == generation {
+            tracing::info!("Session has expired, logging in again");
+            let auth = self.api.login(self.credentials.clone()).await?;
+            session.auth = Arc::new(auth);
+            session.generation += 1;
+        }
+        Ok(session.auth.clone())
+    }
+}
@@ -0,0 +1,378 @@
+use std::{
+    collections::HashMap,
+    hash::Hash,
+    num::NonZeroUsize,
+    sync::{Mutex, MutexGuard},
+};
+
+use async_trait::async_trait;
+use derive_getters::Getters;
+use url::Url;
+
+use crate::object::{
+    Aged, ArticleSlug, ArticleTitle, CommentId, Credentials, FeedArticle, FollowerUser,
+    FullArticle, FullTag, FullUser, MaybeUrl, PlainShortUser, PopularTag, RecommendedArticle,
+    ReplyComment, ShortUser, TagSlug, UserId, UserName,
+};
+
+use super::{
+    ArticlesApi, AuthDrukarnia, BaseApi, CommentsApi, DrukarniaApi, FeedApi, FeedSession,
+    ImageBytes, LoginOptions, Page, Res, TagsApi, UsersApi,
+};
+
+/// Number of objects of each kind kept by default
+const DEFAULT_CAPACITY: usize = 256;
+
+/// Hits and misses of [`CachedDrukarnia`], over all object kinds
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Getters)]
+pub struct CacheStats {
+    /// Calls served from the cache
+    hits: usize,
+    /// Calls passed to the inner accessor, including the ones for expired objects
+    misses: usize,
+}
+
+/// Bounded map, evicting least recently used entries
+#[derive(Debug)]
+struct Lru<K, V> {
+    capacity: NonZeroUsize,
+    entries: HashMap<K, (V, u64)>,
+    tick: u64,
+}
+
+impl<K: Eq + Hash + Clone, V: Clone + Aged> Lru<K, V> {
+    fn new(capacity: NonZeroUsize) -> Self {
+        Self {
+            capacity,
+            entries: HashMap::new(),
+            tick: 0,
+        }
+    }
+
+    /// Cached value, unless it's older than `ttl`
+    fn get(&mut self, key: &K, ttl: time::Duration) -> Option<V> {
+        self.tick += 1;
+        let (value, used) = self.entries.get_mut(key)?;
+        if value.is_older_than(ttl) {
+            self.entries.remove(key);
+            return None;
+        }
+        *used = self.tick;
+        Some(value.clone())
+    }
+
+    fn insert(&mut self, key: K, value: V) {
+        self.tick += 1;
+        if self.entries.len() >= self.capacity.get() && !self.entries.contains_key(&key) {
+            // linear, but capacity is expected to be small enough for that to not matter
+            let oldest = self
+                .entries
+                .iter()
+                .min_by_key(|(_, (_, used))| *used)
+                .map(|(key, _)| key.clone());
+            if let Some(oldest) = oldest {
+                self.entries.remove(&oldest);
+            }
+        }
+        self.entries.insert(key, (value, self.tick));
+    }
+
+    fn remove(&mut self, key: &K) {
+        self.entries.remove(key);
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+/// API accessor wrapper, caching tags, users and articles by their slugs and names
+///
+/// Objects are refetched once they are older than the configured time-to-live (see [`Aged::is_older_than`]),
+/// and least recently used ones are evicted once there are too many of them.
+/// Everything else, including logging in, is passed to the inner accessor as-is.
+///
+/// Default methods built on the cached ones (like [`TagsApi::get_tags`]) benefit from the cache too.
+///
+/// ```no_run
+/// # use type_matrux::{client::{CachedDrukarnia, TagsApi}, ReqwestApi};
+/// # async fn example() -> Result<(), type_matrux::client::Error> {
+/// let api = CachedDrukarnia::new(ReqwestApi::new(), time::Duration::minutes(10));
+/// let slug = "tekhnologiyi".parse().unwrap();
+/// let tag = api.get_tag(&slug).await?;
+/// // served from the cache
+/// let same_tag = api.get_tag(&slug).await?;
+/// assert_eq!(api.cache_stats().hits(), &1);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct CachedDrukarnia<C> {
+    inner: C,
+    ttl: time::Duration,
+    tags: Mutex<Lru<TagSlug, FullTag>>,
+    users: Mutex<Lru<UserName, FullUser>>,
+    articles: Mutex<Lru<ArticleSlug, FullArticle>>,
+    stats: Mutex<CacheStats>,
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    // cache is only ever a copy of fetched data, so it's fine even if some call had panicked
+    mutex
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+}
+
+impl<C> CachedDrukarnia<C> {
+    /// Wraps an accessor, keeping objects for `ttl`
+    pub fn new(inner: C, ttl: time::Duration) -> Self {
+        let capacity = NonZeroUsize::new(DEFAULT_CAPACITY).expect("Default capacity is not zero");
+        Self {
+            inner,
+            ttl,
+            tags: Mutex::new(Lru::new(capacity)),
+            users: Mutex::new(Lru::new(capacity)),
+            articles: Mutex::new(Lru::new(capacity)),
+            stats: Mutex::default(),
+        }
+    }
+
+    /// Sets maximal number of objects of each kind to keep, 256 by default
+    ///
+    /// Drops everything cached so far.
+    #[must_use]
+    pub fn with_capacity(self, capacity: NonZeroUsize) -> Self {
+        Self {
+            tags: Mutex::new(Lru::new(capacity)),
+            users: Mutex::new(Lru::new(capacity)),
+            articles: Mutex::new(Lru::new(capacity)),
+            ..self
+        }
+    }
+
+    /// Wrapped accessor
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+
+    /// Unwraps the accessor, dropping the cache
+    pub fn into_inner(self) -> C {
+        self.inner
+    }
+
+    /// Hits and misses so far
+    pub fn cache_stats(&self) -> CacheStats {
+        *lock(&self.stats)
+    }
+
+    /// Drops cached tag, so that it's refetched on the next call
+    pub fn invalidate_tag(&self, slug: &TagSlug) {
+        lock(&self.tags).remove(slug);
+    }
+
+    /// Drops cached user, so that they are refetched on the next call
+    pub fn invalidate_user(&self, name: &UserName) {
+        lock(&self.users).remove(name);
+    }
+
+    /// Drops cached article, so that it's refetched on the next call
+    pub fn invalidate_article(&self, slug: &ArticleSlug) {
+        lock(&self.articles).remove(slug);
+    }
+
+    /// Drops everything cached, keeping the stats
+    pub fn invalidate_all(&self) {
+        lock(&self.tags).clear();
+        lock(&self.users).clear();
+        lock(&self.articles).clear();
+    }
+
+    fn cached<K, V>(&self, cache: &Mutex<Lru<K, V>>, key: &K) -> Option<V>
+    where
+        K: Eq + Hash + Clone,
+        V: Clone + Aged,
+    {
+        let cached = lock(cache).get(key, self.ttl);
+        let mut stats = lock(&self.stats);
+        match cached {
+            Some(_) => stats.hits += 1,
+            None => stats.misses += 1,
+        }
+        cached
+    }
+}
+
+#[async_trait]
+impl<C> BaseApi for CachedDrukarnia<C>
+where
+    C: DrukarniaApi + Send + Sync,
+    C::Auth: AuthDrukarnia,
+{
+    type Auth = C::Auth;
+
+    fn base_url(&self) -> &Url {
+        self.inner.base_url()
+    }
+
+    async fn login_with(&self, credentials: Credentials, options: LoginOptions) -> Res<C::Auth> {
+        self.inner.login_with(credentials, options).await
+    }
+}
+
+#[async_trait]
+impl<C> UsersApi for CachedDrukarnia<C>
+where
+    C: DrukarniaApi + Send + Sync,
+    C::Auth: AuthDrukarnia,
+{
+    async fn get_user(&self, name: &UserName) -> Res<FullUser> {
+        if let Some(user) = self.cached(&self.users, name) {
+            return Ok(user);
+        }
+        let user = self.inner.get_user(name).await?;
+        lock(&self.users).insert(name.clone(), user.clone());
+        Ok(user)
+    }
+
+    async fn search_user_page(&self, name: &UserName, page: NonZeroUsize) -> Res<Vec<ShortUser>> {
+        self.inner.search_user_page(name, page).await
+    }
+
+    async fn search_user_page_meta(
+        &self,
+        name: &UserName,
+        page: NonZeroUsize,
+    ) -> Res<Page<ShortUser>> {
+        self.inner.search_user_page_meta(name, page).await
+    }
+
+    async fn search_user_page_plain(
+        &self,
+        name: &UserName,
+        page: NonZeroUsize,
+    ) -> Res<Vec<PlainShortUser>> {
+        self.inner.search_user_page_plain(name, page).await
+    }
+
+    async fn search_user_page_plain_meta(
+        &self,
+        name: &UserName,
+        page: NonZeroUsize,
+    ) -> Res<Page<PlainShortUser>> {
+        self.inner.search_user_page_plain_meta(name, page).await
+    }
+
+    async fn get_followers_page(&self, id: &UserId, page: NonZeroUsize) -> Res<Vec<FollowerUser>> {
+        self.inner.get_followers_page(id, page).await
+    }
+
+    async fn get_followers_page_meta(
+        &self,
+        id: &UserId,
+        page: NonZeroUsize,
+    ) -> Res<Page<FollowerUser>> {
+        self.inner.get_followers_page_meta(id, page).await
+    }
+}
+
+#[async_trait]
+impl<C> ArticlesApi for CachedDrukarnia<C>
+where
+    C: DrukarniaApi + Send + Sync,
+    C::Auth: AuthDrukarnia,
+{
+    async fn get_article(&self, slug: &ArticleSlug) -> Res<FullArticle> {
+        if let Some(article) = self.cached(&self.articles, slug) {
+            return Ok(article);
+        }
+        let article = self.inner.get_article(slug).await?;
+        lock(&self.articles).insert(slug.clone(), article.clone());
+        Ok(article)
+    }
+
+    async fn search_article_page(
+        &self,
+        name: &ArticleTitle,
+        page: NonZeroUsize,
+    ) -> Res<Vec<RecommendedArticle>> {
+        self.inner.search_article_page(name, page).await
+    }
+
+    async fn search_article_page_meta(
+        &self,
+        name: &ArticleTitle,
+        page: NonZeroUsize,
+    ) -> Res<Page<RecommendedArticle>> {
+        self.inner.search_article_page_meta(name, page).await
+    }
+
+    async fn download_image(&self, url: &MaybeUrl) -> Res<ImageBytes> {
+        self.inner.download_image(url).await
+    }
+}
+
+#[async_trait]
+impl<C> TagsApi for CachedDrukarnia<C>
+where
+    C: DrukarniaApi + Send + Sync,
+    C::Auth: AuthDrukarnia,
+{
+    async fn popular_tags(&self) -> Res<Vec<PopularTag>> {
+        self.inner.popular_tags().await
+    }
+
+    async fn get_tag(&self, slug: &TagSlug) -> Res<FullTag> {
+        if let Some(tag) = self.cached(&self.tags, slug) {
+            return Ok(tag);
+        }
+        let tag = self.inner.get_tag(slug).await?;
+        lock(&self.tags).insert(slug.clone(), tag.clone());
+        Ok(tag)
+    }
+}
+
+#[async_trait]
+impl<C> CommentsApi for CachedDrukarnia<C>
+where
+    C: DrukarniaApi + Send + Sync,
+    C::Auth: AuthDrukarnia,
+{
+    async fn get_replies(&self, comment: &CommentId) -> Res<Vec<ReplyComment>> {
+        self.inner.get_replies(comment).await
+    }
+}
+
+#[async_trait]
+impl<C> FeedApi for CachedDrukarnia<C>
+where
+    C: DrukarniaApi + Send + Sync,
+    C::Auth: AuthDrukarnia,
+{
+    async fn feed_page(&self, page: NonZeroUsize) -> Res<Vec<FeedArticle>> {
+        self.inner.feed_page(page).await
+    }
+
+    async fn feed_page_meta(&self, page: NonZeroUsize) -> Res<Page<FeedArticle>> {
+        self.inner.feed_page_meta(page).await
+    }
+
+    async fn feed_session(&self) -> Res<FeedSession> {
+        self.inner.feed_session().await
+    }
+
+    async fn feed_page_with(
+        &self,
+        session: &FeedSession,
+        page: NonZeroUsize,
+    ) -> Res<Vec<FeedArticle>> {
+        self.inner.feed_page_with(session, page).await
+    }
+
+    async fn feed_page_with_meta(
+        &self,
+        session: &FeedSession,
+        page: NonZeroUsize,
+    ) -> Res<Page<FeedArticle>> {
+        self.inner.feed_page_with_meta(session, page).await
+    }
+}
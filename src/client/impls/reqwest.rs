@@ -4,70 +4,108 @@ use async_trait::async_trait;
 use derive_more::Deref;
 use reqwest::{
     header::{self},
-    Client, Response, StatusCode,
+    Client, RequestBuilder, Response, StatusCode,
 };
 use secrecy::{ExposeSecret, SecretString};
 use tracing::info;
+use url::Url;
+use zeroize::Zeroizing;
 
 use crate::{
     client::{
-        ArticleId, ArticleSlug, ArticleTitle, AuthDrukarnia, AuthorizedUser, CommentId,
-        DrukarniaApi, Error, FullArticle, FullTag, FullUser, PopularTag, Res, ShortUser, TagSlug,
-        UserName,
+        ArticleId, ArticleSlug, ArticleTitle, ArticlesApi, AuthDrukarnia, AuthorizedUser, BaseApi,
+        CommentId, CommentsApi, DrukarniaClient, Error, FeedApi, FeedSession, FullArticle, FullTag,
+        FullUser, ImageBytes, LoginOptions, Page, PlainShortUser, PopularTag, Res, ShortUser,
+        TagSlug, TagsApi, UserName, UsersApi,
     },
     object::{
-        FeedArticle, FollowerUser, FullBookmark, FullList, ListArticle, ListId, RecommendedArticle,
+        CommentLikeState, Credentials, FeedArticle, FollowerUser, FullBookmark, FullList,
+        LikeAmount, ListArticle, ListId, LoginIdentifier, MaybeUrl, RecommendedArticle,
         ReplyComment, UserId,
     },
 };
 
-static USER_AGENT: &str = "type-matrux/0.1.0";
-
 /// Super-angry message explaining that url arithmetic is correct
 ///
 /// Should not be shown to the end-user, if crate was tested properly
 static ANGRY_URL: &str = "Should be able to append endpoint to base url";
 
+/// Appends a single path segment (like username or slug) to the endpoint
+///
+/// Segment is percent-encoded, so it never introduces new path components, even if it contains `/`, `?` or `#`.
+///
+/// # Returns
+/// `None` for empty, `.` and `..` segments, since these would point to another endpoint instead.
+fn endpoint_with_segment(base: &Url, endpoint: &str, segment: &str) -> Option<Url> {
+    if matches!(segment, "" | "." | "..") {
+        return None;
+    }
+    let mut url = base.join(endpoint).expect(ANGRY_URL);
+    url.path_segments_mut()
+        .expect(ANGRY_URL)
+        .pop_if_empty()
+        .push(segment);
+    Some(url)
+}
+
 /// A convenience macro to set user agent header, send a request, await it and map-return any request error
 ///
 /// Not intended to be used outside of this module, as it's tied to `reqwest` crate functions
 macro_rules! send_ok {
-    ($req:expr) => {
-        $req.header(header::USER_AGENT, USER_AGENT)
-            .header(header::HOST, "drukarnia.com.ua")
+    ($api:expr, $req:expr) => {{
+        $api.wait_turn().await;
+        $req.header(header::USER_AGENT, $api.user_agent())
             .send()
             .await
             .map_err(|err| super::super::Error::OnExecution(Box::new(err)))?
-    };
+    }};
 }
 
 static CONTEXT_SIZE: usize = 30;
 
 /// A convenience macro to parse a response to json, await for a result and map-return any error
 ///
+/// With `raw-json` feature, response text is attached to the parsed object.
+///
 /// Not intended to be used outside of this module, as it's tied to `reqwest` crate functions
 macro_rules! json_ok {
     ($res:expr, $tp:ty) => {{
-        let text: String = $res
-            .text()
+        let body = $res
+            .bytes()
             .await
             .map_err(|err| super::super::Error::OnExecution(Box::new(err)))?;
-        serde_json::from_str::<$tp>(text.as_str()).map_err(|err| {
-            let line = err.line();
-            let line = text
-                .lines()
-                .nth(line - 1)
-                .expect("Line number should be valid");
-            let column = err.column();
-            let cause = line[column.saturating_sub(CONTEXT_SIZE)
-                ..std::cmp::min(column + CONTEXT_SIZE, line.len())]
-                .to_owned();
+        let parsed = serde_json::from_slice::<$tp>(&body).map_err(|err| {
+            let cause = bad_json_cause(&body, &err);
             super::super::Error::BadJson(err, cause)
-        })?
+        })?;
+        #[cfg(feature = "raw-json")]
+        let parsed = {
+            let mut parsed = parsed;
+            // body was just parsed as JSON, so it's valid UTF-8
+            if let Ok(text) = std::str::from_utf8(&body) {
+                crate::object::AttachRaw::attach_raw(&mut parsed, text);
+            }
+            parsed
+        };
+        parsed
     }};
 }
 
-#[allow(unused)]
+/// Part of the response body around the position JSON deserializing has failed at
+///
+/// Only built on failure, so that successful responses are never copied.
+fn bad_json_cause(body: &[u8], err: &serde_json::Error) -> String {
+    let line = body
+        .split(|&byte| byte == b'\n')
+        .nth(err.line().saturating_sub(1))
+        .unwrap_or_default();
+    let column = err.column().min(line.len());
+    let context =
+        &line[column.saturating_sub(CONTEXT_SIZE)..(column + CONTEXT_SIZE).min(line.len())];
+    // context might cut a character in half
+    String::from_utf8_lossy(context).into_owned()
+}
+
 fn extract_token(res: &Response) -> Option<SecretString> {
     res.headers()
         .into_iter()
@@ -78,34 +116,118 @@ fn extract_token(res: &Response) -> Option<SecretString> {
         .map(|v| SecretString::new(v.to_owned()))
 }
 
+/// Collects `name=value` pairs of every cookie set in response, attributes dropped
+fn extract_cookies(res: &Response) -> FeedSession {
+    let cookies: Vec<&str> = res
+        .headers()
+        .get_all(header::SET_COOKIE)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .filter_map(|value| value.split(';').next())
+        .map(str::trim)
+        .filter(|pair| !pair.is_empty())
+        .collect();
+    FeedSession::new(cookies.join("; "))
+}
+
+/// Serializes login request body, so that it's zeroized once dropped
+///
+/// Request gets it's own copy of the body, which is out of reach, unfortunately.
+fn login_body(credentials: &Credentials) -> Zeroizing<Vec<u8>> {
+    #[derive(serde::Serialize)]
+    struct Body<'c> {
+        /// `email` or `username` field
+        #[serde(flatten)]
+        identifier: &'c LoginIdentifier,
+        password: &'c str,
+    }
+
+    Zeroizing::new(
+        serde_json::to_vec(&Body {
+            identifier: credentials.identifier(),
+            password: credentials.password().expose_secret(),
+        })
+        .expect("Should be able to serialize login body"),
+    )
+}
+
 #[derive(Debug, serde::Deserialize)]
-#[allow(unused)]
 struct AuthResponse {
     user: AuthorizedUser,
 }
 
+/// Authorized user gets it's own part of the response
+#[cfg(feature = "raw-json")]
+impl crate::object::AttachRaw for AuthResponse {
+    fn attach_raw(&mut self, raw: &str) {
+        #[derive(serde::Deserialize)]
+        struct Raw<'r> {
+            #[serde(borrow)]
+            user: &'r serde_json::value::RawValue,
+        }
+
+        if let Ok(Raw { user }) = serde_json::from_str(raw) {
+            self.user.attach_raw(user.get());
+        }
+    }
+}
+
 #[async_trait]
-impl DrukarniaApi for Client {
+impl BaseApi for DrukarniaClient {
     type Auth = Auth;
 
-    #[tracing::instrument(name = "Fetching popular tags")]
-    async fn popular_tags(&self) -> Res<Vec<PopularTag>> {
-        const ENDPOINT: &str = "/api/articles/tags/popular";
+    fn base_url(&self) -> &Url {
+        &self.base_url
+    }
+
+    #[tracing::instrument(name = "Authenticating user", skip(credentials))]
+    async fn login_with(&self, credentials: Credentials, options: LoginOptions) -> Res<Self::Auth> {
+        static ENDPOINT: &str = "/api/users/login";
         let url = self.base_url().join(ENDPOINT).expect(ANGRY_URL);
-        let response = send_ok!(self.get(url));
-        let tag = json_ok!(response, Vec<PopularTag>);
-        Ok(tag)
+        let body = login_body(&credentials);
+        // user agent might be overridden, so `send_ok!` is not applicable here
+        let mut request = self
+            .post(url)
+            .body(body.to_vec())
+            .header(header::CONTENT_TYPE, mime::APPLICATION_JSON.essence_str())
+            .header(
+                header::USER_AGENT,
+                options.user_agent().as_deref().unwrap_or(self.user_agent()),
+            );
+        if let Some(referer) = options.referer() {
+            request = request
+                .header(header::REFERER, referer.as_str())
+                .header(header::ORIGIN, referer.origin().ascii_serialization());
+        }
+        for (name, value) in options.headers() {
+            request = request.header(name.as_str(), value.as_str());
+        }
+        let response = request
+            .send()
+            .await
+            .map_err(|err| Error::OnExecution(Box::new(err)))?;
+
+        if response.status() == StatusCode::NOT_FOUND {
+            // "Такого юзера не існує або невірний пароль"
+            return Err(Error::BadCredentials);
+        }
+
+        let token = extract_token(&response).ok_or(Error::NoToken)?;
+        let auth_user = json_ok!(response, AuthResponse).user;
+        // cookie store is not enabled, so the token is only sent by session itself
+        Ok(Auth(self.clone(), auth_user, token, false))
     }
+}
 
+#[async_trait]
+impl UsersApi for DrukarniaClient {
     #[tracing::instrument(name = "Loading user")]
     async fn get_user(&self, name: &UserName) -> Res<FullUser> {
         const ENDPOINT: &str = "/api/users/profile/";
-        let url = self
-            .base_url()
-            .join(ENDPOINT)
-            .and_then(|endpoint| endpoint.join(name.as_ref()))
-            .expect(ANGRY_URL);
-        let response = send_ok!(self.get(url));
+        let Some(url) = endpoint_with_segment(self.base_url(), ENDPOINT, name.as_ref()) else {
+            return Err(Error::NoObject);
+        };
+        let response = send_ok!(self, self.get(url));
 
         if response.status() == StatusCode::NOT_FOUND {
             // User does not exist
@@ -124,42 +246,113 @@ impl DrukarniaApi for Client {
             .append_pair("name", name.as_ref())
             .append_pair("page", &page.to_string())
             .append_pair("withRelationships", "true");
-        let response = send_ok!(self.get(url));
+        let response = send_ok!(self, self.get(url));
         let users_page = json_ok!(response, Vec<ShortUser>);
         Ok(users_page)
     }
 
-    #[tracing::instrument(name = "Loading tag")]
-    async fn get_tag(&self, slug: &TagSlug) -> Res<FullTag> {
-        const ENDPOINT: &str = "/api/articles/tags/";
+    async fn search_user_page_meta(
+        &self,
+        name: &UserName,
+        page: NonZeroUsize,
+    ) -> Res<Page<ShortUser>> {
+        let users = self.search_user_page(name, page).await?;
+        Ok(Page::with_page_size(users, page, self.page_size()))
+    }
+
+    #[tracing::instrument(name = "Searching user without relationships")]
+    async fn search_user_page_plain(
+        &self,
+        name: &UserName,
+        page: NonZeroUsize,
+    ) -> Res<Vec<PlainShortUser>> {
+        const ENDPOINT: &str = "/api/users/info";
+        let mut url = self.base_url().join(ENDPOINT).expect(ANGRY_URL);
+        url.query_pairs_mut()
+            .append_pair("name", name.as_ref())
+            .append_pair("page", &page.to_string());
+        let response = send_ok!(self, self.get(url));
+        let users_page = json_ok!(response, Vec<PlainShortUser>);
+        Ok(users_page)
+    }
+
+    async fn search_user_page_plain_meta(
+        &self,
+        name: &UserName,
+        page: NonZeroUsize,
+    ) -> Res<Page<PlainShortUser>> {
+        let users = self.search_user_page_plain(name, page).await?;
+        Ok(Page::with_page_size(users, page, self.page_size()))
+    }
+
+    #[tracing::instrument(name = "Loading followers")]
+    async fn get_followers_page(&self, id: &UserId, page: NonZeroUsize) -> Res<Vec<FollowerUser>> {
+        const ENDPOINT: &str = "/api/relationships/";
         let mut url = self
             .base_url()
             .join(ENDPOINT)
-            .and_then(|endpoint| endpoint.join(slug.as_ref()))
+            .and_then(|endpoint| endpoint.join(&format!("{}/followers", id)))
             .expect(ANGRY_URL);
-        // FIXME not really sure why should I add this here,
-        // but the site returns 404 otherwise :idk:
-        url.query_pairs_mut().append_pair("page", "1");
-        let response = send_ok!(self.get(url));
+        url.query_pairs_mut().append_pair("page", &page.to_string());
+        let response = send_ok!(self, self.get(url));
+        let followers = json_ok!(response, Vec<FollowerUser>);
+        Ok(followers)
+    }
 
+    async fn get_followers_page_meta(
+        &self,
+        id: &UserId,
+        page: NonZeroUsize,
+    ) -> Res<Page<FollowerUser>> {
+        let followers = self.get_followers_page(id, page).await?;
+        Ok(Page::with_page_size(followers, page, self.page_size()))
+    }
+}
+
+#[async_trait]
+impl ArticlesApi for DrukarniaClient {
+    #[tracing::instrument(name = "Downloading image")]
+    async fn download_image(&self, url: &MaybeUrl) -> Res<ImageBytes> {
+        let url = match url {
+            MaybeUrl::Url(url) => url.clone(),
+            MaybeUrl::BadUrl(source, reason) => {
+                return Err(Error::InvalidUrl(source.clone(), reason.clone()))
+            }
+        };
+        // images are located at other host, so `send_ok!` is not applicable here
+        let response = self
+            .get(url)
+            .header(header::USER_AGENT, self.user_agent())
+            .send()
+            .await
+            .map_err(|err| Error::OnExecution(Box::new(err)))?;
         if response.status() == StatusCode::NOT_FOUND {
-            // Tag does not exist
+            // Image does not exist
             return Err(Error::NoObject);
         }
+        let response = response
+            .error_for_status()
+            .map_err(|err| Error::OnExecution(Box::new(err)))?;
 
-        let tag = json_ok!(response, FullTag);
-        Ok(tag)
+        let content_type = response
+            .headers()
+            .get(header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse().ok());
+        let bytes = response
+            .bytes()
+            .await
+            .map_err(|err| Error::OnExecution(Box::new(err)))?;
+        Ok(ImageBytes::new(content_type, bytes.into()))
     }
 
     #[tracing::instrument(name = "Loading article")]
     async fn get_article(&self, slug: &ArticleSlug) -> Res<FullArticle> {
         const ENDPOINT: &str = "/api/articles/";
-        let url = self
-            .base_url()
-            .join(ENDPOINT)
-            .and_then(|endpoint| endpoint.join(slug.as_ref()))
-            .expect(ANGRY_URL);
-        let response = send_ok!(self.get(url));
+        let Some(url) = endpoint_with_segment(self.base_url(), ENDPOINT, slug.as_ref()) else {
+            return Err(Error::NoObject);
+        };
+        let response = send_ok!(self, self.get(url));
         if response.status() == StatusCode::NOT_FOUND {
             // Article does not exist
             return Err(Error::NoObject);
@@ -180,25 +373,55 @@ impl DrukarniaApi for Client {
         url.query_pairs_mut()
             .append_pair("name", name.as_ref())
             .append_pair("page", &page.to_string());
-        let response = send_ok!(self.get(url));
+        let response = send_ok!(self, self.get(url));
         let articles = json_ok!(response, Vec<RecommendedArticle>);
         Ok(articles)
     }
 
-    #[tracing::instrument(name = "Loading followers")]
-    async fn get_followers_page(&self, id: &UserId, page: NonZeroUsize) -> Res<Vec<FollowerUser>> {
-        const ENDPOINT: &str = "/api/relationships/";
-        let mut url = self
-            .base_url()
-            .join(ENDPOINT)
-            .and_then(|endpoint| endpoint.join(&format!("{}/followers", id)))
-            .expect(ANGRY_URL);
-        url.query_pairs_mut().append_pair("page", &page.to_string());
-        let response = send_ok!(self.get(url));
-        let followers = json_ok!(response, Vec<FollowerUser>);
-        Ok(followers)
+    async fn search_article_page_meta(
+        &self,
+        name: &ArticleTitle,
+        page: NonZeroUsize,
+    ) -> Res<Page<RecommendedArticle>> {
+        let articles = self.search_article_page(name, page).await?;
+        Ok(Page::with_page_size(articles, page, self.page_size()))
+    }
+}
+
+#[async_trait]
+impl TagsApi for DrukarniaClient {
+    #[tracing::instrument(name = "Fetching popular tags")]
+    async fn popular_tags(&self) -> Res<Vec<PopularTag>> {
+        const ENDPOINT: &str = "/api/articles/tags/popular";
+        let url = self.base_url().join(ENDPOINT).expect(ANGRY_URL);
+        let response = send_ok!(self, self.get(url));
+        let tag = json_ok!(response, Vec<PopularTag>);
+        Ok(tag)
     }
 
+    #[tracing::instrument(name = "Loading tag")]
+    async fn get_tag(&self, slug: &TagSlug) -> Res<FullTag> {
+        const ENDPOINT: &str = "/api/articles/tags/";
+        let Some(mut url) = endpoint_with_segment(self.base_url(), ENDPOINT, slug.as_ref()) else {
+            return Err(Error::NoObject);
+        };
+        // FIXME not really sure why should I add this here,
+        // but the site returns 404 otherwise :idk:
+        url.query_pairs_mut().append_pair("page", "1");
+        let response = send_ok!(self, self.get(url));
+
+        if response.status() == StatusCode::NOT_FOUND {
+            // Tag does not exist
+            return Err(Error::NoObject);
+        }
+
+        let tag = json_ok!(response, FullTag);
+        Ok(tag)
+    }
+}
+
+#[async_trait]
+impl CommentsApi for DrukarniaClient {
     #[tracing::instrument(name = "Getting replies")]
     async fn get_replies(&self, comment: &CommentId) -> Res<Vec<ReplyComment>> {
         const ENDPOINT: &str = "/api/articles/000000000000000000000000/comments/";
@@ -207,7 +430,7 @@ impl DrukarniaApi for Client {
             .join(ENDPOINT)
             .and_then(|article_comments| article_comments.join(&format!("{}/replies", comment)))
             .expect(ANGRY_URL);
-        let response = send_ok!(self.get(url));
+        let response = send_ok!(self, self.get(url));
 
         if response.status() == StatusCode::UNAUTHORIZED {
             return Err(Error::NoObject);
@@ -218,9 +441,10 @@ impl DrukarniaApi for Client {
         let comments = json_ok!(response, Vec<ReplyComment>);
         Ok(comments)
     }
+}
 
-    #[tracing::instrument(name = "Loading feed page")]
-    async fn feed_page(&self, page: NonZeroUsize) -> Res<Vec<FeedArticle>> {
+impl DrukarniaClient {
+    fn feed_request(&self, session: &FeedSession, page: NonZeroUsize) -> RequestBuilder {
         const ENDPOINT: &str = "/api/preferences/feed";
         let url = self
             .base_url()
@@ -232,71 +456,400 @@ impl DrukarniaApi for Client {
                 endpoint
             })
             .expect(ANGRY_URL);
-        let response = send_ok!(self.get(url));
+        let request = self.get(url);
+        match session.cookie() {
+            Some(cookie) => request.header(header::COOKIE, cookie),
+            None => request,
+        }
+    }
+}
 
-        // TODO add assertions for expected response code in all of the functions
-        assert_eq!(response.status(), StatusCode::OK, "Unexpected status code");
+#[async_trait]
+impl FeedApi for DrukarniaClient {
+    async fn feed_page(&self, page: NonZeroUsize) -> Res<Vec<FeedArticle>> {
+        self.feed_page_with(&FeedSession::default(), page).await
+    }
+
+    async fn feed_page_meta(&self, page: NonZeroUsize) -> Res<Page<FeedArticle>> {
+        let feed_articles = self.feed_page(page).await?;
+        Ok(Page::with_page_size(feed_articles, page, self.page_size()))
+    }
+
+    #[tracing::instrument(name = "Establishing feed session")]
+    async fn feed_session(&self) -> Res<FeedSession> {
+        let first_page = NonZeroUsize::new(1).expect("1 != 0");
+        let response = send_ok!(self, self.feed_request(&FeedSession::default(), first_page));
+        let response = response
+            .error_for_status()
+            .map_err(|err| Error::OnExecution(Box::new(err)))?;
+        let session = extract_cookies(&response);
+        let feed_articles = json_ok!(response, Vec<FeedArticle>);
+        Ok(session.with_first_page(feed_articles))
+    }
+
+    #[tracing::instrument(name = "Loading feed page", skip(session))]
+    async fn feed_page_with(
+        &self,
+        session: &FeedSession,
+        page: NonZeroUsize,
+    ) -> Res<Vec<FeedArticle>> {
+        if let (1, Some(first_page)) = (page.get(), session.first_page()) {
+            return Ok(first_page.to_vec());
+        }
+        let response = send_ok!(self, self.feed_request(session, page));
+        let response = response
+            .error_for_status()
+            .map_err(|err| Error::OnExecution(Box::new(err)))?;
 
         let feed_articles = json_ok!(response, Vec<FeedArticle>);
         Ok(feed_articles)
     }
 
-    /*
-    #[tracing::instrument(name = "Authenticating user")]
-    #[allow(unused)]
-    async fn login(&self, credentials: Credentials) -> Res<Self::Auth> {
-        static ENDPOINT: &str = "/api/users/login";
-        let url = self.base_url().join(ENDPOINT).expect(ANGRY_URL);
-        let body = format!(
-            r#"
-            {{
-                "email": "{}",
-                "password": "{}"
-            }}
-            "#,
-            credentials.email(),
-            credentials.password().expose_secret()
-        );
-        let response = send_ok!(self
-            .post(url)
-            .body(body)
-            .header(header::CONTENT_TYPE, mime::APPLICATION_JSON.essence_str()));
+    async fn feed_page_with_meta(
+        &self,
+        session: &FeedSession,
+        page: NonZeroUsize,
+    ) -> Res<Page<FeedArticle>> {
+        let feed_articles = self.feed_page_with(session, page).await?;
+        Ok(Page::with_page_size(feed_articles, page, self.page_size()))
+    }
+}
 
-        if response.status() == StatusCode::NOT_FOUND {
-            // "Такого юзера не існує або невірний пароль"
-            return Err(Error::BadCredentials);
-        }
+/// Calls the site itself, see [`DrukarniaClient`] to call other hosts
+///
+/// Cloning a client is cheap, so every call is just delegated to the default [`DrukarniaClient`].
+#[async_trait]
+impl BaseApi for Client {
+    type Auth = Auth;
 
-        let token = extract_token(&response).ok_or(Error::NoToken)?;
-        let auth_user = json_ok!(response, AuthResponse).user;
-        let new_client = Client::builder()
-            .default_headers(HeaderMap::new())
-            .build()
-            .expect("Should be able to build new client");
-        Ok(Auth(new_client, auth_user, token))
+    async fn login_with(&self, credentials: Credentials, options: LoginOptions) -> Res<Self::Auth> {
+        DrukarniaClient::from(self.clone())
+            .login_with(credentials, options)
+            .await
     }
-    */
 }
 
-/// [`reqwest::Client`] wrapper, that's currently authorized on the site
-#[derive(Debug, Deref)]
-pub struct Auth(#[deref] Client, AuthorizedUser, SecretString);
+#[async_trait]
+impl UsersApi for Client {
+    async fn get_user(&self, name: &UserName) -> Res<FullUser> {
+        DrukarniaClient::from(self.clone()).get_user(name).await
+    }
 
+    async fn search_user_page(&self, name: &UserName, page: NonZeroUsize) -> Res<Vec<ShortUser>> {
+        DrukarniaClient::from(self.clone())
+            .search_user_page(name, page)
+            .await
+    }
+
+    async fn search_user_page_meta(
+        &self,
+        name: &UserName,
+        page: NonZeroUsize,
+    ) -> Res<Page<ShortUser>> {
+        DrukarniaClient::from(self.clone())
+            .search_user_page_meta(name, page)
+            .await
+    }
+
+    async fn search_user_page_plain(
+        &self,
+        name: &UserName,
+        page: NonZeroUsize,
+    ) -> Res<Vec<PlainShortUser>> {
+        DrukarniaClient::from(self.clone())
+            .search_user_page_plain(name, page)
+            .await
+    }
+
+    async fn search_user_page_plain_meta(
+        &self,
+        name: &UserName,
+        page: NonZeroUsize,
+    ) -> Res<Page<PlainShortUser>> {
+        DrukarniaClient::from(self.clone())
+            .search_user_page_plain_meta(name, page)
+            .await
+    }
+
+    async fn get_followers_page(&self, id: &UserId, page: NonZeroUsize) -> Res<Vec<FollowerUser>> {
+        DrukarniaClient::from(self.clone())
+            .get_followers_page(id, page)
+            .await
+    }
+
+    async fn get_followers_page_meta(
+        &self,
+        id: &UserId,
+        page: NonZeroUsize,
+    ) -> Res<Page<FollowerUser>> {
+        DrukarniaClient::from(self.clone())
+            .get_followers_page_meta(id, page)
+            .await
+    }
+}
+
+#[async_trait]
+impl ArticlesApi for Client {
+    async fn download_image(&self, url: &MaybeUrl) -> Res<ImageBytes> {
+        DrukarniaClient::from(self.clone())
+            .download_image(url)
+            .await
+    }
+
+    async fn get_article(&self, slug: &ArticleSlug) -> Res<FullArticle> {
+        DrukarniaClient::from(self.clone()).get_article(slug).await
+    }
+
+    async fn search_article_page(
+        &self,
+        name: &ArticleTitle,
+        page: NonZeroUsize,
+    ) -> Res<Vec<RecommendedArticle>> {
+        DrukarniaClient::from(self.clone())
+            .search_article_page(name, page)
+            .await
+    }
+
+    async fn search_article_page_meta(
+        &self,
+        name: &ArticleTitle,
+        page: NonZeroUsize,
+    ) -> Res<Page<RecommendedArticle>> {
+        DrukarniaClient::from(self.clone())
+            .search_article_page_meta(name, page)
+            .await
+    }
+}
+
+#[async_trait]
+impl TagsApi for Client {
+    async fn popular_tags(&self) -> Res<Vec<PopularTag>> {
+        DrukarniaClient::from(self.clone()).popular_tags().await
+    }
+
+    async fn get_tag(&self, slug: &TagSlug) -> Res<FullTag> {
+        DrukarniaClient::from(self.clone()).get_tag(slug).await
+    }
+}
+
+#[async_trait]
+impl CommentsApi for Client {
+    async fn get_replies(&self, comment: &CommentId) -> Res<Vec<ReplyComment>> {
+        DrukarniaClient::from(self.clone())
+            .get_replies(comment)
+            .await
+    }
+}
+
+#[async_trait]
+impl FeedApi for Client {
+    async fn feed_page(&self, page: NonZeroUsize) -> Res<Vec<FeedArticle>> {
+        DrukarniaClient::from(self.clone()).feed_page(page).await
+    }
+
+    async fn feed_page_meta(&self, page: NonZeroUsize) -> Res<Page<FeedArticle>> {
+        DrukarniaClient::from(self.clone())
+            .feed_page_meta(page)
+            .await
+    }
+
+    async fn feed_session(&self) -> Res<FeedSession> {
+        DrukarniaClient::from(self.clone()).feed_session().await
+    }
+
+    async fn feed_page_with(
+        &self,
+        session: &FeedSession,
+        page: NonZeroUsize,
+    ) -> Res<Vec<FeedArticle>> {
+        DrukarniaClient::from(self.clone())
+            .feed_page_with(session, page)
+            .await
+    }
+
+    async fn feed_page_with_meta(
+        &self,
+        session: &FeedSession,
+        page: NonZeroUsize,
+    ) -> Res<Page<FeedArticle>> {
+        DrukarniaClient::from(self.clone())
+            .feed_page_with_meta(session, page)
+            .await
+    }
+}
+
+/// Same as `send_ok!`, but authorizes the request with a token
+///
+/// Requests without a body are sent as an empty form, as the site does.
+/// Requests with JSON body should pass it as `json = &body`, so that body headers are not overridden.
 macro_rules! auth_send_ok {
-    ($req:expr, $t:expr) => {
-        send_ok!($req
-            .header(header::COOKIE, $t.expose_secret())
-            .header(
-                header::CONTENT_TYPE,
-                mime::APPLICATION_WWW_FORM_URLENCODED.essence_str()
-            )
-            .header(header::CONTENT_LENGTH, 0))
+    ($api:expr, $req:expr, $t:expr, json = $body:expr) => {
+        send_ok!(
+            $api,
+            $req.header(header::COOKIE, $t.expose_secret()).json($body)
+        )
+    };
+    ($api:expr, $req:expr, $t:expr) => {
+        send_ok!(
+            $api,
+            $req.header(header::COOKIE, $t.expose_secret())
+                .header(
+                    header::CONTENT_TYPE,
+                    mime::APPLICATION_WWW_FORM_URLENCODED.essence_str()
+                )
+                .header(header::CONTENT_LENGTH, 0)
+        )
     };
 }
 
+/// [`reqwest::Client`] wrapper, that's currently authorized on the site
+///
+/// `Debug` output only contains authorized user's id and username, and no token.
+#[derive(Deref)]
+pub struct Auth(
+    #[deref] DrukarniaClient,
+    AuthorizedUser,
+    SecretString,
+    /// Whether session was split into parts, and thus should not be logged out on drop
+    #[cfg_attr(not(feature = "logout-on-drop"), allow(dead_code))]
+    bool,
+);
+
+impl std::fmt::Debug for Auth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Auth")
+            .field("api", &self.0)
+            .field("user_id", self.1.id())
+            .field("username", self.1.username())
+            .field("token", &format_args!("[REDACTED]"))
+            .finish()
+    }
+}
+
+impl Auth {
+    /// Resumes a session from a token, previously obtained with [`Auth::session_token`]
+    ///
+    /// Token is validated by requesting user's bookmark lists, so no login is performed.
+    /// Since there's no known endpoint returning authorized user's data, it's snapshot should be supplied as well.
+    ///
+    /// # Errors
+    /// - [`Error::SessionExpired`]: token is not valid anymore
+    /// - [`Error::NoToken`]: token is empty
+    #[tracing::instrument(name = "Resuming a session", skip(client, token))]
+    pub async fn from_token(
+        client: impl Into<DrukarniaClient>,
+        user: AuthorizedUser,
+        token: SecretString,
+    ) -> Res<Self> {
+        static ENDPOINT: &str = "/api/articles/bookmarks/lists";
+        let client = client.into();
+        if token.expose_secret().is_empty() {
+            return Err(Error::NoToken);
+        }
+        let url = client.base_url().join(ENDPOINT).expect(ANGRY_URL);
+        let response = auth_send_ok!(client, client.get(url), token);
+        if matches!(
+            response.status(),
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN
+        ) {
+            return Err(Error::SessionExpired);
+        }
+        response
+            .error_for_status()
+            .map_err(|err| Error::OnExecution(Box::new(err)))?;
+        Ok(Self(client, user, token, false))
+    }
+
+    /// Wraps supplied session parts without validating them, for tests only
+    #[cfg(feature = "test-constructors")]
+    pub fn new_for_tests(
+        client: impl Into<DrukarniaClient>,
+        user: AuthorizedUser,
+        token: SecretString,
+    ) -> Self {
+        Self(client.into(), user, token, false)
+    }
+
+    /// Underlying unauthorized HTTP client
+    pub fn client(&self) -> &Client {
+        self.0.client()
+    }
+
+    /// Accessor this session was obtained with, i.e. [`AuthDrukarnia::Downgrade`]
+    ///
+    /// Keeps the base url session was obtained at, so anonymous calls are made to the same host.
+    pub fn downgrade(&self) -> &DrukarniaClient {
+        &self.0
+    }
+
+    /// Splits session into the accessor, authorized user and session token
+    ///
+    /// Session is not logged out, so client can still be used, and token might be resumed with [`Auth::from_token`].
+    pub fn into_parts(mut self) -> (DrukarniaClient, AuthorizedUser, SecretString) {
+        self.3 = true;
+        // fields can't be moved out of a `Drop` type, token is swapped out so that it's never copied
+        let token = std::mem::replace(&mut self.2, SecretString::new(String::new()));
+        (self.0.clone(), self.1.clone(), token)
+    }
+
+    /// Token of this session, so that it could be persisted and resumed with [`Auth::from_token`]
+    pub fn session_token(&self) -> &SecretString {
+        &self.2
+    }
+
+    /// Saves session token and authorized user's snapshot as a JSON file
+    ///
+    /// On Unix, file is only accessible to it's owner. Password is never written.
+    ///
+    /// # Errors
+    /// If file could not be written
+    #[cfg(feature = "session-store")]
+    pub fn save_session(&self, path: impl AsRef<std::path::Path>) -> std::io::Result<()> {
+        use std::io::Write;
+
+        let session = serde_json::to_vec(&StoredSession {
+            token: self.2.expose_secret(),
+            user: &self.1,
+        })?;
+        let mut options = std::fs::OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+        options.open(path)?.write_all(&session)
+    }
+
+    /// Restores a session, saved with [`Auth::save_session`]
+    ///
+    /// Stored token is validated with [`Auth::from_token`], rather than trusted.
+    ///
+    /// # Errors
+    /// - [`Error::SessionFile`]: file could not be read
+    /// - [`Error::BadJson`]: file does not contain a stored session
+    /// - same as [`Auth::from_token`]
+    #[cfg(feature = "session-store")]
+    pub async fn restore_session(
+        client: impl Into<DrukarniaClient>,
+        path: impl AsRef<std::path::Path>,
+    ) -> Res<Self> {
+        let text = std::fs::read_to_string(path).map_err(Error::SessionFile)?;
+        let StoredSession { token, user } =
+            serde_json::from_str::<StoredSession<String, AuthorizedUser>>(&text)
+                .map_err(|err| Error::BadJson(err, String::new()))?;
+        Self::from_token(client, user, SecretString::new(token)).await
+    }
+}
+
+/// Session, as stored by [`Auth::save_session`]
+#[cfg(feature = "session-store")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct StoredSession<T, U> {
+    token: T,
+    user: U,
+}
+
 #[async_trait]
 impl AuthDrukarnia for Auth {
-    type Downgrade = Client;
+    type Downgrade = DrukarniaClient;
 
     fn authorized_user(&self) -> &AuthorizedUser {
         &self.1
@@ -322,7 +875,7 @@ impl AuthDrukarnia for Auth {
         } else {
             self.delete(url)
         };
-        let response = auth_send_ok!(request, self.2);
+        let response = auth_send_ok!(self, request, self.2);
         assert_eq!(
             response.status(),
             if follow {
@@ -346,7 +899,7 @@ impl AuthDrukarnia for Auth {
     async fn get_bookmark_lists(&self) -> Res<Vec<FullList>> {
         static ENDPOINT: &str = "/api/articles/bookmarks/lists";
         let url = self.base_url().join(ENDPOINT).expect(ANGRY_URL);
-        let response = auth_send_ok!(self.get(url), self.2);
+        let response = auth_send_ok!(self, self.get(url), self.2);
         let lists = json_ok!(response, Vec<FullList>);
         Ok(lists)
     }
@@ -355,21 +908,11 @@ impl AuthDrukarnia for Auth {
     async fn bookmark_article(&self, list: &ListId, article: &ArticleId) -> Res<FullBookmark> {
         static ENDPOINT: &str = "/api/articles/bookmarks";
         let url = self.base_url().join(ENDPOINT).expect(ANGRY_URL);
-        let body = format!(
-            r#"
-            {{
-                "article": {},
-                "list": {}
-            }}
-            "#,
-            article, list
-        );
-        let response = auth_send_ok!(
-            self.post(url)
-                .body(body)
-                .header(header::CONTENT_TYPE, mime::APPLICATION_JSON.essence_str()),
-            self.2
-        );
+        let body = serde_json::json!({
+            "article": article,
+            "list": list,
+        });
+        let response = auth_send_ok!(self, self.post(url), self.2, json = &body);
         let bookmark = json_ok!(response, FullBookmark);
         Ok(bookmark)
     }
@@ -382,7 +925,7 @@ impl AuthDrukarnia for Auth {
             .join(ENDPOINT)
             .and_then(|endpoint| endpoint.join(&format!("{}/bookmarks", article)))
             .expect(ANGRY_URL);
-        let response = auth_send_ok!(self.delete(url), self.2);
+        let response = auth_send_ok!(self, self.delete(url), self.2);
         let bookmark = json_ok!(response, FullBookmark);
         Ok(bookmark)
     }
@@ -395,34 +938,29 @@ impl AuthDrukarnia for Auth {
             .join(ENDPOINT)
             .and_then(|endpoint| endpoint.join(&list.to_string()))
             .expect(ANGRY_URL);
-        let response = auth_send_ok!(self.get(url), self.2);
+        let response = auth_send_ok!(self, self.get(url), self.2);
 
         let list = json_ok!(response, Vec<ListArticle>);
         Ok(list)
     }
 
     #[tracing::instrument(name = "Liking article")]
-    async fn like_article(&self, article: &ArticleId, likes: usize) -> Res {
+    async fn like_article(&self, article: &ArticleId, amount: LikeAmount) -> Res {
         static ENDPOINT: &str = "/api/articles/";
         let url = self
             .base_url()
             .join(ENDPOINT)
             .and_then(|endpoint| endpoint.join(&format!("{}/like", article)))
             .expect(ANGRY_URL);
-        let body = format!(
-            r#"
-            {{
-                "likes": {}
-            }}
-            "#,
-            likes
-        );
-        let _ = auth_send_ok!(
-            self.post(url)
-                .body(body)
-                .header(header::CONTENT_TYPE, mime::APPLICATION_JSON.essence_str()),
-            self.2
-        );
+        let body = serde_json::json!({ "likes": amount });
+        let response = auth_send_ok!(self, self.post(url), self.2, json = &body);
+        if response.status() == StatusCode::NOT_FOUND {
+            // Article does not exist
+            return Err(Error::NoObject);
+        }
+        response
+            .error_for_status()
+            .map_err(|err| Error::OnExecution(Box::new(err)))?;
         Ok(())
     }
 
@@ -432,7 +970,7 @@ impl AuthDrukarnia for Auth {
         article: &ArticleId,
         comment: &CommentId,
         liked: bool,
-    ) -> Res {
+    ) -> Res<CommentLikeState> {
         static ENDPOINT: &str = "/api/articles/";
         let url = self
             .base_url()
@@ -444,8 +982,13 @@ impl AuthDrukarnia for Auth {
         } else {
             self.delete(url)
         };
-        let _ = auth_send_ok!(request, self.2);
-        Ok(())
+        let response = auth_send_ok!(self, request, self.2);
+        if response.status() == StatusCode::NOT_FOUND {
+            // Article or comment does not exist
+            return Err(Error::NoObject);
+        }
+        let state = json_ok!(response, CommentLikeState);
+        Ok(state)
     }
 
     // TODO
@@ -505,30 +1048,34 @@ impl AuthDrukarnia for Auth {
     */
 }
 
+#[cfg(feature = "logout-on-drop")]
 #[tracing::instrument(name = "Logging user out")]
-async fn log_out(auth: Client) {
+async fn log_out(auth: DrukarniaClient) {
     static ENDPOINT: &str = "/api/users/logout";
     let url = auth.base_url().join(ENDPOINT).expect(ANGRY_URL);
-    auth.get(url).send().await.ok();
+    auth.get(url)
+        .header(header::USER_AGENT, auth.user_agent())
+        .send()
+        .await
+        .ok();
 }
 
+#[cfg(feature = "logout-on-drop")]
 impl Drop for Auth {
     fn drop(&mut self) {
-        // I tried REALLY HARD, but literally every solution
-        // I came up with resulted in thread hanging indefinitely
-        // (I suspect that's because of tokio::main macro runtime is single-threaded,
-        // but CREATING SEPARATE THREADS DID NOT HELP)
-
-        // Scoped thread with `futures::executor::block_on`
-        // would not actually run the future, but just get blocked after first await point.
-        // Scoped threads alongside it would run just fine, so something IS really fishy here
-
-        // This all means, that there's literally no way to do the following without cloning `reqwest::Client`
-
-        // Also, I don't really know if there's a guarantee that tokio task actually finishes before parent runtime drops
-        let client = self.0.clone();
-        tokio::spawn(log_out(client)); // also, this is the only place in my release code,
-                                       // where I use tokio, making it a direct dependency :(
-                                       // I guess, `reqwest` does that internally anyway, so it's a **big** problem?
+        if self.3 {
+            return;
+        }
+        // Blocking on logout from here hangs the thread on a single-threaded runtime,
+        // so the best thing to do is to spawn it on the current runtime, if there's one.
+        // There's no guarantee this task finishes before the runtime shuts down, though.
+        match tokio::runtime::Handle::try_current() {
+            Ok(runtime) => {
+                runtime.spawn(log_out(self.0.clone()));
+            }
+            Err(_) => {
+                tracing::warn!("No tokio runtime to log out in, leaving the session as is");
+            }
+        }
     }
 }
@@ -0,0 +1,418 @@
+use std::{
+    collections::HashMap,
+    hash::Hash,
+    num::NonZeroUsize,
+    ops::Deref,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use async_trait::async_trait;
+
+use crate::object::{
+    ArticleId, ArticleSlug, ArticleTitle, AuthorizedUser, CommentId, CommentLikeState, Credentials,
+    FeedArticle, FollowerUser, FullArticle, FullBookmark, FullList, FullTag, FullUser, LikeAmount,
+    ListArticle, ListId, MaybeUrl, PlainShortUser, PopularTag, RecommendedArticle, ReplyComment,
+    ShortUser, TagSlug, UserId, UserName,
+};
+
+use super::{
+    ArticlesApi, AuthDrukarnia, BaseApi, CommentsApi, Error, FeedApi, ImageBytes, LoginOptions,
+    Page, TagsApi, UsersApi,
+};
+
+type Res<T = ()> = Result<T, Error>;
+
+/// In-memory [`DrukarniaApi`](super::DrukarniaApi) accessor, for testing code built on this crate without network access
+///
+/// Objects are served from the maps filled with `expect_*` methods, and [`Error::NoObject`] is returned for the
+/// ones that were not expected. Paged methods serve pages in the order they were provided, followed by empty ones.
+/// The last provided page is reported as such (see [`Page::has_more`]), so the default streams (like [`FeedApi::feed`])
+/// end right after it.
+///
+/// Clones share the expectations and call counters, so a mock could be kept around after moving it into the tested code.
+///
+/// ```
+/// # use type_matrux::{client::{MockDrukarnia, TagsApi}};
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let api = MockDrukarnia::new();
+/// api.expect_popular_tags(vec![]);
+///
+/// assert!(api.popular_tags().await.unwrap().is_empty());
+/// assert_eq!(api.calls("popular_tags"), 1);
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MockDrukarnia {
+    state: Arc<Mutex<State>>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    calls: HashMap<&'static str, usize>,
+    popular_tags: Option<Vec<PopularTag>>,
+    users: HashMap<UserName, FullUser>,
+    user_search: HashMap<UserName, Vec<Vec<ShortUser>>>,
+    tags: HashMap<TagSlug, FullTag>,
+    articles: HashMap<ArticleSlug, FullArticle>,
+    article_search: HashMap<ArticleTitle, Vec<Vec<RecommendedArticle>>>,
+    followers: HashMap<UserId, Vec<Vec<FollowerUser>>>,
+    replies: HashMap<CommentId, Vec<ReplyComment>>,
+    images: HashMap<String, ImageBytes>,
+    feed: Vec<Vec<FeedArticle>>,
+    login: Option<AuthorizedUser>,
+    bookmark_lists: Option<Vec<FullList>>,
+    list_articles: HashMap<ListId, Vec<ListArticle>>,
+    bookmark: Option<FullBookmark>,
+}
+
+fn page<E: Clone>(pages: Option<&Vec<Vec<E>>>, page: NonZeroUsize) -> Vec<E> {
+    pages
+        .and_then(|pages| pages.get(page.get() - 1))
+        .cloned()
+        .unwrap_or_default()
+}
+
+/// Same as [`page`], but also tells if that's the last provided page
+fn page_meta<E: Clone>(pages: Option<&Vec<Vec<E>>>, index: NonZeroUsize) -> Page<E> {
+    let has_more = index.get() < pages.map_or(0, Vec::len);
+    Page::new(page(pages, index), index).with_has_more(Some(has_more))
+}
+
+fn found<K: Eq + Hash, V: Clone>(map: &HashMap<K, V>, key: &K) -> Res<V> {
+    map.get(key).cloned().ok_or(Error::NoObject)
+}
+
+impl MockDrukarnia {
+    /// Creates a mock with no expectations
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        // expectations are plain data, so these are fine even if some test had panicked
+        self.state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    /// Locks the state, counting a call to the method
+    fn call(&self, method: &'static str) -> MutexGuard<'_, State> {
+        let mut state = self.state();
+        *state.calls.entry(method).or_default() += 1;
+        state
+    }
+
+    /// Counts a call to the method, that does not need the state
+    fn count(&self, method: &'static str) {
+        *self.state().calls.entry(method).or_default() += 1;
+    }
+
+    /// Number of calls made to a method, named as in [`DrukarniaApi`](super::DrukarniaApi) or [`AuthDrukarnia`] (like `"get_user"`)
+    ///
+    /// Default methods are counted by the methods they call. Pages are counted as the methods without `_meta` suffix,
+    /// and the mock knows which page is the last one, so a stream over three feed pages counts as three `"feed_page"` calls.
+    pub fn calls(&self, method: &str) -> usize {
+        self.state().calls.get(method).copied().unwrap_or(0)
+    }
+
+    /// Sets popular tags
+    pub fn expect_popular_tags(&self, tags: Vec<PopularTag>) -> &Self {
+        self.state().popular_tags = Some(tags);
+        self
+    }
+
+    /// Adds a user, served by it's username
+    pub fn expect_user(&self, user: FullUser) -> &Self {
+        self.state().users.insert(user.username().clone(), user);
+        self
+    }
+
+    /// Sets pages of user search results for a name
+    pub fn expect_user_search(&self, name: UserName, pages: Vec<Vec<ShortUser>>) -> &Self {
+        self.state().user_search.insert(name, pages);
+        self
+    }
+
+    /// Adds a tag, served by it's slug
+    pub fn expect_tag(&self, tag: FullTag) -> &Self {
+        self.state().tags.insert(tag.slug().clone(), tag);
+        self
+    }
+
+    /// Adds an article, served by it's slug
+    pub fn expect_article(&self, article: FullArticle) -> &Self {
+        self.state()
+            .articles
+            .insert(article.slug().clone(), article);
+        self
+    }
+
+    /// Sets pages of article search results for a title
+    pub fn expect_article_search(
+        &self,
+        title: ArticleTitle,
+        pages: Vec<Vec<RecommendedArticle>>,
+    ) -> &Self {
+        self.state().article_search.insert(title, pages);
+        self
+    }
+
+    /// Sets pages of user's followers
+    pub fn expect_followers(&self, id: UserId, pages: Vec<Vec<FollowerUser>>) -> &Self {
+        self.state().followers.insert(id, pages);
+        self
+    }
+
+    /// Sets replies to a comment
+    pub fn expect_replies(&self, comment: CommentId, replies: Vec<ReplyComment>) -> &Self {
+        self.state().replies.insert(comment, replies);
+        self
+    }
+
+    /// Adds an image, served by it's url
+    pub fn expect_image(&self, url: &MaybeUrl, image: ImageBytes) -> &Self {
+        self.state().images.insert(url.source().to_owned(), image);
+        self
+    }
+
+    /// Sets feed pages
+    pub fn expect_feed(&self, pages: Vec<Vec<FeedArticle>>) -> &Self {
+        self.state().feed = pages;
+        self
+    }
+
+    /// Allows logging in, as the provided user. Without it, login fails with [`Error::BadCredentials`]
+    pub fn expect_login(&self, user: AuthorizedUser) -> &Self {
+        self.state().login = Some(user);
+        self
+    }
+
+    /// Sets authorized user's bookmark lists
+    pub fn expect_bookmark_lists(&self, lists: Vec<FullList>) -> &Self {
+        self.state().bookmark_lists = Some(lists);
+        self
+    }
+
+    /// Sets articles of a bookmark list
+    pub fn expect_list_articles(&self, list: ListId, articles: Vec<ListArticle>) -> &Self {
+        self.state().list_articles.insert(list, articles);
+        self
+    }
+
+    /// Sets bookmark returned from bookmarking and unbookmarking
+    pub fn expect_bookmark(&self, bookmark: FullBookmark) -> &Self {
+        self.state().bookmark = Some(bookmark);
+        self
+    }
+}
+
+#[async_trait]
+impl BaseApi for MockDrukarnia {
+    type Auth = MockAuth;
+
+    async fn login_with(&self, _credentials: Credentials, _options: LoginOptions) -> Res<MockAuth> {
+        let user = self
+            .call("login_with")
+            .login
+            .clone()
+            .ok_or(Error::BadCredentials)?;
+        Ok(MockAuth {
+            api: self.clone(),
+            user,
+        })
+    }
+}
+
+#[async_trait]
+impl UsersApi for MockDrukarnia {
+    async fn get_user(&self, name: &UserName) -> Res<FullUser> {
+        found(&self.call("get_user").users, name)
+    }
+
+    async fn search_user_page(&self, name: &UserName, page_: NonZeroUsize) -> Res<Vec<ShortUser>> {
+        Ok(page(
+            self.call("search_user_page").user_search.get(name),
+            page_,
+        ))
+    }
+
+    async fn search_user_page_meta(
+        &self,
+        name: &UserName,
+        page_: NonZeroUsize,
+    ) -> Res<Page<ShortUser>> {
+        Ok(page_meta(
+            self.call("search_user_page").user_search.get(name),
+            page_,
+        ))
+    }
+
+    async fn search_user_page_plain_meta(
+        &self,
+        name: &UserName,
+        page_: NonZeroUsize,
+    ) -> Res<Page<PlainShortUser>> {
+        let users = self.search_user_page_meta(name, page_).await?;
+        let has_more = *users.has_more();
+        let users = users.into_items().into_iter().map(PlainShortUser::from);
+        Ok(Page::new(users.collect(), page_).with_has_more(has_more))
+    }
+
+    async fn get_followers_page(&self, id: &UserId, page_: NonZeroUsize) -> Res<Vec<FollowerUser>> {
+        let state = self.call("get_followers_page");
+        let pages = state.followers.get(id).ok_or(Error::NoObject)?;
+        Ok(page(Some(pages), page_))
+    }
+
+    async fn get_followers_page_meta(
+        &self,
+        id: &UserId,
+        page_: NonZeroUsize,
+    ) -> Res<Page<FollowerUser>> {
+        let state = self.call("get_followers_page");
+        let pages = state.followers.get(id).ok_or(Error::NoObject)?;
+        Ok(page_meta(Some(pages), page_))
+    }
+}
+
+#[async_trait]
+impl ArticlesApi for MockDrukarnia {
+    async fn get_article(&self, slug: &ArticleSlug) -> Res<FullArticle> {
+        found(&self.call("get_article").articles, slug)
+    }
+
+    async fn search_article_page(
+        &self,
+        name: &ArticleTitle,
+        page_: NonZeroUsize,
+    ) -> Res<Vec<RecommendedArticle>> {
+        Ok(page(
+            self.call("search_article_page").article_search.get(name),
+            page_,
+        ))
+    }
+
+    async fn search_article_page_meta(
+        &self,
+        name: &ArticleTitle,
+        page_: NonZeroUsize,
+    ) -> Res<Page<RecommendedArticle>> {
+        Ok(page_meta(
+            self.call("search_article_page").article_search.get(name),
+            page_,
+        ))
+    }
+
+    async fn download_image(&self, url: &MaybeUrl) -> Res<ImageBytes> {
+        found(
+            &self.call("download_image").images,
+            &url.source().to_owned(),
+        )
+    }
+}
+
+#[async_trait]
+impl TagsApi for MockDrukarnia {
+    async fn popular_tags(&self) -> Res<Vec<PopularTag>> {
+        self.call("popular_tags")
+            .popular_tags
+            .clone()
+            .ok_or(Error::NoObject)
+    }
+
+    async fn get_tag(&self, slug: &TagSlug) -> Res<FullTag> {
+        found(&self.call("get_tag").tags, slug)
+    }
+}
+
+#[async_trait]
+impl CommentsApi for MockDrukarnia {
+    async fn get_replies(&self, comment: &CommentId) -> Res<Vec<ReplyComment>> {
+        found(&self.call("get_replies").replies, comment)
+    }
+}
+
+#[async_trait]
+impl FeedApi for MockDrukarnia {
+    async fn feed_page(&self, page_: NonZeroUsize) -> Res<Vec<FeedArticle>> {
+        Ok(page(Some(&self.call("feed_page").feed), page_))
+    }
+
+    async fn feed_page_meta(&self, page_: NonZeroUsize) -> Res<Page<FeedArticle>> {
+        Ok(page_meta(Some(&self.call("feed_page").feed), page_))
+    }
+}
+
+/// Authorized counterpart of [`MockDrukarnia`], obtained by logging in
+///
+/// Shares expectations and call counters with the mock it was obtained from.
+/// Actions (like following a user) always succeed, and are only counted.
+#[derive(Debug, Clone)]
+pub struct MockAuth {
+    api: MockDrukarnia,
+    user: AuthorizedUser,
+}
+
+impl Deref for MockAuth {
+    type Target = MockDrukarnia;
+
+    fn deref(&self) -> &Self::Target {
+        &self.api
+    }
+}
+
+#[async_trait]
+impl AuthDrukarnia for MockAuth {
+    type Downgrade = MockDrukarnia;
+
+    fn authorized_user(&self) -> &AuthorizedUser {
+        &self.user
+    }
+
+    async fn user_set_following(&self, _id: &UserId, _follow: bool) -> Res {
+        self.count("user_set_following");
+        Ok(())
+    }
+
+    async fn get_bookmark_lists(&self) -> Res<Vec<FullList>> {
+        self.call("get_bookmark_lists")
+            .bookmark_lists
+            .clone()
+            .ok_or(Error::NoObject)
+    }
+
+    async fn bookmark_article(&self, _list: &ListId, _article: &ArticleId) -> Res<FullBookmark> {
+        self.call("bookmark_article")
+            .bookmark
+            .clone()
+            .ok_or(Error::NoObject)
+    }
+
+    async fn unbookmark_article(&self, _article: &ArticleId) -> Res<FullBookmark> {
+        self.call("unbookmark_article")
+            .bookmark
+            .clone()
+            .ok_or(Error::NoObject)
+    }
+
+    async fn get_list_articles(&self, list: &ListId) -> Res<Vec<ListArticle>> {
+        found(&self.call("get_list_articles").list_articles, list)
+    }
+
+    async fn like_article(&self, _article: &ArticleId, _amount: LikeAmount) -> Res {
+        self.count("like_article");
+        Ok(())
+    }
+
+    async fn set_comment_liked(
+        &self,
+        _article: &ArticleId,
+        _comment: &CommentId,
+        liked: bool,
+    ) -> Res<CommentLikeState> {
+        self.count("set_comment_liked");
+        // as if nobody else liked the comment
+        Ok(CommentLikeState::new(usize::from(liked), liked))
+    }
+}
@@ -2,23 +2,55 @@ mod utils;
 
 mod impls;
 
+mod refresh;
+
+pub use refresh::Refresh;
+
+mod auto_auth;
+
+pub use auto_auth::AutoAuth;
+
+mod cache;
+
+pub use cache::{CacheStats, CachedDrukarnia};
+
+mod read;
+
+pub use read::DrukarniaRead;
+
+#[cfg(feature = "mock")]
+mod mock;
+
+#[cfg(feature = "mock")]
+pub use mock::{MockAuth, MockDrukarnia};
+
 pub use impls::reqwest::Auth as ReqwestAuth;
 use lazy_static::lazy_static;
 pub use reqwest::Client as ReqwestApi;
 
-use std::{num::NonZeroUsize, ops::Deref};
+use std::{
+    collections::HashMap,
+    num::NonZeroUsize,
+    ops::Deref,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
 
 use async_trait::async_trait;
+use derive_getters::Getters;
+use futures::StreamExt;
 use thiserror::Error;
 use url::Url;
 
 use crate::object::{
-    ArticleId, ArticleSlug, ArticleTitle, AuthorizedUser, CommentId, Credentials, FeedArticle,
-    FollowerUser, FullArticle, FullBookmark, FullList, FullTag, FullUser, ListArticle, ListId,
-    PopularTag, RecommendedArticle, ReplyComment, ShortUser, TagSlug, UserId, UserName,
+    ArticleId, ArticleSlug, ArticleTitle, AuthorizedUser, CommentId, CommentLikeState,
+    CommentThread, Credentials, FeedArticle, FollowerUser, FullArticle, FullBookmark, FullList,
+    FullTag, FullUser, LikeAmount, ListArticle, ListId, MaybeUrl, PlainShortUser, PopularTag,
+    RecommendedArticle, ReplyComment, ShortUser, SlugFromUrlError, TagSlug, UserId, UserName,
 };
 
 use self::utils::PageSearchStream;
+pub use self::utils::{Dedup, ObjectStreamExt, StreamCheckpoint, UntilOlderThan};
 
 /// That's [DrukarniaApi]'s error type.
 ///
@@ -33,7 +65,7 @@ pub enum Error {
     ///
     /// Under normal operation, this sort of error should not occur.
     #[error(transparent)]
-    OnExecution(Box<dyn std::error::Error>),
+    OnExecution(Box<dyn std::error::Error + Send + Sync>),
     /// An error happened at response JSON deserializing.
     ///
     /// If you see this sort of error pop up, this is most likely due to Drukarnia API has changed.
@@ -51,21 +83,535 @@ pub enum Error {
     /// Supplied email and/or password are not correct.
     #[error("Supplied credentials are not correct")]
     BadCredentials,
+    /// Session token is not valid anymore, so user should log in again.
+    #[error("Session has expired")]
+    SessionExpired,
+    /// Stored session could not be read.
+    #[cfg(feature = "session-store")]
+    #[error("Could not read stored session: {0}")]
+    SessionFile(#[source] std::io::Error),
     /// Queried object (user, article, tag, etc) does not exist.
     #[error("Queried object (user, article, tag, etc) does not exist")]
     NoObject,
+    /// Supplied url does not point to an object of requested type.
+    #[error(transparent)]
+    BadObjectUrl(#[from] SlugFromUrlError),
+    /// Supplied url is not valid, so it cannot be requested.
+    ///
+    /// Contains both source string (zeroth field) and error description (first field), same as [`MaybeUrl::BadUrl`].
+    #[error("Url {0:?} is not valid: {1}")]
+    InvalidUrl(String, String),
+    /// Accessor does not support requested operation.
+    ///
+    /// Contains operation's name.
+    #[error("Operation {0:?} is not supported by this accessor")]
+    Unsupported(&'static str),
 }
 
 type Res<T = ()> = Result<T, Error>;
 
+/// Downloaded image, like article's cover or user's avatar
+#[derive(Debug, Clone, PartialEq, Eq, Getters)]
+pub struct ImageBytes {
+    /// Image's content type, if server had specified a valid one
+    content_type: Option<mime::Mime>,
+    bytes: Vec<u8>,
+}
+
+impl ImageBytes {
+    /// Creates image out of it's content type and bytes
+    pub fn new(content_type: Option<mime::Mime>, bytes: Vec<u8>) -> Self {
+        Self {
+            content_type,
+            bytes,
+        }
+    }
+
+    /// Takes image bytes
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+}
+
+/// Fetches an object per key, running up to `concurrency` fetches at once, and returns results in the order of keys
+async fn fetch_all<K, T, F, Fut>(keys: &[K], concurrency: usize, fetch: F) -> Vec<(K, Res<T>)>
+where
+    K: Clone,
+    F: Fn(K) -> Fut,
+    Fut: std::future::Future<Output = Res<T>>,
+{
+    let mut results: Vec<_> = futures::stream::iter(keys.iter().cloned().enumerate())
+        .map(|(index, key)| {
+            let result = fetch(key.clone());
+            async move { (index, key, result.await) }
+        })
+        .buffer_unordered(concurrency.max(1))
+        .collect()
+        .await;
+    // requests complete in any order
+    results.sort_by_key(|(index, ..)| *index);
+    results
+        .into_iter()
+        .map(|(_, key, result)| (key, result))
+        .collect()
+}
+
+/// Article along with it's comments, assembled into threads, see [`DrukarniaApi::get_article_with_replies`]
+#[derive(Debug, Getters)]
+pub struct ArticleWithThreads {
+    /// Article itself
+    article: FullArticle,
+    /// Article's comments, with all of the replies that could be fetched
+    thread: CommentThread,
+    /// Comments, replies to which could not be fetched, along with the errors
+    partial_errors: Vec<(CommentId, Error)>,
+}
+
+impl ArticleWithThreads {
+    /// Splits into article, comment thread and errors
+    pub fn into_parts(self) -> (FullArticle, CommentThread, Vec<(CommentId, Error)>) {
+        (self.article, self.thread, self.partial_errors)
+    }
+}
+
+/// Everything about some author, see [`UsersApi::get_author_portfolio`]
+#[derive(Debug, Clone, Getters, serde::Serialize)]
+pub struct AuthorPortfolio {
+    /// Author's profile, including their articles
+    user: FullUser,
+    /// Number of author's articles per their main tag
+    articles_per_tag: HashMap<TagSlug, usize>,
+    /// Likes on all of the author's articles
+    total_likes: usize,
+    /// Comments on all of the author's articles
+    total_comments: usize,
+    /// Author's total reads
+    total_reads: usize,
+}
+
+impl AuthorPortfolio {
+    /// Aggregates user's articles
+    pub fn new(user: FullUser) -> Self {
+        let mut articles_per_tag = HashMap::new();
+        let mut total_likes = 0;
+        let mut total_comments = 0;
+        for article in user.articles() {
+            *articles_per_tag
+                .entry(article.main_tag_slug().clone())
+                .or_default() += 1;
+            total_likes += article.like_num();
+            total_comments += article.comment_num();
+        }
+        let total_reads = *user.read_num();
+        Self {
+            user,
+            articles_per_tag,
+            total_likes,
+            total_comments,
+            total_reads,
+        }
+    }
+}
+
+/// A single page of paginated results, along with whatever is known about the following ones
+#[derive(Debug, Clone, PartialEq, Eq, Getters)]
+pub struct Page<T> {
+    /// Objects on this page
+    items: Vec<T>,
+    /// Index of this page, starting from one
+    index: NonZeroUsize,
+    /// Whether there are pages after this one, if known
+    ///
+    /// Site does not report this, so most of the time only the last page can be recognized - as a one shorter than the page size.
+    has_more: Option<bool>,
+}
+
+impl<T> Page<T> {
+    /// Creates a page, with nothing known about the following ones, unless it's empty
+    pub fn new(items: Vec<T>, index: NonZeroUsize) -> Self {
+        let has_more = items.is_empty().then_some(false);
+        Self {
+            items,
+            index,
+            has_more,
+        }
+    }
+
+    /// Creates a page, that is known to be the last one if it's shorter than `page_size`
+    pub fn with_page_size(items: Vec<T>, index: NonZeroUsize, page_size: usize) -> Self {
+        let has_more = (items.len() < page_size).then_some(false);
+        Self {
+            items,
+            index,
+            has_more,
+        }
+    }
+
+    /// Sets whether there are pages after this one
+    #[must_use]
+    pub fn with_has_more(self, has_more: Option<bool>) -> Self {
+        Self { has_more, ..self }
+    }
+
+    /// `true` if this page is known to be the last one
+    pub fn is_last(&self) -> bool {
+        self.has_more == Some(false)
+    }
+
+    /// Takes objects out of the page
+    pub fn into_items(self) -> Vec<T> {
+        self.items
+    }
+}
+
+/// Cookies site had set on an anonymous feed response, along with the first page it returned
+///
+/// Without these, every anonymous feed request gets a freshly shuffled assortment, so pages do not add up
+/// to a consistent sequence. Sending them back keeps the same feed across requests, see [`FeedApi::feed_session`].
+///
+/// Session is established by requesting the first page, so that page is kept as well, and served
+/// without another request.
+///
+/// Default session carries no cookies, that's the same as requesting the feed without a session.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FeedSession {
+    cookie: Option<String>,
+    first_page: Option<Vec<FeedArticle>>,
+}
+
+impl FeedSession {
+    /// Creates a session from a `Cookie` header value, like one saved from [`FeedSession::cookie`] earlier
+    pub fn new(cookie: impl Into<String>) -> Self {
+        let cookie = cookie.into();
+        Self {
+            cookie: (!cookie.is_empty()).then_some(cookie),
+            first_page: None,
+        }
+    }
+
+    /// Keeps first page of the feed, as returned while establishing the session
+    pub fn with_first_page(mut self, page: Vec<FeedArticle>) -> Self {
+        self.first_page = Some(page);
+        self
+    }
+
+    /// Value to send in the `Cookie` header, if there's any
+    pub fn cookie(&self) -> Option<&str> {
+        self.cookie.as_deref()
+    }
+
+    /// First page of the feed, if it was returned while establishing the session
+    pub fn first_page(&self) -> Option<&[FeedArticle]> {
+        self.first_page.as_deref()
+    }
+
+    /// Whether site had actually set any cookies
+    pub fn is_established(&self) -> bool {
+        self.cookie.is_some()
+    }
+}
+
+/// Additional request parameters for [`BaseApi::login_with`]
+///
+/// Site sometimes requires login request to look like it was sent by a browser, so these allow mimicking one.
+///
+/// Extra headers might carry identifiers, so `Debug` output only contains their names.
+#[derive(Clone, Default, PartialEq, Eq, Getters)]
+pub struct LoginOptions {
+    /// Page login request is sent from, also sets `Origin` header to it's origin
+    referer: Option<Url>,
+    /// User agent to use instead of the default one
+    user_agent: Option<String>,
+    /// Any other headers, like device identifier
+    headers: Vec<(String, String)>,
+}
+
+impl LoginOptions {
+    /// Sets page login request is sent from
+    pub fn with_referer(mut self, referer: Url) -> Self {
+        self.referer = Some(referer);
+        self
+    }
+
+    /// Overrides user agent
+    pub fn with_user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// Adds a header to send along
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+}
+
+impl std::fmt::Debug for LoginOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LoginOptions")
+            .field("referer", &self.referer)
+            .field("user_agent", &self.user_agent)
+            .field(
+                "headers",
+                &self
+                    .headers
+                    .iter()
+                    .map(|(name, _)| (name.as_str(), format_args!("[REDACTED]")))
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
 lazy_static! {
     static ref DEFAULT_BASE_URL: Url =
         Url::parse("https://drukarnia.com.ua/").expect("Should be able to parse base url");
 }
 
-/// Represents object's ability to make requests to Drukarnia's API.
+/// Identifies the crate and it's actual version, unless other user agent is configured
+static DEFAULT_USER_AGENT: &str = concat!("type-matrux/", env!("CARGO_PKG_VERSION"));
+
+/// Number of objects on a full page of site's paginated results, unless other page size is configured
+const DEFAULT_PAGE_SIZE: usize = 10;
+
+/// [`reqwest::Client`] wrapper, carrying crate-level configuration
+///
+/// Might be used to point requests at a staging host or a local mock server, identify yourself to the site,
+/// or to be polite by spacing the requests. Plain [`reqwest::Client`] uses the defaults.
+///
+/// ```no_run
+/// # use std::time::Duration;
+/// # use type_matrux::{client::{DrukarniaClient, TagsApi}};
+/// # async fn example() -> Result<(), type_matrux::client::Error> {
+/// let client = DrukarniaClient::builder()
+///     .user_agent("my-scraper/1.0 (me@example.com)")
+///     .timeout(Duration::from_secs(10))
+///     .min_request_interval(Duration::from_millis(500))
+///     .build()?;
+/// let tags = client.popular_tags().await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, derive_more::Deref)]
+pub struct DrukarniaClient {
+    #[deref]
+    client: reqwest::Client,
+    base_url: Url,
+    user_agent: String,
+    throttle: Throttle,
+    page_size: usize,
+}
+
+impl DrukarniaClient {
+    /// Starts configuring a new client
+    pub fn builder() -> DrukarniaClientBuilder {
+        DrukarniaClientBuilder::default()
+    }
+
+    /// Creates a new client with default configuration, calling the API at `base_url`
+    pub fn with_base_url(base_url: Url) -> Self {
+        Self {
+            base_url,
+            ..Self::from(reqwest::Client::new())
+        }
+    }
+
+    /// Underlying HTTP client
+    pub fn client(&self) -> &reqwest::Client {
+        &self.client
+    }
+
+    /// User agent sent with every request
+    pub fn user_agent(&self) -> &str {
+        &self.user_agent
+    }
+
+    /// Number of objects on a full page, see [`DrukarniaClientBuilder::page_size`]
+    pub fn page_size(&self) -> usize {
+        self.page_size
+    }
+
+    /// Waits until the next request is allowed to be sent
+    pub(crate) async fn wait_turn(&self) {
+        self.throttle.wait().await;
+    }
+}
+
+/// Calls the site itself with default configuration
+impl From<reqwest::Client> for DrukarniaClient {
+    fn from(client: reqwest::Client) -> Self {
+        Self {
+            client,
+            base_url: DEFAULT_BASE_URL.clone(),
+            user_agent: DEFAULT_USER_AGENT.to_owned(),
+            throttle: Throttle::default(),
+            page_size: DEFAULT_PAGE_SIZE,
+        }
+    }
+}
+
+/// Configures [`DrukarniaClient`]
+///
+/// For long crawls, please be polite: space the requests, and keep a few connections alive instead of opening
+/// new ones all the time.
+/// ```
+/// # use std::time::Duration;
+/// # use type_matrux::client::DrukarniaClient;
+/// let client = DrukarniaClient::builder()
+///     .user_agent("my-crawler/1.0 (me@example.com)")
+///     .min_request_interval(Duration::from_secs(1))
+///     .timeout(Duration::from_secs(30))
+///     .connect_timeout(Duration::from_secs(10))
+///     .pool_max_idle_per_host(2)
+///     .tcp_keepalive(Duration::from_secs(60))
+///     .build()
+///     .expect("Should build a client");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct DrukarniaClientBuilder {
+    base_url: Option<Url>,
+    user_agent: Option<String>,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    min_request_interval: Duration,
+    pool_max_idle_per_host: Option<usize>,
+    tcp_keepalive: Option<Duration>,
+    http2_prior_knowledge: bool,
+    page_size: Option<usize>,
+}
+
+impl DrukarniaClientBuilder {
+    /// Url API is located at, `https://drukarnia.com.ua/` by default
+    pub fn base_url(mut self, base_url: Url) -> Self {
+        self.base_url = Some(base_url);
+        self
+    }
+
+    /// User agent to identify with, crate name and version by default
+    pub fn user_agent(mut self, user_agent: &str) -> Self {
+        self.user_agent = Some(user_agent.to_owned());
+        self
+    }
+
+    /// Timeout for each request, none by default
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Timeout for connecting to the site, none by default
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Minimal time between the starts of two consequent requests, zero by default
+    ///
+    /// Shared between the client's clones, including authorized sessions obtained with it.
+    pub fn min_request_interval(mut self, interval: Duration) -> Self {
+        self.min_request_interval = interval;
+        self
+    }
+
+    /// Maximal number of idle connections kept open to the site, unlimited by default
+    pub fn pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.pool_max_idle_per_host = Some(max);
+        self
+    }
+
+    /// Sends TCP keepalive probes at this interval, none by default
+    pub fn tcp_keepalive(mut self, interval: Duration) -> Self {
+        self.tcp_keepalive = Some(interval);
+        self
+    }
+
+    /// Talks HTTP/2 right away, without negotiating it first
+    ///
+    /// Requests fail, if the host does not support HTTP/2, so that's mostly useful for local proxies and mirrors.
+    pub fn http2_prior_knowledge(mut self) -> Self {
+        self.http2_prior_knowledge = true;
+        self
+    }
+
+    /// Number of objects on a full page of site's paginated results, 10 by default
+    ///
+    /// A shorter page is known to be the last one, so streams end without requesting the next (empty) page.
+    /// Zero disables that, so streams always end on an empty page.
+    pub fn page_size(mut self, page_size: usize) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Builds configured client
+    ///
+    /// # Errors
+    /// [`Error::OnExecution`], if HTTP client could not be initialized
+    pub fn build(self) -> Res<DrukarniaClient> {
+        let mut client = reqwest::Client::builder();
+        if let Some(timeout) = self.timeout {
+            client = client.timeout(timeout);
+        }
+        if let Some(timeout) = self.connect_timeout {
+            client = client.connect_timeout(timeout);
+        }
+        if let Some(max) = self.pool_max_idle_per_host {
+            client = client.pool_max_idle_per_host(max);
+        }
+        if let Some(interval) = self.tcp_keepalive {
+            client = client.tcp_keepalive(interval);
+        }
+        if self.http2_prior_knowledge {
+            client = client.http2_prior_knowledge();
+        }
+        let client = client
+            .build()
+            .map_err(|err| Error::OnExecution(Box::new(err)))?;
+        Ok(DrukarniaClient {
+            client,
+            base_url: self.base_url.unwrap_or_else(|| DEFAULT_BASE_URL.clone()),
+            user_agent: self
+                .user_agent
+                .unwrap_or_else(|| DEFAULT_USER_AGENT.to_owned()),
+            throttle: Throttle {
+                interval: self.min_request_interval,
+                next: Arc::default(),
+            },
+            page_size: self.page_size.unwrap_or(DEFAULT_PAGE_SIZE),
+        })
+    }
+}
+
+/// Spaces requests at least `interval` apart
+#[derive(Debug, Clone, Default)]
+struct Throttle {
+    interval: Duration,
+    /// Time the next request is allowed at, shared between clones
+    next: Arc<Mutex<Option<Instant>>>,
+}
+
+impl Throttle {
+    async fn wait(&self) {
+        if self.interval.is_zero() {
+            return;
+        }
+        let delay = {
+            let mut next = self.next.lock().expect("Should not be poisoned");
+            let now = Instant::now();
+            let start = next.map_or(now, |next| next.max(now));
+            *next = Some(start + self.interval);
+            start - now
+        };
+        if !delay.is_zero() {
+            tokio::time::sleep(delay).await;
+        }
+    }
+}
+
+/// Base of the API accessor traits, holding things common to all of them
+///
+/// Every accessor implements this one, along with the per-domain traits it supports.
 #[async_trait]
-pub trait DrukarniaApi {
+pub trait BaseApi {
     /// Defines a type of this accessor's authenticated version.
     ///
     /// Might even be `Self`, but this is not desired, as authenticated API accessor is kinda different from a regular one.
@@ -78,12 +624,37 @@ pub trait DrukarniaApi {
         &DEFAULT_BASE_URL
     }
 
-    /// Retrieves currently popular tags at Drukarnia.
+    /// Logs in a Drukarnia user.
+    ///
+    /// Same as [`BaseApi::login_with`] with default options.
+    async fn login(&self, credentials: Credentials) -> Res<Self::Auth>
+    where
+        Self::Auth: AuthDrukarnia,
+    {
+        self.login_with(credentials, LoginOptions::default()).await
+    }
+
+    /// Logs in a Drukarnia user, sending additional headers along.
+    ///
+    /// Unstable: site intermittently rejects logins, seemingly unless request looks like one from a browser.
     ///
     /// # Implementation
-    /// Expected to GET `/api/articles/tags/popular`.
-    async fn popular_tags(&self) -> Res<Vec<PopularTag>>;
+    /// Expected to POST `/api/users/login` with JSON body containing `email` (or `username`) and `password`.
+    /// Token is returned as a `token` cookie, and authorized user - as a `user` field of JSON response.
+    ///
+    /// # Errors
+    /// - [`Error::Unsupported`]: accessor does not implement logging in, which is the default
+    async fn login_with(&self, _credentials: Credentials, _options: LoginOptions) -> Res<Self::Auth>
+    where
+        Self::Auth: AuthDrukarnia,
+    {
+        Err(Error::Unsupported("login"))
+    }
+}
 
+/// Requests concerning users.
+#[async_trait]
+pub trait UsersApi: BaseApi {
     /// Retrieves a certain user.
     ///
     /// # Implementation
@@ -99,6 +670,17 @@ pub trait DrukarniaApi {
     /// Expected to GET `/api/users/info?name=USER_NAME&page=PAGE&withRelationships=true`
     async fn search_user_page(&self, name: &UserName, page: NonZeroUsize) -> Res<Vec<ShortUser>>;
 
+    /// Same as [`UsersApi::search_user_page`], but tells if there are more pages, see [`Page::has_more`]
+    ///
+    /// By default, only an empty page is known to be the last one.
+    async fn search_user_page_meta(
+        &self,
+        name: &UserName,
+        page: NonZeroUsize,
+    ) -> Res<Page<ShortUser>> {
+        Ok(Page::new(self.search_user_page(name, page).await?, page))
+    }
+
     /// Searches user by it's name.
     ///
     /// # Returns
@@ -112,31 +694,178 @@ pub trait DrukarniaApi {
     ///
     /// # Note
     /// Stream ends after first error, since after the error, there's no way for stream to determine, if search results had ended
-    fn search_user(&self, name: UserName) -> PageSearchStream<Self::Auth, ShortUser>
+    /// A long crawl might be continued later from stream's `checkpoint`, see [`StreamCheckpoint`].
+    fn search_user(&self, name: UserName) -> PageSearchStream<'_, '_, '_, Self::Auth, ShortUser>
     where
-        Self: Sized,
+        Self: Sized + Sync,
     {
+        // shared between the pages, so that the query itself is not copied for each of them
+        let name = Arc::new(name);
         PageSearchStream::create(self, move |page| {
-            let name_ = name.clone();
-            Box::pin(async move { self.search_user_page(&name_, page).await })
+            let name = Arc::clone(&name);
+            Box::pin(async move { self.search_user_page_meta(&name, page).await })
         })
     }
 
-    /// Fetches a tag by it's slug.
+    /// Same as [`UsersApi::search_user_page`], but without relationships to the found users.
+    ///
+    /// Relationships are only meaningful for an authorized request, so anonymous consumers might skip them.
+    /// By default, relationships are requested anyway, and then dropped.
     ///
     /// # Implementation
-    /// Expected to GET `/api/articles/tags/TAG_SLUG`
+    /// Expected to GET `/api/users/info?name=USER_NAME&page=PAGE`
+    async fn search_user_page_plain(
+        &self,
+        name: &UserName,
+        page: NonZeroUsize,
+    ) -> Res<Vec<PlainShortUser>> {
+        let users = self.search_user_page(name, page).await?;
+        Ok(users.into_iter().map(PlainShortUser::from).collect())
+    }
+
+    /// Same as [`UsersApi::search_user_page_plain`], but tells if there are more pages, see [`Page::has_more`]
+    ///
+    /// By default, only an empty page is known to be the last one.
+    async fn search_user_page_plain_meta(
+        &self,
+        name: &UserName,
+        page: NonZeroUsize,
+    ) -> Res<Page<PlainShortUser>> {
+        Ok(Page::new(
+            self.search_user_page_plain(name, page).await?,
+            page,
+        ))
+    }
+
+    /// Searches user by it's name, without relationships to the found users, see [`UsersApi::search_user`]
+    ///
+    /// # Note
+    /// Stream ends after first error, since after the error, there's no way for stream to determine, if search results had ended
+    /// A long crawl might be continued later from stream's `checkpoint`, see [`StreamCheckpoint`].
+    fn search_user_plain(
+        &self,
+        name: UserName,
+    ) -> PageSearchStream<'_, '_, '_, Self::Auth, PlainShortUser>
+    where
+        Self: Sized + Sync,
+    {
+        let name = Arc::new(name);
+        PageSearchStream::create(self, move |page| {
+            let name = Arc::clone(&name);
+            Box::pin(async move { self.search_user_page_plain_meta(&name, page).await })
+        })
+    }
+
+    /// Get followers of a user by it's id.
+    ///
+    /// # Implementation
+    /// Expected to GET `/api/relationships/USER_ID/followers?page=PAGE`
     ///
     /// # Errors
-    /// [`Error::NoObject`]: tag with provided slug does not exists
-    async fn get_tag(&self, slug: &TagSlug) -> Res<FullTag>;
+    /// - [Error::NoObject]: User with provided id does not exist
+    async fn get_followers_page(&self, id: &UserId, page: NonZeroUsize) -> Res<Vec<FollowerUser>>;
+
+    /// Same as [`UsersApi::get_followers_page`], but tells if there are more pages, see [`Page::has_more`]
+    ///
+    /// By default, only an empty page is known to be the last one.
+    async fn get_followers_page_meta(
+        &self,
+        id: &UserId,
+        page: NonZeroUsize,
+    ) -> Res<Page<FollowerUser>> {
+        Ok(Page::new(self.get_followers_page(id, page).await?, page))
+    }
+
+    /// Get followers of a user by it's id.
+    ///
+    /// # Returns
+    /// A stream of result pages.
+    ///
+    /// # Implementation details
+    /// This function should not be reimplemented.
+    ///
+    /// Currently, the underlying stream consequently calls for result pages,
+    /// although this might be changed in the future for more optimized approach.
+    ///
+    /// # Note
+    /// Stream ends after first error, since after the error, there's no way for stream to determine, if results had ended
+    /// A long crawl might be continued later from stream's `checkpoint`, see [`StreamCheckpoint`].
+    fn get_followers(&self, id: UserId) -> PageSearchStream<'_, '_, '_, Self::Auth, FollowerUser>
+    where
+        Self: Sized + Sync,
+    {
+        // TODO prettify this
+        let id = Arc::new(id);
+        PageSearchStream::create(self, move |page| {
+            let id = Arc::clone(&id);
+            Box::pin(async move { self.get_followers_page_meta(&id, page).await })
+        })
+    }
+
+    /// Retrieves users by their names, see [`ArticlesApi::get_articles`]
+    async fn get_users(
+        &self,
+        names: &[UserName],
+        concurrency: usize,
+    ) -> Vec<(UserName, Res<FullUser>)> {
+        fetch_all(names, concurrency, |name| async move {
+            self.get_user(&name).await
+        })
+        .await
+    }
+
+    /// Retrieves user's profile, and aggregates their articles, see [`AuthorPortfolio`]
+    ///
+    /// Site returns all of the author's articles along with their profile, so this is a single request.
+    ///
+    /// # Errors
+    /// - [`Error::NoObject`]: user with provided name does not exist
+    async fn get_author_portfolio(&self, name: &UserName) -> Res<AuthorPortfolio> {
+        self.get_user(name).await.map(AuthorPortfolio::new)
+    }
+
+    /// Retrieves user's profile, and renders their articles as an RSS feed, see [`author_feed`](crate::export::rss::author_feed)
+    ///
+    /// Links in the feed point to [`BaseApi::base_url`].
+    ///
+    /// # Errors
+    /// - [`Error::NoObject`]: user with provided name does not exist
+    #[cfg(feature = "rss")]
+    async fn author_rss(&self, name: &UserName) -> Res<String> {
+        let user = self.get_user(name).await?;
+        Ok(crate::export::rss::author_feed(
+            &user,
+            user.articles(),
+            self.base_url(),
+        ))
+    }
+}
 
+/// Requests concerning articles.
+#[async_trait]
+pub trait ArticlesApi: BaseApi {
     /// Fetches an article by it's slug.
     ///
     /// # Implementation
     /// Expected to GET `/api/articles/ARTICLE_SLUG`.
     async fn get_article(&self, slug: &ArticleSlug) -> Res<FullArticle>;
 
+    /// Fetches an article by it's url, as seen in browser.
+    ///
+    /// # Implementation details
+    /// This function should not be reimplemented.
+    ///
+    /// Url is expected to point to the [`BaseApi::base_url`]'s host.
+    ///
+    /// # Errors
+    /// - [`Error::BadObjectUrl`]: url does not point to an article
+    /// - [`Error::NoObject`]: article with extracted slug does not exist
+    async fn get_article_by_url(&self, url: &Url) -> Res<FullArticle> {
+        let host = self.base_url().host_str().unwrap_or_default();
+        let slug = ArticleSlug::from_url_at(url, host)?;
+        self.get_article(&slug).await
+    }
+
     /// Searches an article by it's title.
     ///
     /// # Implementation
@@ -147,6 +876,17 @@ pub trait DrukarniaApi {
         page: NonZeroUsize,
     ) -> Res<Vec<RecommendedArticle>>;
 
+    /// Same as [`ArticlesApi::search_article_page`], but tells if there are more pages, see [`Page::has_more`]
+    ///
+    /// By default, only an empty page is known to be the last one.
+    async fn search_article_page_meta(
+        &self,
+        name: &ArticleTitle,
+        page: NonZeroUsize,
+    ) -> Res<Page<RecommendedArticle>> {
+        Ok(Page::new(self.search_article_page(name, page).await?, page))
+    }
+
     /// Searches an article by it's title.
     ///
     /// # Returns
@@ -160,49 +900,80 @@ pub trait DrukarniaApi {
     ///
     /// # Note
     /// Stream ends after first error, since after the error, there's no way for stream to determine, if search results had ended
-    fn search_article(&self, name: ArticleTitle) -> PageSearchStream<Self::Auth, RecommendedArticle>
+    /// A long crawl might be continued later from stream's `checkpoint`, see [`StreamCheckpoint`].
+    fn search_article(
+        &self,
+        name: ArticleTitle,
+    ) -> PageSearchStream<'_, '_, '_, Self::Auth, RecommendedArticle>
     where
-        Self: Sized,
+        Self: Sized + Sync,
     {
+        let name = Arc::new(name);
         PageSearchStream::create(self, move |page| {
-            let name_ = name.clone();
-            Box::pin(async move { self.search_article_page(&name_, page).await })
+            let name = Arc::clone(&name);
+            Box::pin(async move { self.search_article_page_meta(&name, page).await })
         })
     }
 
-    /// Get followers of a user by it's id.
+    /// Retrieves articles by their slugs, requesting up to `concurrency` of them at once.
+    ///
+    /// Results are returned in the order of the slugs, each paired with it's slug. Zero `concurrency` is treated as one.
+    async fn get_articles(
+        &self,
+        slugs: &[ArticleSlug],
+        concurrency: usize,
+    ) -> Vec<(ArticleSlug, Res<FullArticle>)> {
+        fetch_all(slugs, concurrency, |slug| async move {
+            self.get_article(&slug).await
+        })
+        .await
+    }
+
+    /// Downloads an image, like article's cover or user's avatar.
     ///
     /// # Implementation
-    /// Expected to GET `/api/relationships/USER_ID/followers?page=PAGE`
+    /// Expected to GET the url as-is, since images are served by a separate host.
     ///
     /// # Errors
-    /// - [Error::NoObject]: User with provided id does not exist
-    async fn get_followers_page(&self, id: &UserId, page: NonZeroUsize) -> Res<Vec<FollowerUser>>;
+    /// - [`Error::InvalidUrl`]: url is not valid
+    /// - [`Error::NoObject`]: image does not exist
+    async fn download_image(&self, url: &MaybeUrl) -> Res<ImageBytes>;
+}
 
-    /// Get followers of a user by it's id.
-    ///
-    /// # Returns
-    /// A stream of result pages.
+/// Requests concerning tags.
+#[async_trait]
+pub trait TagsApi: BaseApi {
+    /// Retrieves currently popular tags at Drukarnia.
     ///
-    /// # Implementation details
-    /// This function should not be reimplemented.
+    /// # Implementation
+    /// Expected to GET `/api/articles/tags/popular`.
+    async fn popular_tags(&self) -> Res<Vec<PopularTag>>;
+
+    /// Fetches a tag by it's slug.
     ///
-    /// Currently, the underlying stream consequently calls for result pages,
-    /// although this might be changed in the future for more optimized approach.
+    /// # Implementation
+    /// Expected to GET `/api/articles/tags/TAG_SLUG`
     ///
-    /// # Note
-    /// Stream ends after first error, since after the error, there's no way for stream to determine, if results had ended
-    fn get_followers(&self, id: UserId) -> PageSearchStream<Self::Auth, FollowerUser>
-    where
-        Self: Sized,
-    {
-        // TODO prettify this
-        PageSearchStream::create(self, move |page| {
-            let id_ = id.clone();
-            Box::pin(async move { self.get_followers_page(&id_, page).await })
+    /// # Errors
+    /// [`Error::NoObject`]: tag with provided slug does not exists
+    async fn get_tag(&self, slug: &TagSlug) -> Res<FullTag>;
+
+    /// Retrieves tags by their slugs, see [`ArticlesApi::get_articles`]
+    async fn get_tags(
+        &self,
+        slugs: &[TagSlug],
+        concurrency: usize,
+    ) -> Vec<(TagSlug, Res<FullTag>)> {
+        fetch_all(slugs, concurrency, |slug| async move {
+            self.get_tag(&slug).await
         })
+        .await
     }
+}
 
+/// Requests concerning comments.
+#[async_trait]
+pub trait CommentsApi: BaseApi {
     /// Get replies to a comment.
     ///
     /// # Implementation
@@ -211,13 +982,24 @@ pub trait DrukarniaApi {
     ///
     /// GET `/api/articles/000000000000000000000000/comments/COMMENT_ID/replies`
     async fn get_replies(&self, comment: &CommentId) -> Res<Vec<ReplyComment>>;
+}
 
+/// Requests concerning user's feed.
+#[async_trait]
+pub trait FeedApi: BaseApi {
     /// Get a single feed page.
     ///
     /// # Implementation
     /// Expected to GET to `/api/preferences/feed?page=PAGE`
     async fn feed_page(&self, page: NonZeroUsize) -> Res<Vec<FeedArticle>>;
 
+    /// Same as [`FeedApi::feed_page`], but tells if there are more pages, see [`Page::has_more`]
+    ///
+    /// By default, only an empty page is known to be the last one.
+    async fn feed_page_meta(&self, page: NonZeroUsize) -> Res<Page<FeedArticle>> {
+        Ok(Page::new(self.feed_page(page).await?, page))
+    }
+
     /// Get articles at feed.
     ///
     /// # Returns
@@ -231,24 +1013,163 @@ pub trait DrukarniaApi {
     ///
     /// # Note
     /// Stream ends after first error, since after the error, there's no way for stream to determine, if results had ended
-    fn feed(&self) -> PageSearchStream<Self::Auth, FeedArticle>
+    /// A long crawl might be continued later from stream's `checkpoint`, see [`StreamCheckpoint`].
+    fn feed(&self) -> PageSearchStream<'_, '_, '_, Self::Auth, FeedArticle>
     where
-        Self: Sized,
+        Self: Sized + Sync,
     {
-        PageSearchStream::create(self, |page| self.feed_page(page))
+        PageSearchStream::create(self, |page| self.feed_page_meta(page))
     }
 
-    /// Logs in a Drukarnia user.
+    /// Establishes a feed session, so that anonymous feed pages add up to a consistent sequence.
+    ///
+    /// By default, no session is established, and feed is requested as usual.
+    ///
+    /// # Implementation
+    /// Expected to GET to `/api/preferences/feed?page=1`, and keep cookies site sets in response, along with the page itself
+    async fn feed_session(&self) -> Res<FeedSession> {
+        Ok(FeedSession::default())
+    }
+
+    /// Same as [`FeedApi::feed_page`], but within a session, see [`FeedApi::feed_session`]
+    ///
+    /// By default, session is ignored.
+    ///
+    /// # Implementation
+    /// Expected to serve session's [`FeedSession::first_page`] without a request, if there's one
+    async fn feed_page_with(
+        &self,
+        _session: &FeedSession,
+        page: NonZeroUsize,
+    ) -> Res<Vec<FeedArticle>> {
+        self.feed_page(page).await
+    }
+
+    /// Same as [`FeedApi::feed_page_with`], but tells if there are more pages, see [`Page::has_more`]
     ///
-    /// Currently, should not be used and/or implemented
-    async fn login(&self, _credentials: Credentials) -> Res<Self::Auth>
+    /// By default, only an empty page is known to be the last one.
+    async fn feed_page_with_meta(
+        &self,
+        session: &FeedSession,
+        page: NonZeroUsize,
+    ) -> Res<Page<FeedArticle>> {
+        Ok(Page::new(self.feed_page_with(session, page).await?, page))
+    }
+
+    /// Get articles at feed, within a session, see [`FeedApi::feed`] and [`FeedApi::feed_session`]
+    ///
+    /// # Implementation details
+    /// This function should not be reimplemented.
+    fn feed_with(
+        &self,
+        session: FeedSession,
+    ) -> PageSearchStream<'_, '_, '_, Self::Auth, FeedArticle>
     where
-        Self::Auth: AuthDrukarnia,
+        Self: Sized + Sync,
     {
-        unimplemented!("Unstable");
+        let session = Arc::new(session);
+        PageSearchStream::create(self, move |page| {
+            let session = Arc::clone(&session);
+            Box::pin(async move { self.feed_page_with_meta(&session, page).await })
+        })
+    }
+
+    /// Establishes a feed session first, and then gets articles at feed within it, see [`FeedApi::feed_with`]
+    ///
+    /// # Implementation details
+    /// This function should not be reimplemented.
+    ///
+    /// # Errors
+    /// Whatever [`FeedApi::feed_session`] fails with
+    async fn feed_in_session(&self) -> Res<PageSearchStream<'_, '_, '_, Self::Auth, FeedArticle>>
+    where
+        Self: Sized + Sync,
+    {
+        let session = self.feed_session().await?;
+        Ok(self.feed_with(session))
     }
 }
 
+/// Represents object's ability to make requests to Drukarnia's API.
+///
+/// Requests are split between [`UsersApi`], [`ArticlesApi`], [`TagsApi`], [`CommentsApi`] and [`FeedApi`],
+/// and this trait is implemented for anything implementing all of them. It also holds requests spanning several of these.
+///
+/// Calling a method on a concrete accessor requires the trait defining it to be in scope, so it's easier to import all of them
+/// with the [`prelude`](crate::prelude). Generic code bound by `DrukarniaApi` can call all of them right away.
+#[async_trait]
+pub trait DrukarniaApi: UsersApi + ArticlesApi + TagsApi + CommentsApi + FeedApi {
+    /// Fetches an article along with replies to all of it's comments.
+    ///
+    /// # Returns
+    /// Fetched article and it's comments, arranged into reply trees.
+    ///
+    /// # Implementation details
+    /// This function should not be reimplemented.
+    ///
+    /// Replies are requested sequentially, one request per comment that has any.
+    ///
+    /// # Errors
+    /// - [`Error::NoObject`]: article with provided slug does not exist
+    async fn get_article_thread(&self, slug: &ArticleSlug) -> Res<(FullArticle, CommentThread)> {
+        let article = self.get_article(slug).await?;
+        let mut replies = vec![];
+        for comment in article.comments() {
+            if *comment.reply_num() > 0 {
+                replies.extend(self.get_replies(comment.id()).await?);
+            }
+        }
+        let thread = CommentThread::build(article.comments().clone(), replies);
+        Ok((article, thread))
+    }
+
+    /// Retrieves an article, along with all of the replies to it's comments.
+    ///
+    /// Unlike [`DrukarniaApi::get_article_thread`], requests up to `concurrency` replies at once,
+    /// and does not fail, if some of the replies could not be fetched - these are reported in [`ArticleWithThreads::partial_errors`] instead.
+    /// Zero `concurrency` is treated as one.
+    ///
+    /// # Errors
+    /// - [`Error::NoObject`]: article with provided slug does not exist
+    async fn get_article_with_replies(
+        &self,
+        slug: &ArticleSlug,
+        concurrency: usize,
+    ) -> Res<ArticleWithThreads> {
+        let article = self.get_article(slug).await?;
+        let commented: Vec<CommentId> = article
+            .comments()
+            .iter()
+            .filter(|comment| *comment.reply_num() > 0)
+            .map(|comment| comment.id().clone())
+            .collect();
+        let results: Vec<_> = futures::stream::iter(commented)
+            .map(|comment| async move {
+                let result = self.get_replies(&comment).await;
+                (comment, result)
+            })
+            .buffer_unordered(concurrency.max(1))
+            .collect()
+            .await;
+        let mut replies = vec![];
+        let mut partial_errors = vec![];
+        for (comment, result) in results {
+            match result {
+                Ok(comment_replies) => replies.extend(comment_replies),
+                Err(err) => partial_errors.push((comment, err)),
+            }
+        }
+        let thread = CommentThread::build(article.comments().clone(), replies);
+        Ok(ArticleWithThreads {
+            article,
+            thread,
+            partial_errors,
+        })
+    }
+}
+
+impl<T> DrukarniaApi for T where T: UsersApi + ArticlesApi + TagsApi + CommentsApi + FeedApi {}
+
 /// Represents Drukarnia API caller that currently has a valid authenticated user
 ///
 /// It's expected to log user out, once dropped
@@ -299,7 +1220,7 @@ pub trait AuthDrukarnia: Deref<Target = Self::Downgrade> {
     /// responds with the same, I guess
     async fn bookmark_article(&self, list: &ListId, article: &ArticleId) -> Res<FullBookmark>;
 
-    ///
+    /// DELETE to `/api/articles/{ARTICLE_ID}/bookmarks`
     async fn unbookmark_article(&self, article: &ArticleId) -> Res<FullBookmark>;
 
     /// GET to `/api/articles/bookmarks/lists/{LIST_ID}`
@@ -318,12 +1239,23 @@ pub trait AuthDrukarnia: Deref<Target = Self::Downgrade> {
     /// }
     async fn get_list_articles(&self, list: &ListId) -> Res<Vec<ListArticle>>;
 
-    /// - like: POST to `/api/articles/{ARTICLE_ID}/like`
-    /// with json body
-    /// {
-    ///     likes: LIKES_NUM????
-    /// }
-    async fn like_article(&self, article: &ArticleId, likes: usize) -> Res;
+    /// Sets the number of likes authorized user gives to an article.
+    ///
+    /// Amount replaces the one given before, rather than adding to it, so that [`LikeAmount::ZERO`]
+    /// removes the like altogether, see [`AuthDrukarnia::unlike_article`].
+    /// Thus, article's `like_num` changes by the difference between the amounts.
+    ///
+    /// # Implementation
+    /// Expected to POST `/api/articles/{ARTICLE_ID}/like` with JSON body `{"likes": AMOUNT}`
+    ///
+    /// # Errors
+    /// - [`Error::NoObject`]: article with provided id does not exist
+    async fn like_article(&self, article: &ArticleId, amount: LikeAmount) -> Res;
+
+    /// Removes authorized user's likes from an article, same as liking it with [`LikeAmount::ZERO`]
+    async fn unlike_article(&self, article: &ArticleId) -> Res {
+        self.like_article(article, LikeAmount::ZERO).await
+    }
 
     // Postponed for future revisions
     /*
@@ -339,12 +1271,22 @@ pub trait AuthDrukarnia: Deref<Target = Self::Downgrade> {
     ) -> Res<CommentId>;
     */
 
-    /// -like: POST to `/api/articles/{ARTICLE_ID}/comments/{COMMENT_ID}/likes`
-    /// with empty json body
+    /// Likes or unlikes a comment, returning it's resulting likes.
     ///
-    /// - unlike: DELETE to same endpoint
-    async fn set_comment_liked(&self, article: &ArticleId, comment: &CommentId, liked: bool)
-        -> Res;
+    /// # Implementation
+    /// - like: POST to `/api/articles/{ARTICLE_ID}/comments/{COMMENT_ID}/likes` with empty body
+    /// - unlike: DELETE to the same endpoint
+    ///
+    /// Both respond with JSON containing `likesNum` and `isLiked`.
+    ///
+    /// # Errors
+    /// - [`Error::NoObject`]: article or comment with provided id does not exist
+    async fn set_comment_liked(
+        &self,
+        article: &ArticleId,
+        comment: &CommentId,
+        liked: bool,
+    ) -> Res<CommentLikeState>;
 
     // Actual interface for this needs some thinking
     // Reserved for future revisions
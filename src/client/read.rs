@@ -0,0 +1,131 @@
+use std::num::NonZeroUsize;
+
+use async_trait::async_trait;
+use url::Url;
+
+use crate::object::{
+    ArticleSlug, ArticleTitle, CommentId, FeedArticle, FollowerUser, FullArticle, FullTag,
+    FullUser, MaybeUrl, PopularTag, RecommendedArticle, ReplyComment, ShortUser, TagSlug, UserId,
+    UserName,
+};
+
+use super::{
+    ArticlesApi, BaseApi, CommentsApi, DrukarniaApi, FeedApi, ImageBytes, Res, TagsApi, UsersApi,
+};
+
+/// Object-safe subset of [`DrukarniaApi`], for accepting any accessor as `&dyn DrukarniaRead` or `Box<dyn DrukarniaRead>`
+///
+/// Unlike [`DrukarniaApi`], it has no associated authorized accessor type, so accessors with different
+/// [`BaseApi::Auth`] could be stored together. Streams and logging in are left out, since they need the concrete type.
+///
+/// Every [`DrukarniaApi`] accessor implements this trait, and there's no need to implement it directly.
+/// Methods are named the same, so it's better not to import both traits into the same scope.
+///
+/// ```
+/// # use type_matrux::{client::{DrukarniaRead, Error}, object::{FullUser, UserName}, ReqwestApi};
+/// async fn fetch_user(api: &dyn DrukarniaRead, name: &UserName) -> Result<FullUser, Error> {
+///     api.get_user(name).await
+/// }
+///
+/// let api: Box<dyn DrukarniaRead> = Box::new(ReqwestApi::new());
+/// ```
+#[async_trait]
+pub trait DrukarniaRead: Send + Sync {
+    /// See [`BaseApi::base_url`]
+    fn base_url(&self) -> &Url;
+
+    /// See [`TagsApi::popular_tags`]
+    async fn popular_tags(&self) -> Res<Vec<PopularTag>>;
+
+    /// See [`UsersApi::get_user`]
+    async fn get_user(&self, name: &UserName) -> Res<FullUser>;
+
+    /// See [`UsersApi::search_user_page`]
+    async fn search_user_page(&self, name: &UserName, page: NonZeroUsize) -> Res<Vec<ShortUser>>;
+
+    /// See [`TagsApi::get_tag`]
+    async fn get_tag(&self, slug: &TagSlug) -> Res<FullTag>;
+
+    /// See [`ArticlesApi::get_article`]
+    async fn get_article(&self, slug: &ArticleSlug) -> Res<FullArticle>;
+
+    /// See [`ArticlesApi::get_article_by_url`]
+    async fn get_article_by_url(&self, url: &Url) -> Res<FullArticle>;
+
+    /// See [`ArticlesApi::search_article_page`]
+    async fn search_article_page(
+        &self,
+        name: &ArticleTitle,
+        page: NonZeroUsize,
+    ) -> Res<Vec<RecommendedArticle>>;
+
+    /// See [`UsersApi::get_followers_page`]
+    async fn get_followers_page(&self, id: &UserId, page: NonZeroUsize) -> Res<Vec<FollowerUser>>;
+
+    /// See [`CommentsApi::get_replies`]
+    async fn get_replies(&self, comment: &CommentId) -> Res<Vec<ReplyComment>>;
+
+    /// See [`ArticlesApi::download_image`]
+    async fn download_image(&self, url: &MaybeUrl) -> Res<ImageBytes>;
+
+    /// See [`FeedApi::feed_page`]
+    async fn feed_page(&self, page: NonZeroUsize) -> Res<Vec<FeedArticle>>;
+}
+
+#[async_trait]
+impl<A> DrukarniaRead for A
+where
+    A: DrukarniaApi + Send + Sync,
+{
+    fn base_url(&self) -> &Url {
+        BaseApi::base_url(self)
+    }
+
+    async fn popular_tags(&self) -> Res<Vec<PopularTag>> {
+        TagsApi::popular_tags(self).await
+    }
+
+    async fn get_user(&self, name: &UserName) -> Res<FullUser> {
+        UsersApi::get_user(self, name).await
+    }
+
+    async fn search_user_page(&self, name: &UserName, page: NonZeroUsize) -> Res<Vec<ShortUser>> {
+        UsersApi::search_user_page(self, name, page).await
+    }
+
+    async fn get_tag(&self, slug: &TagSlug) -> Res<FullTag> {
+        TagsApi::get_tag(self, slug).await
+    }
+
+    async fn get_article(&self, slug: &ArticleSlug) -> Res<FullArticle> {
+        ArticlesApi::get_article(self, slug).await
+    }
+
+    async fn get_article_by_url(&self, url: &Url) -> Res<FullArticle> {
+        ArticlesApi::get_article_by_url(self, url).await
+    }
+
+    async fn search_article_page(
+        &self,
+        name: &ArticleTitle,
+        page: NonZeroUsize,
+    ) -> Res<Vec<RecommendedArticle>> {
+        ArticlesApi::search_article_page(self, name, page).await
+    }
+
+    async fn get_followers_page(&self, id: &UserId, page: NonZeroUsize) -> Res<Vec<FollowerUser>> {
+        UsersApi::get_followers_page(self, id, page).await
+    }
+
+    async fn get_replies(&self, comment: &CommentId) -> Res<Vec<ReplyComment>> {
+        CommentsApi::get_replies(self, comment).await
+    }
+
+    async fn download_image(&self, url: &MaybeUrl) -> Res<ImageBytes> {
+        ArticlesApi::download_image(self, url).await
+    }
+
+    async fn feed_page(&self, page: NonZeroUsize) -> Res<Vec<FeedArticle>> {
+        FeedApi::feed_page(self, page).await
+    }
+}
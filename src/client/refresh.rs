@@ -0,0 +1,76 @@
+use async_trait::async_trait;
+use time::Duration;
+
+use crate::{
+    object::{Aged, FullArticle, FullTag, FullUser},
+    DrukarniaApi,
+};
+
+use super::Res;
+
+/// Represents object's ability to be re-fetched from the site
+#[async_trait]
+pub trait Refresh: Aged + Sized {
+    /// Fetches the object again, replacing `self` with the result
+    ///
+    /// # Errors
+    /// Same as the method used to fetch the object. In case of an error, `self` is left unchanged.
+    async fn refresh<A>(&mut self, api: &A) -> Res
+    where
+        A: DrukarniaApi + Sync + ?Sized;
+
+    /// Refreshes the object, if it was fetched more than `age` ago
+    ///
+    /// # Returns
+    /// `true` if the object was refreshed
+    ///
+    /// # Errors
+    /// Same as [`Refresh::refresh`]
+    async fn refresh_if_older_than<A>(&mut self, age: Duration, api: &A) -> Res<bool>
+    where
+        A: DrukarniaApi + Sync + ?Sized,
+    {
+        if self.is_older_than(age) {
+            self.refresh(api).await?;
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+}
+
+/// Article is refetched by it's slug
+#[async_trait]
+impl Refresh for FullArticle {
+    async fn refresh<A>(&mut self, api: &A) -> Res
+    where
+        A: DrukarniaApi + Sync + ?Sized,
+    {
+        *self = api.get_article(self.slug()).await?;
+        Ok(())
+    }
+}
+
+/// User is refetched by it's username
+#[async_trait]
+impl Refresh for FullUser {
+    async fn refresh<A>(&mut self, api: &A) -> Res
+    where
+        A: DrukarniaApi + Sync + ?Sized,
+    {
+        *self = api.get_user(self.username()).await?;
+        Ok(())
+    }
+}
+
+/// Tag is refetched by it's slug
+#[async_trait]
+impl Refresh for FullTag {
+    async fn refresh<A>(&mut self, api: &A) -> Res
+    where
+        A: DrukarniaApi + Sync + ?Sized,
+    {
+        *self = api.get_tag(self.slug()).await?;
+        Ok(())
+    }
+}
@@ -1,28 +1,59 @@
-use std::{num::NonZeroUsize, pin::Pin, task::Poll};
+use std::{collections::HashSet, hash::Hash, num::NonZeroUsize, pin::Pin, task::Poll};
 
+use derive_getters::Getters;
 use futures::{Future, Stream};
 use pin_project::pin_project;
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
 
-use crate::DrukarniaApi;
+use crate::{
+    client::BaseApi,
+    object::{HasCreatedAt, HasId, HasSlug},
+};
 
-use super::Res;
+use super::{Page, Res};
 
-type Fut<'l, E> = Pin<Box<dyn Future<Output = Res<Vec<E>>> + 'l>>;
+type Fut<'l, E> = Pin<Box<dyn Future<Output = Res<Page<E>>> + 'l>>;
+
+/// Page index, number of objects skipped at it, and the rest of it's objects
+type Fetched<E> = (NonZeroUsize, usize, Vec<E>);
+
+/// Position in a paged stream, to resume it from later
+///
+/// It does not contain the query itself, so it's up to you to resume the same stream it was taken from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Getters, Serialize, Deserialize)]
+pub struct StreamCheckpoint {
+    /// Page to be fetched next
+    page: NonZeroUsize,
+    /// Number of objects at that page that were already consumed
+    offset: usize,
+}
+
+impl StreamCheckpoint {
+    /// Creates a checkpoint at `offset` object of the `page`
+    pub fn new(page: NonZeroUsize, offset: usize) -> Self {
+        Self { page, offset }
+    }
+}
 
 #[pin_project]
 pub struct PageSearchStream<'client, 'generator, 'future, Auth, E> {
-    pub(super) client: &'client dyn DrukarniaApi<Auth = Auth>,
+    pub(super) client: &'client dyn BaseApi<Auth = Auth>,
     pub(super) generator: Box<dyn (Fn(NonZeroUsize) -> Fut<'future, E>) + 'generator>,
     pub(super) current_page: NonZeroUsize,
     #[pin]
     current_future: Fut<'future, E>,
+    /// Objects to drop from the next page, as they were consumed before resuming
+    skip: usize,
     errored: bool,
+    /// Last page was returned already
+    finished: bool,
 }
 
 impl<'client, 'generator, 'future, Auth, E>
     PageSearchStream<'client, 'generator, 'future, Auth, E>
 {
-    pub(super) fn create<G>(client: &'client dyn DrukarniaApi<Auth = Auth>, generator: G) -> Self
+    pub(super) fn create<G>(client: &'client dyn BaseApi<Auth = Auth>, generator: G) -> Self
     where
         'client: 'generator,
         'generator: 'future,
@@ -34,14 +65,99 @@ impl<'client, 'generator, 'future, Auth, E>
             client,
             generator: Box::new(generator),
             current_page: first_page,
+            skip: 0,
             errored: false,
+            finished: false,
         }
     }
 
+    /// Continues the stream from a checkpoint, instead of the first page
+    ///
+    /// Objects consumed before the checkpoint are dropped from it's page, so that a flattened stream
+    /// yields exactly the objects that were left.
+    pub fn resume_from(mut self, checkpoint: StreamCheckpoint) -> Self {
+        self.current_future = (self.generator)(checkpoint.page);
+        self.current_page = checkpoint.page;
+        self.skip = checkpoint.offset;
+        self.errored = false;
+        self.finished = false;
+        self
+    }
+
+    /// Page to be fetched next
+    pub fn current_page(&self) -> NonZeroUsize {
+        self.current_page
+    }
+
+    /// `true` if stream has ended due to an error
+    pub fn has_errored(&self) -> bool {
+        self.errored
+    }
+
+    /// Position to resume the stream from, see [`PageSearchStream::resume_from`]
+    pub fn checkpoint(&self) -> StreamCheckpoint {
+        StreamCheckpoint::new(self.current_page, self.skip)
+    }
+
     pub fn flat(self) -> SearchStream<'client, 'generator, 'future, Auth, E> {
         SearchStream {
             parent: self,
             this_page: vec![],
+            page: NonZeroUsize::new(1).expect("1 != 0"),
+            offset: 0,
+        }
+    }
+
+    /// Polls for the next page, along with it's index, and a number of objects skipped at it
+    fn poll_page(
+        self: Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> Poll<Option<Res<Fetched<E>>>> {
+        let mut projection = self.project();
+        loop {
+            if *projection.errored || *projection.finished {
+                // API had errored previously, or there are no more pages
+                return Poll::Ready(None);
+            }
+            let res = match projection.current_future.as_mut().poll(cx) {
+                Poll::Ready(res) => res,
+                // Next page was not loaded yet
+                Poll::Pending => return Poll::Pending,
+            };
+            let page = match res {
+                Ok(page) => page,
+                Err(err) => {
+                    // API had errored
+                    // Return the error now, but flip the flag, so that on next poll stream would end
+                    *projection.errored = true;
+                    return Poll::Ready(Some(Err(err)));
+                }
+            };
+            let index = *projection.current_page;
+            let is_last = page.is_last();
+            let mut items = page.into_items();
+            if items.is_empty() {
+                // Results had ended, and so is this stream
+                *projection.finished = true;
+                return Poll::Ready(None);
+            }
+            // Step up the page
+            *projection.current_page = index.saturating_add(1);
+            if is_last {
+                // No need to request the next page, it's known to be empty
+                *projection.finished = true;
+            } else {
+                // Create new future
+                projection
+                    .current_future
+                    .set((projection.generator)(*projection.current_page));
+            }
+            let skip = std::mem::take(projection.skip);
+            if skip < items.len() {
+                items.drain(..skip);
+                return Poll::Ready(Some(Ok((index, skip, items))));
+            }
+            // Everything at this page was consumed before resuming, go on to the next one
         }
     }
 }
@@ -58,44 +174,8 @@ where
         self: std::pin::Pin<&mut Self>,
         cx: &mut std::task::Context<'_>,
     ) -> Poll<Option<Self::Item>> {
-        let mut projection = self.project();
-        if *projection.errored {
-            // API had errored previously, end the stream
-            return Poll::Ready(None);
-        }
-
-        match projection.current_future.as_mut().poll(cx) {
-            Poll::Ready(res) => {
-                match res {
-                    Ok(ok) => {
-                        if ok.is_empty() {
-                            // Results had ended, and so is this stream
-                            Poll::Ready(None)
-                        } else {
-                            // Next page fetched successfully
-                            // Step up the page
-                            *projection.current_page = projection.current_page.saturating_add(1);
-                            // Create new future
-                            projection
-                                .current_future
-                                .set((projection.generator)(*projection.current_page));
-                            // Return current result
-                            Poll::Ready(Some(Ok(ok)))
-                        }
-                    }
-                    Err(err) => {
-                        // API had errored
-                        // Return the error now, but flip the flag, so that on next poll stream would end
-                        *projection.errored = true;
-                        Poll::Ready(Some(Err(err)))
-                    }
-                }
-            }
-            Poll::Pending => {
-                // Next page was not loaded yet
-                Poll::Pending
-            }
-        }
+        self.poll_page(cx)
+            .map(|page| page.map(|page| page.map(|(_, _, items)| items)))
     }
 }
 
@@ -103,7 +183,28 @@ where
 pub struct SearchStream<'client, 'generator, 'future, Auth, E> {
     #[pin]
     parent: PageSearchStream<'client, 'generator, 'future, Auth, E>,
+    /// Objects of the current page left to yield, in reverse order
     this_page: Vec<E>,
+    /// Index of the current page
+    page: NonZeroUsize,
+    /// Number of objects at the current page yielded so far
+    offset: usize,
+}
+
+impl<'client, 'generator, 'future, Auth, E> SearchStream<'client, 'generator, 'future, Auth, E> {
+    /// `true` if stream has ended due to an error
+    pub fn has_errored(&self) -> bool {
+        self.parent.has_errored()
+    }
+
+    /// Position to resume the stream from, see [`PageSearchStream::resume_from`]
+    pub fn checkpoint(&self) -> StreamCheckpoint {
+        if self.this_page.is_empty() {
+            self.parent.checkpoint()
+        } else {
+            StreamCheckpoint::new(self.page, self.offset)
+        }
+    }
 }
 
 impl<'client, 'generator, 'future, Auth, E> Stream
@@ -118,23 +219,139 @@ where
         self: Pin<&mut Self>,
         cx: &mut std::task::Context<'_>,
     ) -> Poll<Option<Self::Item>> {
-        let projection = self.project();
-        if projection.this_page.is_empty() {
-            let mut new_page = match projection.parent.poll_next(cx) {
+        let mut projection = self.project();
+        loop {
+            if let Some(object) = projection.this_page.pop() {
+                *projection.offset += 1;
+                return Poll::Ready(Some(Ok(object)));
+            }
+            let (index, skipped, mut new_page) = match projection.parent.as_mut().poll_page(cx) {
                 Poll::Ready(Some(Ok(page))) => page,
                 Poll::Ready(Some(Err(err))) => return Poll::Ready(Some(Err(err))),
                 Poll::Ready(None) => return Poll::Ready(None),
                 Poll::Pending => return Poll::Pending,
             };
-
             new_page.reverse();
             *projection.this_page = new_page;
+            *projection.page = index;
+            *projection.offset = skipped;
         }
-        // This page is not empty now
-        if let Some(article) = projection.this_page.pop() {
-            Poll::Ready(Some(Ok(article)))
-        } else {
-            Poll::Ready(None)
+    }
+}
+
+/// Adapters for streams of objects, like ones returned by [`DrukarniaApi`](crate::DrukarniaApi) search methods
+pub trait ObjectStreamExt<E>: Stream<Item = Res<E>> + Sized {
+    /// Skips objects that were already yielded by this stream
+    ///
+    /// Site's pagination may shift while stream is being consumed, so same object might appear twice.
+    fn dedup(self) -> Dedup<Self, E, [u8; 12]>
+    where
+        E: HasId,
+    {
+        Dedup {
+            stream: self,
+            seen: HashSet::new(),
+            key: |object| *object.raw_id(),
+        }
+    }
+
+    /// Same as [`ObjectStreamExt::dedup`], but identifies objects by their slug
+    ///
+    /// Intended for objects that might lack an id, like [`RecommendedArticle`](crate::object::RecommendedArticle).
+    fn dedup_by_slug(self) -> Dedup<Self, E, String>
+    where
+        E: HasSlug,
+    {
+        Dedup {
+            stream: self,
+            seen: HashSet::new(),
+            key: |object| object.slug_str().to_owned(),
+        }
+    }
+
+    /// Ends the stream on the first object created before `cutoff`
+    ///
+    /// Intended for streams ordered newest first, like feed.
+    fn until_older_than(self, cutoff: OffsetDateTime) -> UntilOlderThan<Self>
+    where
+        E: HasCreatedAt,
+    {
+        UntilOlderThan {
+            stream: self,
+            cutoff,
+            ended: false,
+        }
+    }
+}
+
+impl<S, E> ObjectStreamExt<E> for S where S: Stream<Item = Res<E>> {}
+
+/// Stream for [`ObjectStreamExt::dedup`] and [`ObjectStreamExt::dedup_by_slug`]
+#[pin_project]
+pub struct Dedup<S, E, K> {
+    #[pin]
+    stream: S,
+    seen: HashSet<K>,
+    key: fn(&E) -> K,
+}
+
+impl<S, E, K> Stream for Dedup<S, E, K>
+where
+    S: Stream<Item = Res<E>>,
+    K: Eq + Hash,
+{
+    type Item = Res<E>;
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        let mut projection = self.project();
+        loop {
+            match projection.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(Ok(object))) => {
+                    if projection.seen.insert((projection.key)(&object)) {
+                        return Poll::Ready(Some(Ok(object)));
+                    }
+                    // Object was seen already, poll for the next one
+                }
+                other => return other,
+            }
+        }
+    }
+}
+
+/// Stream for [`ObjectStreamExt::until_older_than`]
+#[pin_project]
+pub struct UntilOlderThan<S> {
+    #[pin]
+    stream: S,
+    cutoff: OffsetDateTime,
+    ended: bool,
+}
+
+impl<S, E> Stream for UntilOlderThan<S>
+where
+    S: Stream<Item = Res<E>>,
+    E: HasCreatedAt,
+{
+    type Item = Res<E>;
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        let projection = self.project();
+        if *projection.ended {
+            return Poll::Ready(None);
+        }
+        match projection.stream.poll_next(cx) {
+            Poll::Ready(Some(Ok(object))) if object.created_at() < *projection.cutoff => {
+                // Objects are too old from now on, end the stream
+                *projection.ended = true;
+                Poll::Ready(None)
+            }
+            other => other,
         }
     }
 }
@@ -0,0 +1,237 @@
+use std::{path::Path, time::Duration};
+
+use derive_getters::Getters;
+use secrecy::ExposeSecret;
+use serde::{Deserialize, Deserializer};
+use url::Url;
+
+use crate::{
+    client::{BaseApi, DrukarniaClientBuilder, Error, ReqwestAuth},
+    object::{Credentials, LoginIdentifier},
+    DrukarniaClient,
+};
+
+/// Prefix of the environment variables read by [`Config::from_env`]
+pub const ENV_PREFIX: &str = "TYPE_MATRUX_";
+
+/// An error that might occur while loading [`Config`]
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum ConfigError {
+    /// Config file could not be read
+    #[error("Could not read config file: {0}")]
+    Io(#[from] std::io::Error),
+    /// Config file is not a valid config, or misses a required field
+    #[error("Config file is not valid: {0}")]
+    Parse(#[from] toml::de::Error),
+    /// Environment variable has an invalid value
+    #[error("Environment variable {name} is not valid: {reason}")]
+    BadVar {
+        /// Variable's name
+        name: String,
+        /// What's wrong with the value
+        reason: String,
+    },
+    /// A field is required, but is not set neither in the file nor in the environment
+    #[error("{0} is required, but is not set")]
+    MissingField(&'static str),
+}
+
+/// Request timeouts, in milliseconds in the config file
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Getters)]
+#[serde(default, deny_unknown_fields)]
+pub struct Timeouts {
+    /// Timeout for each request
+    #[serde(deserialize_with = "millis")]
+    request: Option<Duration>,
+    /// Timeout for connecting to the site
+    #[serde(deserialize_with = "millis")]
+    connect: Option<Duration>,
+}
+
+/// Runtime configuration, read from a TOML file and environment
+///
+/// All fields are optional, and crate defaults are used for the missing ones. Durations are in milliseconds:
+/// ```toml
+/// base_url = "https://drukarnia.com.ua/"
+/// user_agent = "my-scraper/1.0 (me@example.com)"
+/// min_request_interval = 500
+///
+/// [timeouts]
+/// request = 10000
+/// connect = 3000
+///
+/// [credentials]
+/// email = "me@example.com"
+/// password = "hunter2"
+/// ```
+///
+/// Credentials might have `username` in place of `email`.
+///
+/// Environment variables override the file, see [`Config::merge_env`] for their names.
+///
+/// Credentials are never shown in `Debug` output.
+#[derive(Debug, Clone, Default, Deserialize, Getters)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Credentials to log in with
+    credentials: Option<Credentials>,
+    /// Url API is located at
+    base_url: Option<Url>,
+    /// User agent to identify with
+    user_agent: Option<String>,
+    /// Minimal time between the starts of two consequent requests
+    #[serde(deserialize_with = "millis")]
+    min_request_interval: Option<Duration>,
+    /// Request timeouts
+    timeouts: Timeouts,
+}
+
+fn millis<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Duration>, D::Error> {
+    Ok(Option::<u64>::deserialize(deserializer)?.map(Duration::from_millis))
+}
+
+impl Config {
+    /// Reads config from a TOML file, overriding it with environment variables
+    ///
+    /// # Errors
+    /// See [`ConfigError`]
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        Self::from_toml(&std::fs::read_to_string(path)?)?.merge_env(std::env::vars())
+    }
+
+    /// Reads config from environment variables only
+    ///
+    /// # Errors
+    /// See [`ConfigError`]
+    pub fn from_env() -> Result<Self, ConfigError> {
+        Self::default().merge_env(std::env::vars())
+    }
+
+    /// Parses config from a TOML string, without looking at environment
+    ///
+    /// # Errors
+    /// [`ConfigError::Parse`], if string is not a valid config
+    pub fn from_toml(toml: &str) -> Result<Self, ConfigError> {
+        Ok(toml::from_str(toml)?)
+    }
+
+    /// Overrides config with the variables, ignoring the ones not starting with [`ENV_PREFIX`]
+    ///
+    /// Recognized variables are (without the prefix):
+    /// - `EMAIL` (or `USERNAME`) and `PASSWORD`, both of which are required, unless credentials are already set.
+    ///   `EMAIL` takes precedence over `USERNAME`;
+    /// - `BASE_URL` and `USER_AGENT`;
+    /// - `MIN_REQUEST_INTERVAL`, `TIMEOUT` and `CONNECT_TIMEOUT`, in milliseconds.
+    ///
+    /// # Errors
+    /// - [`ConfigError::BadVar`], if a variable has an invalid value
+    /// - [`ConfigError::MissingField`], if only one of login identifier and password is set, and there are no credentials to take
+    ///   the other one from
+    pub fn merge_env(
+        mut self,
+        vars: impl IntoIterator<Item = (String, String)>,
+    ) -> Result<Self, ConfigError> {
+        let mut email = None;
+        let mut username = None;
+        let mut password = None;
+        for (name, value) in vars {
+            let Some(key) = name.strip_prefix(ENV_PREFIX) else {
+                continue;
+            };
+            let bad = |reason: String| ConfigError::BadVar {
+                name: name.clone(),
+                reason,
+            };
+            let millis = |value: &str| {
+                value
+                    .parse()
+                    .map(Duration::from_millis)
+                    .map_err(|err| bad(format!("{err}")))
+            };
+            match key {
+                "EMAIL" => email = Some(value),
+                "USERNAME" => username = Some(value),
+                "PASSWORD" => password = Some(value),
+                "BASE_URL" => {
+                    self.base_url = Some(value.parse().map_err(|err| bad(format!("{err}")))?);
+                }
+                "USER_AGENT" => self.user_agent = Some(value),
+                "MIN_REQUEST_INTERVAL" => self.min_request_interval = Some(millis(&value)?),
+                "TIMEOUT" => self.timeouts.request = Some(millis(&value)?),
+                "CONNECT_TIMEOUT" => self.timeouts.connect = Some(millis(&value)?),
+                _ => {}
+            }
+        }
+
+        if email.is_some() || username.is_some() || password.is_some() {
+            // variables might override just one of the file's credentials
+            let current = self.credentials.as_ref();
+            let identifier =
+                match (email, username) {
+                    (Some(email), _) => LoginIdentifier::Email(email.parse().map_err(
+                        |err: email_address::Error| ConfigError::BadVar {
+                            name: format!("{ENV_PREFIX}EMAIL"),
+                            reason: err.to_string(),
+                        },
+                    )?),
+                    (None, Some(username)) => LoginIdentifier::Username(
+                        username.parse().unwrap_or_else(|never| match never {}),
+                    ),
+                    (None, None) => current
+                        .map(|credentials| credentials.identifier().clone())
+                        .ok_or(ConfigError::MissingField("email"))?,
+                };
+            let password = password
+                .or_else(|| {
+                    current.map(|credentials| credentials.password().expose_secret().clone())
+                })
+                .ok_or(ConfigError::MissingField("password"))?;
+            self.credentials = Some(Credentials::with_identifier(identifier, password));
+        }
+        Ok(self)
+    }
+
+    /// Client builder with everything but credentials configured
+    pub fn client_builder(&self) -> DrukarniaClientBuilder {
+        let mut builder = DrukarniaClient::builder();
+        if let Some(base_url) = &self.base_url {
+            builder = builder.base_url(base_url.clone());
+        }
+        if let Some(user_agent) = &self.user_agent {
+            builder = builder.user_agent(user_agent);
+        }
+        if let Some(interval) = self.min_request_interval {
+            builder = builder.min_request_interval(interval);
+        }
+        if let Some(timeout) = self.timeouts.request {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(timeout) = self.timeouts.connect {
+            builder = builder.connect_timeout(timeout);
+        }
+        builder
+    }
+
+    /// Builds configured client
+    ///
+    /// # Errors
+    /// [`Error::OnExecution`], if HTTP client could not be initialized
+    pub fn build_client(&self) -> Result<DrukarniaClient, Error> {
+        self.client_builder().build()
+    }
+
+    /// Builds configured client and logs in with configured credentials
+    ///
+    /// `None`, if there are no credentials configured.
+    ///
+    /// # Errors
+    /// Same as [`Config::build_client`] and [`BaseApi::login`]
+    pub async fn build_authorized(&self) -> Result<Option<ReqwestAuth>, Error> {
+        let client = self.build_client()?;
+        match self.credentials.clone() {
+            Some(credentials) => client.login(credentials).await.map(Some),
+            None => Ok(None),
+        }
+    }
+}
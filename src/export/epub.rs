@@ -0,0 +1,428 @@
+use std::collections::HashMap;
+
+use derive_getters::Getters;
+use time::{format_description::well_known::Rfc3339, OffsetDateTime, UtcOffset};
+use url::Url;
+
+use crate::{
+    client::ImageBytes,
+    object::{escape_html, ContentBlock, FullArticle, MaybeUrl},
+};
+
+use super::article_header;
+
+/// An error that might occur while building an EPUB
+#[derive(Debug, thiserror::Error, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum EpubError {
+    /// Book should contain at least one chapter
+    #[error("Book should contain at least one article")]
+    NoArticles,
+    /// ZIP container can't fit the entry (or too many of them), see [`build`]
+    #[error("Entry {0} does not fit into the container")]
+    EntryTooLarge(String),
+}
+
+/// Metadata of the book, see [`build`]
+#[derive(Debug, Clone, Getters)]
+pub struct BookMeta {
+    /// Book's title
+    title: String,
+    /// Book's language tag, `uk` by default
+    language: String,
+    /// Book's authors. If empty, authors of the articles are used
+    authors: Vec<String>,
+    /// Unique identifier of the book. If not specified, one is derived from article ids
+    identifier: Option<String>,
+    /// Last modification time. If not specified, creation time of the newest article is used
+    modified: Option<OffsetDateTime>,
+}
+
+impl BookMeta {
+    /// Metadata of a book with provided title
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            language: "uk".to_owned(),
+            authors: vec![],
+            identifier: None,
+            modified: None,
+        }
+    }
+
+    /// Sets book's language
+    #[must_use]
+    pub fn with_language(mut self, language: impl Into<String>) -> Self {
+        self.language = language.into();
+        self
+    }
+
+    /// Adds an author to the book
+    #[must_use]
+    pub fn with_author(mut self, author: impl Into<String>) -> Self {
+        self.authors.push(author.into());
+        self
+    }
+
+    /// Sets book's unique identifier
+    #[must_use]
+    pub fn with_identifier(mut self, identifier: impl Into<String>) -> Self {
+        self.identifier = Some(identifier.into());
+        self
+    }
+
+    /// Sets book's last modification time
+    #[must_use]
+    pub fn with_modified(mut self, modified: OffsetDateTime) -> Self {
+        self.modified = Some(modified);
+        self
+    }
+}
+
+/// Builds an EPUB3 book, with a chapter per article.
+///
+/// Images are left at their remote locations, see [`build_with_images`] to bundle them.
+///
+/// # Errors
+/// See [`EpubError`]
+pub fn build(articles: &[FullArticle], meta: BookMeta) -> Result<Vec<u8>, EpubError> {
+    build_with_images(articles, meta, |_| None)
+}
+
+/// Same as [`build`], but bundles images from the articles' content into the book.
+///
+/// `fetch` is called once per image url, and is expected to return image's bytes (obtained with
+/// [`ArticlesApi::download_image`](crate::client::ArticlesApi::download_image), for example).
+/// Images it returns `None` for, or that have no image content type, are left at their remote locations.
+///
+/// # Errors
+/// See [`EpubError`]
+pub fn build_with_images(
+    articles: &[FullArticle],
+    meta: BookMeta,
+    mut fetch: impl FnMut(&Url) -> Option<ImageBytes>,
+) -> Result<Vec<u8>, EpubError> {
+    if articles.is_empty() {
+        return Err(EpubError::NoArticles);
+    }
+    let language = escape_html(&meta.language);
+
+    let mut images: Vec<BundledImage> = vec![];
+    let mut bundled: HashMap<Url, Option<String>> = HashMap::new();
+    let mut chapters = vec![];
+    for (index, article) in articles.iter().enumerate() {
+        let mut remote = false;
+        let mut body = article_header(article);
+        for block in article.content() {
+            let mut html = block.to_html();
+            if let ContentBlock::Image {
+                url: MaybeUrl::Url(url),
+                ..
+            } = block
+            {
+                let local = bundled
+                    .entry(url.clone())
+                    .or_insert_with(|| fetch(url).and_then(|image| bundle(&mut images, image)));
+                match local {
+                    Some(href) => {
+                        html = html.replace(
+                            &format!("src=\"{}\"", escape_html(url.as_str())),
+                            &format!("src=\"../{href}\""),
+                        );
+                    }
+                    None => remote |= matches!(url.scheme(), "http" | "https"),
+                }
+            }
+            body.push(html);
+        }
+        let title = escape_html(article.title().as_ref());
+        let document = format!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<!DOCTYPE html>\n<html xmlns=\"http://www.w3.org/1999/xhtml\" lang=\"{language}\" xml:lang=\"{language}\">\n<head>\n<meta charset=\"utf-8\"/>\n<title>{title}</title>\n</head>\n<body>\n<article>\n{}\n</article>\n</body>\n</html>\n",
+            xhtml(&body.join("\n"))
+        );
+        chapters.push(Chapter {
+            href: format!("chapters/chapter-{}.xhtml", index + 1),
+            title,
+            remote,
+            document,
+        });
+    }
+
+    let mut zip = Zip::default();
+    // mimetype should go first, and be stored uncompressed
+    zip.add("mimetype", b"application/epub+zip")?;
+    zip.add("META-INF/container.xml", CONTAINER.as_bytes())?;
+    zip.add(
+        "OEBPS/content.opf",
+        package(articles, &meta, &chapters, &images).as_bytes(),
+    )?;
+    zip.add("OEBPS/nav.xhtml", nav(&meta, &chapters).as_bytes())?;
+    for chapter in &chapters {
+        zip.add(
+            &format!("OEBPS/{}", chapter.href),
+            chapter.document.as_bytes(),
+        )?;
+    }
+    for image in &images {
+        zip.add(&format!("OEBPS/{}", image.href), &image.bytes)?;
+    }
+    zip.finish()
+}
+
+const CONTAINER: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<container version="1.0" xmlns="urn:oasis:names:tc:opendocument:xmlns:container">
+<rootfiles>
+<rootfile full-path="OEBPS/content.opf" media-type="application/oebps-package+xml"/>
+</rootfiles>
+</container>
+"#;
+
+struct Chapter {
+    href: String,
+    /// Escaped title
+    title: String,
+    /// Does chapter reference remote images
+    remote: bool,
+    document: String,
+}
+
+struct BundledImage {
+    href: String,
+    media_type: String,
+    bytes: Vec<u8>,
+}
+
+fn bundle(images: &mut Vec<BundledImage>, image: ImageBytes) -> Option<String> {
+    let content_type = image.content_type().clone()?;
+    if content_type.type_() != mime::IMAGE {
+        return None;
+    }
+    let extension = match content_type.subtype().as_str() {
+        "jpeg" => "jpg",
+        other => other,
+    };
+    let href = format!("images/image-{}.{extension}", images.len() + 1);
+    images.push(BundledImage {
+        href: href.clone(),
+        media_type: content_type.essence_str().to_owned(),
+        bytes: image.into_bytes(),
+    });
+    Some(href)
+}
+
+/// Closes void elements produced by [`ContentBlock::to_html`], making the markup valid XHTML
+fn xhtml(html: &str) -> String {
+    let mut res = String::with_capacity(html.len());
+    let mut rest = html;
+    // attribute values are escaped, so the first '>' closes the tag
+    while let Some(start) = rest.find("<img ") {
+        let end = start + rest[start..].find('>').expect("Image tag should be closed");
+        res.push_str(&rest[..end]);
+        res.push_str("/>");
+        rest = &rest[end + 1..];
+    }
+    res.push_str(rest);
+    res.replace("<br>", "<br/>").replace("<hr>", "<hr/>")
+}
+
+fn package(
+    articles: &[FullArticle],
+    meta: &BookMeta,
+    chapters: &[Chapter],
+    images: &[BundledImage],
+) -> String {
+    let identifier = meta.identifier.clone().unwrap_or_else(|| {
+        let ids: Vec<_> = articles
+            .iter()
+            .map(|article| article.id().to_string())
+            .collect();
+        format!("urn:drukarnia:{}", ids.join("-"))
+    });
+    let mut authors = meta.authors.clone();
+    if authors.is_empty() {
+        for article in articles {
+            let name = article.owner().name().as_ref().to_owned();
+            if !authors.contains(&name) {
+                authors.push(name);
+            }
+        }
+    }
+    let modified = meta
+        .modified
+        .or_else(|| articles.iter().map(|article| *article.created_at()).max())
+        .expect("There should be at least one article");
+    // creation time might be out of RFC 3339 range, but the element is required
+    let modified = modified_date(modified)
+        .or_else(|| modified_date(OffsetDateTime::now_utc()))
+        .expect("Current time should be representable");
+
+    let mut metadata = vec![
+        format!(
+            "<dc:identifier id=\"book-id\">{}</dc:identifier>",
+            escape_html(&identifier)
+        ),
+        format!("<dc:title>{}</dc:title>", escape_html(&meta.title)),
+        format!("<dc:language>{}</dc:language>", escape_html(&meta.language)),
+    ];
+    metadata.extend(
+        authors
+            .iter()
+            .map(|author| format!("<dc:creator>{}</dc:creator>", escape_html(author))),
+    );
+    metadata.push(format!(
+        "<meta property=\"dcterms:modified\">{modified}</meta>"
+    ));
+
+    let mut manifest = vec![
+        "<item id=\"nav\" href=\"nav.xhtml\" media-type=\"application/xhtml+xml\" properties=\"nav\"/>"
+            .to_owned(),
+    ];
+    manifest.extend(chapters.iter().enumerate().map(|(index, chapter)| {
+        let properties = if chapter.remote {
+            " properties=\"remote-resources\""
+        } else {
+            ""
+        };
+        format!(
+            "<item id=\"chapter-{}\" href=\"{}\" media-type=\"application/xhtml+xml\"{properties}/>",
+            index + 1,
+            chapter.href
+        )
+    }));
+    manifest.extend(images.iter().enumerate().map(|(index, image)| {
+        format!(
+            "<item id=\"image-{}\" href=\"{}\" media-type=\"{}\"/>",
+            index + 1,
+            image.href,
+            escape_html(&image.media_type)
+        )
+    }));
+    let spine: Vec<_> = (1..=chapters.len())
+        .map(|index| format!("<itemref idref=\"chapter-{index}\"/>"))
+        .collect();
+
+    format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<package xmlns=\"http://www.idpf.org/2007/opf\" version=\"3.0\" unique-identifier=\"book-id\">\n<metadata xmlns:dc=\"http://purl.org/dc/elements/1.1/\">\n{}\n</metadata>\n<manifest>\n{}\n</manifest>\n<spine>\n{}\n</spine>\n</package>\n",
+        metadata.join("\n"),
+        manifest.join("\n"),
+        spine.join("\n")
+    )
+}
+
+/// Formats time as `dcterms:modified` requires: in UTC, without fractional seconds
+///
+/// `None`, if time can not be represented so.
+fn modified_date(time: OffsetDateTime) -> Option<String> {
+    time.checked_to_offset(UtcOffset::UTC)?
+        .replace_nanosecond(0)
+        .ok()?
+        .format(&Rfc3339)
+        .ok()
+}
+
+fn nav(meta: &BookMeta, chapters: &[Chapter]) -> String {
+    let language = escape_html(&meta.language);
+    let title = escape_html(&meta.title);
+    let items: Vec<_> = chapters
+        .iter()
+        .map(|chapter| {
+            format!(
+                "<li><a href=\"{}\">{}</a></li>",
+                chapter.href, chapter.title
+            )
+        })
+        .collect();
+    format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<!DOCTYPE html>\n<html xmlns=\"http://www.w3.org/1999/xhtml\" xmlns:epub=\"http://www.idpf.org/2007/ops\" lang=\"{language}\" xml:lang=\"{language}\">\n<head>\n<meta charset=\"utf-8\"/>\n<title>{title}</title>\n</head>\n<body>\n<nav epub:type=\"toc\" id=\"toc\">\n<h1>{title}</h1>\n<ol>\n{}\n</ol>\n</nav>\n</body>\n</html>\n",
+        items.join("\n")
+    )
+}
+
+/// Minimal ZIP writer. Entries are stored uncompressed, which is enough for EPUB
+#[derive(Default)]
+struct Zip {
+    out: Vec<u8>,
+    central: Vec<u8>,
+    entries: u16,
+}
+
+impl Zip {
+    /// Version 2.0, the minimal one
+    const VERSION: u16 = 20;
+    /// Names are UTF-8
+    const FLAGS: u16 = 1 << 11;
+    /// 1980-01-01, the earliest date DOS time can represent. Makes the output reproducible
+    const DATE: u16 = (1 << 5) | 1;
+
+    fn add(&mut self, name: &str, data: &[u8]) -> Result<(), EpubError> {
+        let too_large = || EpubError::EntryTooLarge(name.to_owned());
+        let size = u32::try_from(data.len()).map_err(|_| too_large())?;
+        let offset = u32::try_from(self.out.len()).map_err(|_| too_large())?;
+        let name_len = u16::try_from(name.len()).map_err(|_| too_large())?;
+        self.entries = self.entries.checked_add(1).ok_or_else(too_large)?;
+
+        // fields shared by local and central headers, from the "version needed" to the extra field length
+        let mut common = vec![];
+        for field in [Self::VERSION, Self::FLAGS, 0, 0, Self::DATE] {
+            common.extend(field.to_le_bytes());
+        }
+        for field in [crc32(data), size, size] {
+            common.extend(field.to_le_bytes());
+        }
+        for field in [name_len, 0] {
+            common.extend(field.to_le_bytes());
+        }
+
+        self.out.extend(0x0403_4b50_u32.to_le_bytes());
+        self.out.extend(&common);
+        self.out.extend(name.as_bytes());
+        self.out.extend(data);
+
+        self.central.extend(0x0201_4b50_u32.to_le_bytes());
+        self.central.extend(Self::VERSION.to_le_bytes());
+        self.central.extend(&common);
+        // comment length, disk number, internal attributes
+        for field in [0_u16, 0, 0] {
+            self.central.extend(field.to_le_bytes());
+        }
+        // external attributes, local header offset
+        for field in [0, offset] {
+            self.central.extend(field.to_le_bytes());
+        }
+        self.central.extend(name.as_bytes());
+        Ok(())
+    }
+
+    fn finish(mut self) -> Result<Vec<u8>, EpubError> {
+        let too_large = || EpubError::EntryTooLarge("central directory".to_owned());
+        let central_offset = u32::try_from(self.out.len()).map_err(|_| too_large())?;
+        let central_size = u32::try_from(self.central.len()).map_err(|_| too_large())?;
+        self.out.append(&mut self.central);
+        self.out.extend(0x0605_4b50_u32.to_le_bytes());
+        // disk numbers, entries on this disk and total
+        for field in [0, 0, self.entries, self.entries] {
+            self.out.extend(field.to_le_bytes());
+        }
+        for field in [central_size, central_offset] {
+            self.out.extend(field.to_le_bytes());
+        }
+        // comment length
+        self.out.extend(0_u16.to_le_bytes());
+        Ok(self.out)
+    }
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0_u32;
+    for &byte in data {
+        crc ^= u32::from(byte);
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
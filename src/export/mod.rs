@@ -0,0 +1,128 @@
+/// EPUB books, built out of article collections
+#[cfg(feature = "epub")]
+pub mod epub;
+
+/// RSS feeds of authors and tags, which the site does not provide itself
+#[cfg(feature = "rss")]
+pub mod rss;
+
+use base64::Engine;
+use derive_getters::Getters;
+use time::format_description::well_known::Rfc3339;
+
+use crate::{
+    client::ImageBytes,
+    object::{escape_html, FullArticle, MaybeUrl},
+};
+
+/// Parameters of the [`FullArticle::to_html`] export
+#[derive(Debug, Clone, Getters)]
+pub struct HtmlExportOptions {
+    /// Cover image to embed into the document
+    cover: Option<ImageBytes>,
+    /// Should the cover be linked from the site, if it was not provided
+    link_cover: bool,
+}
+
+impl Default for HtmlExportOptions {
+    fn default() -> Self {
+        Self {
+            cover: None,
+            link_cover: true,
+        }
+    }
+}
+
+impl HtmlExportOptions {
+    /// Embeds the cover image as a data URI, making the document fully standalone
+    ///
+    /// Cover is usually obtained with [`FullArticle::cover`].
+    #[must_use]
+    pub fn with_cover(mut self, cover: ImageBytes) -> Self {
+        self.cover = Some(cover);
+        self
+    }
+
+    /// Sets, if the cover should be linked from the site, when it was not provided with [`HtmlExportOptions::with_cover`]
+    #[must_use]
+    pub fn with_link_cover(mut self, link_cover: bool) -> Self {
+        self.link_cover = link_cover;
+        self
+    }
+}
+
+impl FullArticle {
+    /// Renders the article as a standalone HTML document.
+    ///
+    /// Document's header contains article's title, author, creation date and main tag, followed by the cover
+    /// and the [content](crate::object::ArticleContent::to_html). All of the text is escaped.
+    pub fn to_html(&self, options: HtmlExportOptions) -> String {
+        let title = escape_html(self.title().as_ref());
+        let author = escape_html(self.owner().name().as_ref());
+
+        let mut head = vec![
+            "<meta charset=\"utf-8\">".to_owned(),
+            format!("<title>{title}</title>"),
+            format!("<meta name=\"author\" content=\"{author}\">"),
+        ];
+        let description = self.description().as_ref().trim();
+        if !description.is_empty() {
+            head.push(format!(
+                "<meta name=\"description\" content=\"{}\">",
+                escape_html(description)
+            ));
+        }
+        if let Some(canonical) = self.canonical_url() {
+            head.push(format!(
+                "<link rel=\"canonical\" href=\"{}\">",
+                escape_html(canonical.as_str())
+            ));
+        }
+
+        let mut header = article_header(self);
+        let cover = match (options.cover, self.picture()) {
+            (Some(cover), _) => {
+                let content_type = cover
+                    .content_type()
+                    .as_ref()
+                    .map_or("application/octet-stream", |mime| mime.essence_str());
+                let data = base64::engine::general_purpose::STANDARD.encode(cover.bytes());
+                Some(format!("data:{content_type};base64,{data}"))
+            }
+            (None, Some(MaybeUrl::Url(url))) if options.link_cover => Some(url.to_string()),
+            _ => None,
+        };
+        if let Some(cover) = cover {
+            header.push(format!(
+                "<img class=\"cover\" src=\"{}\" alt=\"\">",
+                escape_html(&cover)
+            ));
+        }
+
+        format!(
+            "<!DOCTYPE html>\n<html lang=\"uk\">\n<head>\n{}\n</head>\n<body>\n<article>\n<header>\n{}\n</header>\n{}</article>\n</body>\n</html>\n",
+            head.join("\n"),
+            header.join("\n"),
+            self.content().to_html()
+        )
+    }
+}
+
+/// Title, followed by author, creation date and main tag
+fn article_header(article: &FullArticle) -> Vec<String> {
+    let created_at = article.created_at();
+    // attribute is optional, so it's left out for the times RFC 3339 can't represent
+    let datetime = created_at
+        .format(&Rfc3339)
+        .map(|datetime| format!(" datetime=\"{datetime}\""))
+        .unwrap_or_default();
+    vec![
+        format!("<h1>{}</h1>", escape_html(article.title().as_ref())),
+        format!(
+            "<p><span class=\"author\">{}</span> · <time{datetime}>{}</time> · <span class=\"tag\">{}</span></p>",
+            escape_html(article.owner().name().as_ref()),
+            created_at.date(),
+            escape_html(article.main_tag().as_ref()),
+        ),
+    ]
+}
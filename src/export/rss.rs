@@ -0,0 +1,102 @@
+use time::{format_description::well_known::Rfc2822, OffsetDateTime};
+use url::Url;
+
+use crate::object::{escape_html, ArticleSlug, AuthorArticle, FullTag, FullUser, TagArticle};
+
+/// Builds an RSS 2.0 feed of the author's articles, newest first.
+///
+/// Links point to the site at `base`, like `https://drukarnia.com.ua/`.
+pub fn author_feed(user: &FullUser, articles: &[AuthorArticle], base: &Url) -> String {
+    let name = user.name().as_ref();
+    let description = user
+        .short_description()
+        .as_ref()
+        .map(|description| description.as_ref().trim())
+        .filter(|description| !description.is_empty())
+        .unwrap_or(name);
+    let items = articles
+        .iter()
+        .map(|article| Item {
+            title: article.title().as_ref(),
+            slug: article.slug(),
+            description: article.description().as_ref(),
+            created_at: *article.created_at(),
+        })
+        .collect();
+    channel(
+        name,
+        &join(base, &format!("@{}", user.username().as_ref())),
+        description,
+        base,
+        items,
+    )
+}
+
+/// Builds an RSS 2.0 feed of the articles with a tag, newest first.
+///
+/// Links point to the site at `base`, like `https://drukarnia.com.ua/`.
+pub fn tag_feed(tag: &FullTag, articles: &[TagArticle], base: &Url) -> String {
+    let name = tag.name().as_ref();
+    let items = articles
+        .iter()
+        .map(|article| Item {
+            title: article.title().as_ref(),
+            slug: article.slug(),
+            description: article.description().as_ref(),
+            created_at: *article.created_at(),
+        })
+        .collect();
+    channel(
+        name,
+        &join(base, &format!("tags/{}", tag.slug().as_ref())),
+        name,
+        base,
+        items,
+    )
+}
+
+struct Item<'a> {
+    title: &'a str,
+    slug: &'a ArticleSlug,
+    description: &'a str,
+    created_at: OffsetDateTime,
+}
+
+fn join(base: &Url, path: &str) -> String {
+    base.join(path)
+        .map_or_else(|_| format!("{base}{path}"), String::from)
+}
+
+/// Formats time as RFC 2822 date, if it's representable (years before 1900 are not)
+fn date(time: OffsetDateTime) -> Option<String> {
+    time.format(&Rfc2822).ok()
+}
+
+fn channel(title: &str, link: &str, description: &str, base: &Url, mut items: Vec<Item>) -> String {
+    items.sort_by_key(|item| std::cmp::Reverse(item.created_at));
+    let mut channel = vec![
+        format!("<title>{}</title>", escape_html(title)),
+        format!("<link>{}</link>", escape_html(link)),
+        format!("<description>{}</description>", escape_html(description)),
+        "<language>uk</language>".to_owned(),
+    ];
+    // dates are optional in RSS, so unrepresentable ones are left out
+    if let Some(newest) = items.iter().find_map(|item| date(item.created_at)) {
+        channel.push(format!("<lastBuildDate>{newest}</lastBuildDate>"));
+    }
+    for item in &items {
+        let link = escape_html(&join(base, &format!("articles/{}", item.slug.as_ref())));
+        let pub_date = date(item.created_at)
+            .map(|date| format!("\n<pubDate>{date}</pubDate>"))
+            .unwrap_or_default();
+        channel.push(format!(
+            "<item>\n<title>{}</title>\n<link>{link}</link>\n<guid isPermaLink=\"true\">{link}</guid>{pub_date}\n<description>{}</description>\n</item>",
+            escape_html(item.title),
+            escape_html(item.description),
+        ));
+    }
+    format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<rss version=\"2.0\">\n<channel>\n{}\n</channel>\n</rss>\n",
+        channel.join("\n")
+    )
+}
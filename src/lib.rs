@@ -1,7 +1,7 @@
 //! # Brief
 //! `type_matrux` allows you to search and query data on [`Drukarnia`](https://drukarnia.com.ua/) website.
-//! Although it's not currently implemented, there's a plan to make it also possible to authorize on a website,
-//! and perform any operation one would do with on the actual website (post articles and comments, and interact with other users).
+//! It's also possible to authorize on a website, and perform operations one would do on the actual website
+//! (post comments, like and bookmark articles, follow users), although logging in is still unstable.
 //!
 //! I aimed for the most accurate fetched data representation,
 //! so that there would be no surprizes, like missing/redundant fields, or unexpected field format. To test for that, there are "reinforcement tests" in the test suite -
@@ -11,9 +11,9 @@
 //! I'm **NOT** affiliated with Drukarnia's developes in any way. In fact, they are **not happy** about people creating their own
 //! API adapters and do not provide any sort of API documentation. That said, please consider two things:
 //! - The crate can be easily abused to perform illegal actions and I'm not responsible for anything you do.
-//! Please, respect Drukarnia's policies, as well as privacy of other users.
+//!   Please, respect Drukarnia's policies, as well as privacy of other users.
 //! - I don't know full Drukarnia's API capabilities - this crate only allows you to send requests explicitly used by their front-end
-//! (since these are the only ones I could capture).
+//!   (since these are the only ones I could capture).
 //!
 //! # Details
 //! You might be overwhelmed by the number of data structures defined. This is caused by Drukarnia returning just a little different fields list for each type of the request.
@@ -22,7 +22,7 @@
 //! Also, this crate features simple newtypes for almost any field. While this might be annoying at first, It saved me a couple of times from passing wrong id types for a request.
 //!
 //! Right now, authorized operations are implemented, but not yet tested and proved to work correctly.
-//! To prevent crate users utilizing it, [`DrukarniaApi::login`] implementation panics now. Hope to change that soon.
+//! [`BaseApi::login`](client::BaseApi::login) is considered unstable, since the site intermittently rejects logins.
 //! Also, be careful to not make Drukarnia suspicious
 //! (while testing, I got to the point of Drukarnia denying authorization, claiming that I've done it too many times already).
 //!
@@ -34,7 +34,7 @@
 //! of requests you may send and data received from them.
 //!
 //! ## Feed fetching
-//! Lets say, we want to get some articles from a Feed. To do that, you may use [`DrukarniaApi::feed_page`] or [`DrukarniaApi::feed`].
+//! Lets say, we want to get some articles from a Feed. To do that, you may use [`FeedApi::feed_page`](client::FeedApi::feed_page) or [`FeedApi::feed`](client::FeedApi::feed).
 //! As you might guess, the first one returns you a single feed page:
 //! ```rust
 //! # use reqwest::Client;
@@ -79,7 +79,7 @@
 //! ## Averages
 //! Let's say we want to find an average number of likes, max number of comments and average number of author reads for all
 //! articles searched as having "Дія" in their title.
-//! To do that, we can use [`DrukarniaApi::search_article_page`]/[`DrukarniaApi::search_article`] (having same sort of behavior, as discussed above with feed):
+//! To do that, we can use [`ArticlesApi::search_article_page`](client::ArticlesApi::search_article_page)/[`ArticlesApi::search_article`](client::ArticlesApi::search_article) (having same sort of behavior, as discussed above with feed):
 //! ```rust
 //! # use futures::StreamExt;
 //! # use reqwest::Client;
@@ -119,12 +119,12 @@
 //! In this case, I'd like to be notified on the issue tracker (if not stated otherwise).
 //!
 //! For now, developed feature list is as follows:
-//! - [ ] Add article content typing (it's returned in a sort of weird form, that feels like JSON-serialized HTML).
+//! - [x] Add article content typing (it's returned in a sort of weird form, that feels like JSON-serialized HTML).
 //! - [ ] Add validation to String newtypes. This requires some real effort,
-//! as there's little known about limitations on things like Descriptions and DisplayNames
+//!   as there's little known about limitations on things like Descriptions and DisplayNames
 //! - [ ] Add procedural macro for compile-time verification of object ids, slugs, etc.
-//! This will help user to know that id/slug does not exist at compile-time!
-//! - [ ] Implement auth operations.
+//!   This will help user to know that id/slug does not exist at compile-time!
+//! - [x] Implement auth operations.
 //! - [ ] Add API implementations for other popular HTTP clients like `isahc` and `surf`.
 #![warn(
 //    missing_docs,
@@ -145,8 +145,27 @@ pub mod client;
 /// Contains data objects as well as some infrastructure for their definition and usage.
 ///
 /// All of the provided objects are purposely immutable - this way you can guarantee that actual data stored inside
-/// them was indeed returned from a site. For validation purposes, they all have [`get_age`] function, returning
-/// [`time::Duration`] representing time passed since their creation.
+/// them was indeed returned from a site. For validation purposes, they all implement [`Aged`](object::Aged) trait,
+/// with [`get_age`](object::Aged::get_age) function, returning [`time::Duration`] representing time passed since their creation.
 pub mod object;
 
-pub use client::{DrukarniaApi, ReqwestApi};
+/// Aggregate analyses over fetched objects, like tag co-occurrence graph
+pub mod analysis;
+
+/// Re-exports of the most commonly used items, so that `use type_matrux::prelude::*;` is enough to get started
+pub mod prelude;
+
+/// Synchronous API accessor, for the code that is not async
+#[cfg(feature = "blocking")]
+pub mod blocking;
+
+/// Exporting fetched objects into standalone documents, for archiving
+#[cfg(feature = "export")]
+pub mod export;
+
+/// Typed runtime configuration, loaded from a file and environment
+#[cfg(feature = "config")]
+pub mod config;
+
+pub use client::{DrukarniaApi, DrukarniaClient, ReqwestApi};
+pub use derives::id_hex;
@@ -1,4 +1,4 @@
-use std::{convert::Infallible, str::FromStr};
+use std::{convert::Infallible, num::NonZeroUsize, str::FromStr};
 
 use derives::data_type;
 
@@ -39,6 +39,35 @@ impl FromStr for Description {
 
 super::str_type! {Slug, "slug", "article"}
 
+impl Slug {
+    /// Extracts article's slug from it's url, like `https://drukarnia.com.ua/articles/{slug}`
+    ///
+    /// # Errors
+    /// [`SlugFromUrlError`](super::SlugFromUrlError) if url does not point to an article at [`DEFAULT_HOST`](super::DEFAULT_HOST)
+    pub fn from_url(url: &url::Url) -> Result<Self, super::SlugFromUrlError> {
+        Self::from_url_at(url, super::DEFAULT_HOST)
+    }
+
+    /// Same as [`Slug::from_url`], but expects url at specified host
+    ///
+    /// # Errors
+    /// [`SlugFromUrlError`](super::SlugFromUrlError) if url does not point to an article at the host
+    pub fn from_url_at(url: &url::Url, host: &str) -> Result<Self, super::SlugFromUrlError> {
+        let segments = super::url_path_segments(url, host)?;
+        match segments
+            .iter()
+            .map(String::as_str)
+            .collect::<Vec<_>>()
+            .as_slice()
+        {
+            ["articles", slug] => Ok(Self((*slug).to_owned())),
+            _ => Err(super::SlugFromUrlError::UnexpectedPath(
+                url.path().to_owned(),
+            )),
+        }
+    }
+}
+
 impl FromStr for Slug {
     type Err = Infallible;
 
@@ -48,7 +77,58 @@ impl FromStr for Slug {
     }
 }
 
+/// Number of likes authorized user gives to an article, at most [`LikeAmount::MAX`]
+///
+/// Site lets each user "clap" for an article several times, up to a limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, serde::Serialize)]
+#[serde(transparent)]
+pub struct LikeAmount(u8);
+
+impl LikeAmount {
+    /// No likes, i.e. like removed
+    pub const ZERO: Self = Self(0);
+
+    /// A single like
+    pub const ONE: Self = Self(1);
+
+    /// Maximum number of likes site accepts from a user
+    pub const MAX: Self = Self(10);
+
+    /// Creates an amount, clamping it to [`LikeAmount::MAX`]
+    pub const fn saturating(amount: u8) -> Self {
+        if amount > Self::MAX.0 {
+            Self::MAX
+        } else {
+            Self(amount)
+        }
+    }
+
+    /// Number of likes
+    pub const fn get(self) -> u8 {
+        self.0
+    }
+}
+
+impl TryFrom<u8> for LikeAmount {
+    type Error = super::LikeAmountError;
+
+    fn try_from(amount: u8) -> Result<Self, Self::Error> {
+        if amount > Self::MAX.0 {
+            Err(super::LikeAmountError(amount))
+        } else {
+            Ok(Self(amount))
+        }
+    }
+}
+
+impl From<LikeAmount> for u8 {
+    fn from(amount: LikeAmount) -> Self {
+        amount.0
+    }
+}
+
 data_type!(
+    "An article, as listed among full article's author articles",
     Search,
     id,
     title,
@@ -64,10 +144,12 @@ data_type!(
     main_tag_slug,
     created_at,
     is_bookmarked,
-    unused_pin_created_at,
+    unused_pin_created_at;
+    from Author
 );
 
 data_type!(
+    "An article, as listed on it's author's page",
     Author,
     id,
     title,
@@ -91,8 +173,9 @@ data_type!(
 );
 
 data_type! {
+    "An article, as returned by search or recommended after another one",
     Recommended,
-    id,
+    opt_id,
     title,
     description,
     slug,
@@ -108,10 +191,12 @@ data_type! {
     created_at,
     thumb_picture,
     owner_article,
-    is_bookmarked,
+    is_bookmarked;
+    from Full, Tag
 }
 
 data_type! {
+    "An article, in it's most common short form",
     Short,
     id,
     title,
@@ -128,10 +213,12 @@ data_type! {
     comment_num,
     read_time,
     created_at,
-    is_bookmarked,
+    is_bookmarked;
+    from Full, Author, Tag, Feed
 }
 
 data_type! {
+    "An article, as listed on a tag's page",
     Tag,
     id,
     title,
@@ -150,10 +237,12 @@ data_type! {
     owner_article,
     is_bookmarked,
     created_at,
-    relationships
+    relationships_opt;
+    from Full
 }
 
 data_type! {
+    "An article, as stored in a bookmark list",
     List,
     id,
     title,
@@ -164,10 +253,13 @@ data_type! {
     main_tag_id,
     read_time,
     created_at,
-    is_bookmarked,
+    is_bookmarked;
+    from Full, Short, Search, Author, Tag, Feed
 }
 
 data_type! {
+    "An article, as shown in authorized user's feed",
+    #[lenient]
     Feed,
     id,
     title,
@@ -188,6 +280,7 @@ data_type! {
 }
 
 data_type! {
+    "An article, as returned by it's own endpoint",
     Full,
     id,
     title,
@@ -217,3 +310,188 @@ data_type! {
     comments,
     content,
 }
+
+impl Full {
+    /// Short preview of the article, at most `max_chars` characters long
+    ///
+    /// Uses article's description, if it is not empty, and it's plain text content otherwise.
+    /// See [`excerpt`](super::text::excerpt) for details.
+    pub fn excerpt(&self, max_chars: usize) -> String {
+        let description = self.description.0.trim();
+        if description.is_empty() {
+            super::text::excerpt(&self.content.plain_text(), max_chars)
+        } else {
+            super::text::excerpt(description, max_chars)
+        }
+    }
+
+    /// Downloads article's cover image, preferring full picture over the thumbnail
+    ///
+    /// # Returns
+    /// `None`, if article has no picture at all
+    ///
+    /// # Errors
+    /// Same as [`ArticlesApi::download_image`](crate::client::ArticlesApi::download_image)
+    pub async fn cover(
+        &self,
+        api: &(impl crate::DrukarniaApi + ?Sized),
+    ) -> Result<Option<crate::client::ImageBytes>, crate::client::Error> {
+        match self.picture.as_ref().or(self.thumb_picture.as_ref()) {
+            Some(url) => api.download_image(url).await.map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Links leading outside of the site, in order of appearance and without repetitions
+    ///
+    /// These are content's [links](super::ArticleContent::links) to other hosts, followed by article's canonical url
+    /// and author's donation link. Invalid urls are kept, as there's no telling where they lead.
+    pub fn external_links(&self) -> Vec<super::MaybeUrl> {
+        let is_external = |url: &super::MaybeUrl| {
+            url.as_url()
+                .is_none_or(|url| url.host_str() != Some(super::DEFAULT_HOST))
+        };
+        let mut links: Vec<super::MaybeUrl> = vec![];
+        let found = self.content.links().into_iter().filter(is_external);
+        for url in found
+            .chain(self.canonical.clone())
+            .chain(self.owner.donate_url().clone())
+        {
+            if !links.contains(&url) {
+                links.push(url);
+            }
+        }
+        links
+    }
+
+    /// Number of comments displayed on the site, recomputed from [`Full::comments`]
+    ///
+    /// Can be compared against [`Full::comment_num`], calculated by the site.
+    pub fn visible_comment_count(&self) -> usize {
+        use super::CommentListExt;
+        self.comments.visible().count()
+    }
+
+    /// Average reading speed, used by [`Full::estimated_read_time`] callers that have no better estimate
+    pub const DEFAULT_WORDS_PER_MINUTE: NonZeroUsize = match NonZeroUsize::new(200) {
+        Some(wpm) => wpm,
+        None => unreachable!(),
+    };
+
+    /// Estimates article's read time from it's word count.
+    ///
+    /// Can be compared against [`Full::read_time`], calculated by the site.
+    pub fn estimated_read_time(&self, words_per_minute: NonZeroUsize) -> ::time::Duration {
+        let words = self.content.word_count();
+        #[allow(clippy::cast_precision_loss)]
+        ::time::Duration::seconds_f64(words as f64 * 60.0 / words_per_minute.get() as f64)
+    }
+}
+
+/// Borrowed summary of an article, available for any of it's representations
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Summary<'a> {
+    id: Option<&'a Id>,
+    title: &'a Title,
+    slug: &'a Slug,
+    description: &'a Description,
+    main_tag: &'a super::TagName,
+    read_time: ::time::Duration,
+    created_at: ::time::OffsetDateTime,
+    like_num: Option<usize>,
+    comment_num: Option<usize>,
+}
+
+impl<'a> Summary<'a> {
+    /// Article's id
+    ///
+    /// Some representations (namely, [`Recommended`]) might lack it, use [`Summary::slug`] to identify these.
+    pub fn id(&self) -> Option<&'a Id> {
+        self.id
+    }
+
+    /// Article's title
+    pub fn title(&self) -> &'a Title {
+        self.title
+    }
+
+    /// Article's slug
+    pub fn slug(&self) -> &'a Slug {
+        self.slug
+    }
+
+    /// Article's description
+    pub fn description(&self) -> &'a Description {
+        self.description
+    }
+
+    /// Name of the article's main tag
+    pub fn main_tag(&self) -> &'a super::TagName {
+        self.main_tag
+    }
+
+    /// Read time, as calculated by the site
+    pub fn read_time(&self) -> ::time::Duration {
+        self.read_time
+    }
+
+    /// Article's creation time
+    pub fn created_at(&self) -> ::time::OffsetDateTime {
+        self.created_at
+    }
+
+    /// Number of likes, if the representation has it
+    pub fn like_num(&self) -> Option<usize> {
+        self.like_num
+    }
+
+    /// Number of comments, if the representation has it
+    pub fn comment_num(&self) -> Option<usize> {
+        self.comment_num
+    }
+}
+
+/// Any article representation, that can be viewed as a [`Summary`]
+pub trait AsSummary {
+    /// Borrows article's summary
+    fn as_summary(&self) -> Summary<'_>;
+}
+
+macro_rules! as_summary {
+    {$type:ident, counts} => {
+        as_summary! {$type, |this| Some(&this.id), Some(this.like_num), Some(this.comment_num)}
+    };
+    {$type:ident, counts, opt_id} => {
+        as_summary! {$type, |this| this.id.as_ref(), Some(this.like_num), Some(this.comment_num)}
+    };
+    {$type:ident} => {
+        as_summary! {$type, |this| Some(&this.id), None, None}
+    };
+    {$type:ident, |$this:ident| $id:expr, $like_num:expr, $comment_num:expr} => {
+        impl AsSummary for $type {
+            fn as_summary(&self) -> Summary<'_> {
+                let $this = self;
+                Summary {
+                    id: $id,
+                    title: &self.title,
+                    slug: &self.slug,
+                    description: &self.description,
+                    main_tag: &self.main_tag,
+                    read_time: self.read_time,
+                    created_at: self.created_at,
+                    like_num: $like_num,
+                    comment_num: $comment_num,
+                }
+            }
+        }
+    };
+}
+
+as_summary! {Full, counts}
+as_summary! {Short, counts}
+as_summary! {Search}
+as_summary! {Feed, counts}
+as_summary! {Recommended, counts, opt_id}
+as_summary! {Author, counts}
+as_summary! {Tag, counts}
+as_summary! {List}
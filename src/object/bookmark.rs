@@ -3,6 +3,7 @@ use derives::data_type;
 super::id_type! {"bookmark"}
 
 data_type! {
+    "A bookmark of an article",
     Full,
     id,
     article_id,
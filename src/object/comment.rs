@@ -1,8 +1,162 @@
+use std::{collections::HashMap, ops::Deref, sync::OnceLock};
+
+use derive_getters::Getters;
 use derives::data_type;
+use html_parser::{Dom, Node};
+
+use super::{html, UserName};
 
 super::id_type! {"article comment"}
 
+/// An error that might occur while parsing comment's HTML
+#[derive(Debug, thiserror::Error, Clone, PartialEq, Eq)]
+#[error("Comment is not valid HTML: {0}")]
+pub struct HtmlParseError(String);
+
+/// Comment's HTML, parsed on first access
+///
+/// Parsing is the most expensive part of fetching comments, so it's skipped for the comments that are only counted.
+///
+/// Dereferences to the parsed [`Dom`]. Invalid HTML is treated as plain text there, while [`LazyDom::dom`]
+/// reports the error.
+#[derive(Clone)]
+pub struct LazyDom {
+    source: String,
+    parsed: OnceLock<(Dom, Option<HtmlParseError>)>,
+}
+
+impl LazyDom {
+    /// Wraps HTML source, without parsing it yet
+    pub fn new(source: impl Into<String>) -> Self {
+        Self {
+            source: source.into(),
+            parsed: OnceLock::new(),
+        }
+    }
+
+    /// HTML, exactly as it was returned by the site
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Whether HTML was already parsed
+    pub fn is_parsed(&self) -> bool {
+        self.parsed.get().is_some()
+    }
+
+    /// Parsed HTML
+    ///
+    /// # Errors
+    /// [`HtmlParseError`], if source is not valid HTML
+    pub fn dom(&self) -> Result<&Dom, HtmlParseError> {
+        match self.parse() {
+            (dom, None) => Ok(dom),
+            (_, Some(err)) => Err(err.clone()),
+        }
+    }
+
+    fn parse(&self) -> &(Dom, Option<HtmlParseError>) {
+        self.parsed.get_or_init(|| match Dom::parse(&self.source) {
+            Ok(dom) => (dom, None),
+            Err(err) => {
+                let text = Dom {
+                    children: vec![Node::Text(self.source.clone())],
+                    ..Dom::default()
+                };
+                (text, Some(HtmlParseError(err.to_string())))
+            }
+        })
+    }
+}
+
+impl Deref for LazyDom {
+    type Target = Dom;
+
+    fn deref(&self) -> &Self::Target {
+        &self.parse().0
+    }
+}
+
+impl std::fmt::Debug for LazyDom {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("LazyDom").field(&self.source).finish()
+    }
+}
+
+/// Same source means same HTML, whether it was parsed or not
+impl PartialEq for LazyDom {
+    fn eq(&self, other: &Self) -> bool {
+        self.source == other.source
+    }
+}
+
+impl Eq for LazyDom {}
+
+impl serde::Serialize for LazyDom {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.source)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for LazyDom {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        /// Takes over owned strings, and copies borrowed ones exactly once
+        struct SourceVisitor;
+
+        impl serde::de::Visitor<'_> for SourceVisitor {
+            type Value = LazyDom;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("an HTML fragment")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                Ok(LazyDom::new(v))
+            }
+
+            fn visit_string<E: serde::de::Error>(self, v: String) -> Result<Self::Value, E> {
+                Ok(LazyDom::new(v))
+            }
+        }
+
+        deserializer.deserialize_str(SourceVisitor)
+    }
+}
+
+/// Comment's likes, as returned after authorized user had liked or unliked it
+///
+/// See [`AuthDrukarnia::set_comment_liked`](crate::client::AuthDrukarnia::set_comment_liked).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Getters, serde::Deserialize, serde::Serialize)]
+pub struct LikeState {
+    /// Number of likes, authorized user's one included
+    #[serde(rename = "likesNum")]
+    likes_num: usize,
+    /// Whether authorized user likes the comment now
+    #[serde(
+        rename = "isLiked",
+        deserialize_with = "super::serde_utils::lenient_flag"
+    )]
+    is_liked: bool,
+}
+
+impl LikeState {
+    /// Creates a like state, for the code that has no response to parse it from
+    pub fn new(likes_num: usize, is_liked: bool) -> Self {
+        Self {
+            likes_num,
+            is_liked,
+        }
+    }
+}
+
+/// There's no object with it's own raw representation in the response
+#[cfg(feature = "raw-json")]
+impl super::AttachRaw for LikeState {
+    fn attach_raw(&mut self, _raw: &str) {}
+}
+
 data_type! {
+    "A top-level comment under an article",
     Article,
     id,
     comment_dom,
@@ -12,12 +166,13 @@ data_type! {
     reply_num,
     likes_num,
     created_at,
-    is_liked_bool,
+    is_liked,
     is_blocked,
     unused___v,
 }
 
 data_type! {
+    "A reply to an article's comment",
     Reply,
     id,
     comment_dom,
@@ -27,7 +182,7 @@ data_type! {
     reply_num,
     likes_num,
     created_at,
-    is_liked_bool,
+    is_liked,
     is_blocked,
     reply_to_comment,
     reply_to_user,
@@ -35,3 +190,156 @@ data_type! {
     root_comment_owner,
     unused___v,
 }
+
+impl Article {
+    /// Whether this comment is displayed on the site, i.e. it is neither hidden by article's author, nor blocked
+    pub fn is_visible(&self) -> bool {
+        !self.hidden_by_author && !self.is_blocked
+    }
+
+    /// Comment's text, as it would be displayed on the site
+    ///
+    /// Formatting is dropped, paragraphs are placed on separate lines.
+    pub fn text(&self) -> String {
+        html::display_text(&self.comment.children)
+    }
+
+    /// Users mentioned in this comment with `@username` links
+    pub fn mentions(&self) -> Vec<UserName> {
+        mentioned_users(&self.comment)
+    }
+}
+
+impl Reply {
+    /// Whether this reply is displayed on the site, i.e. it is neither hidden by article's author, nor blocked
+    pub fn is_visible(&self) -> bool {
+        !self.hidden_by_author && !self.is_blocked
+    }
+
+    /// Reply's text, as it would be displayed on the site
+    ///
+    /// Formatting is dropped, paragraphs are placed on separate lines.
+    pub fn text(&self) -> String {
+        html::display_text(&self.comment.children)
+    }
+
+    /// Users mentioned in this reply with `@username` links
+    pub fn mentions(&self) -> Vec<UserName> {
+        mentioned_users(&self.comment)
+    }
+}
+
+/// Extension methods for comment lists, like [`FullArticle::comments`](super::FullArticle::comments)
+pub trait CommentListExt {
+    /// Iterates over comments that are displayed on the site
+    fn visible(&self) -> impl Iterator<Item = &Article>;
+}
+
+impl CommentListExt for [Article] {
+    fn visible(&self) -> impl Iterator<Item = &Article> {
+        self.iter().filter(|comment| comment.is_visible())
+    }
+}
+
+fn mentioned_users(dom: &Dom) -> Vec<UserName> {
+    html::mentions(&dom.children)
+        .into_iter()
+        .map(|name| name.parse().unwrap_or_else(|never| match never {}))
+        .collect()
+}
+
+/// A comment along with all of it's replies
+#[derive(Debug, Clone, PartialEq, Getters)]
+pub struct ThreadNode<C = Article> {
+    comment: C,
+    replies: Vec<ThreadNode<Reply>>,
+    #[getter(skip)]
+    hidden: bool,
+}
+
+impl<C> ThreadNode<C> {
+    /// Splits node into the comment and it's replies
+    pub fn into_parts(self) -> (C, Vec<ThreadNode<Reply>>) {
+        (self.comment, self.replies)
+    }
+
+    /// Whether this node is not displayed on the site, because either it's comment or any of it's ancestors is hidden or blocked
+    pub fn is_hidden(&self) -> bool {
+        self.hidden
+    }
+
+    /// Number of replies in this node's subtree
+    pub fn reply_count(&self) -> usize {
+        self.replies
+            .iter()
+            .map(|reply| 1 + reply.reply_count())
+            .sum()
+    }
+}
+
+/// Article comments arranged into reply trees
+#[derive(Debug, Clone, PartialEq, Getters)]
+pub struct CommentThread {
+    roots: Vec<ThreadNode>,
+    /// Replies to comments that were not found (most likely, hidden or deleted)
+    orphans: Vec<Reply>,
+}
+
+impl CommentThread {
+    /// Builds reply trees out of root comments and their replies.
+    ///
+    /// Replies are attached to the comment they reply to, and ordered by creation time.
+    /// Subtrees rooted in hidden or blocked comments are marked with [`ThreadNode::is_hidden`].
+    /// Replies whose parent is not present are collected into [`CommentThread::orphans`].
+    pub fn build(roots: Vec<Article>, replies: Vec<Reply>) -> Self {
+        fn attach(
+            parent: &Id,
+            hidden: bool,
+            children: &mut HashMap<Id, Vec<Reply>>,
+        ) -> Vec<ThreadNode<Reply>> {
+            let mut replies = children.remove(parent).unwrap_or_default();
+            replies.sort_by_key(|reply| reply.created_at);
+            replies
+                .into_iter()
+                .map(|reply| {
+                    let hidden = hidden || !reply.is_visible();
+                    let replies = attach(&reply.id, hidden, children);
+                    ThreadNode {
+                        comment: reply,
+                        replies,
+                        hidden,
+                    }
+                })
+                .collect()
+        }
+
+        let mut children: HashMap<Id, Vec<Reply>> = HashMap::new();
+        for reply in replies {
+            children
+                .entry(reply.reply_to_comment.clone())
+                .or_default()
+                .push(reply);
+        }
+
+        let roots = roots
+            .into_iter()
+            .map(|comment| {
+                let hidden = !comment.is_visible();
+                let replies = attach(&comment.id, hidden, &mut children);
+                ThreadNode {
+                    comment,
+                    replies,
+                    hidden,
+                }
+            })
+            .collect();
+        let mut orphans: Vec<Reply> = children.into_values().flatten().collect();
+        orphans.sort_by_key(|reply| reply.created_at);
+        Self { roots, orphans }
+    }
+
+    /// Takes root nodes out of the thread
+    pub fn into_roots(self) -> Vec<ThreadNode> {
+        self.roots
+    }
+}
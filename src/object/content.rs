@@ -0,0 +1,842 @@
+use html_parser::Node;
+use serde::{
+    de::{DeserializeOwned, Error as _},
+    Deserialize, Deserializer,
+};
+use serde_json::Value;
+
+use super::{
+    html::{attribute_values, decode_entities, inline_nodes, plain_inline, plain_nodes},
+    MaybeUrl,
+};
+
+/// Typed article content.
+///
+/// Drukarnia stores an article's body as a list of editor blocks, with inline formatting (bold, italic, links)
+/// kept as HTML fragments inside the blocks' text fields.
+#[derive(Debug, Clone)]
+pub struct ArticleContent {
+    blocks: Vec<ContentBlock>,
+    raw: Value,
+}
+
+impl ArticleContent {
+    /// Content blocks, in order of appearance
+    pub fn blocks(&self) -> &[ContentBlock] {
+        &self.blocks
+    }
+
+    /// Original JSON value content was parsed from
+    pub fn raw(&self) -> &Value {
+        &self.raw
+    }
+
+    /// Renders content as a CommonMark document.
+    ///
+    /// Inline formatting (bold, italic, links, code) is preserved. Blocks of unknown type are rendered
+    /// as HTML comments containing their raw data, so nothing is silently dropped.
+    pub fn to_markdown(&self) -> String {
+        let mut res = self
+            .blocks
+            .iter()
+            .map(ContentBlock::to_markdown)
+            .collect::<Vec<_>>()
+            .join("\n\n");
+        res.push('\n');
+        res
+    }
+
+    /// Renders content as an HTML fragment, one element per block.
+    ///
+    /// Inline formatting is sanitized: only basic formatting tags and links with safe schemes are kept,
+    /// and all of the text is escaped. Blocks of unknown type are rendered as HTML comments containing their raw data.
+    pub fn to_html(&self) -> String {
+        let mut res = self
+            .blocks
+            .iter()
+            .map(ContentBlock::to_html)
+            .collect::<Vec<_>>()
+            .join("\n");
+        res.push('\n');
+        res
+    }
+
+    /// Extracts content's text, without any formatting.
+    ///
+    /// Text blocks are separated with empty lines. Media (images and embeds) do not contribute any text.
+    pub fn plain_text(&self) -> String {
+        self.blocks
+            .iter()
+            .filter_map(ContentBlock::plain_text)
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+
+    /// Counts words in content's [plain text](ArticleContent::plain_text).
+    ///
+    /// A word is a run of alphanumeric characters (in any script),
+    /// possibly joined with apostrophes or hyphens, like `пам'ять` or `self-hosted`.
+    pub fn word_count(&self) -> usize {
+        count_words(&self.plain_text())
+    }
+
+    /// Images of the content, in order of appearance and without repetitions.
+    ///
+    /// Includes image blocks, as well as images inlined into the text. Invalid urls are kept as [`MaybeUrl::BadUrl`].
+    pub fn images(&self) -> Vec<MaybeUrl> {
+        unique(self.blocks.iter().flat_map(ContentBlock::images))
+    }
+
+    /// Links of the content, in order of appearance and without repetitions.
+    ///
+    /// Includes links in the text and original links of the embedded content. Links relative to the site are resolved
+    /// against [`DEFAULT_HOST`](super::DEFAULT_HOST), invalid ones are kept as [`MaybeUrl::BadUrl`].
+    ///
+    /// Blocks of unknown type contribute links from their HTML, and from their `url`, `href` and `link` fields.
+    pub fn links(&self) -> Vec<MaybeUrl> {
+        unique(self.blocks.iter().flat_map(ContentBlock::links))
+    }
+
+    fn parse_blocks(raw: &Value) -> Result<Vec<ContentBlock>, String> {
+        match raw {
+            Value::Null => Ok(vec![]),
+            Value::Array(blocks) => blocks
+                .iter()
+                .map(|block| ContentBlock::deserialize(block).map_err(|err| err.to_string()))
+                .collect(),
+            Value::Object(object) => match object.get("blocks") {
+                Some(blocks @ Value::Array(_)) => Self::parse_blocks(blocks),
+                _ => Err("content object should contain a \"blocks\" array".to_owned()),
+            },
+            // Content sometimes arrives as a JSON string, containing serialized blocks
+            Value::String(s) => {
+                let inner: Value = serde_json::from_str(s).map_err(|err| err.to_string())?;
+                Self::parse_blocks(&inner)
+            }
+            other => Err(format!("unexpected content value: {other}")),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ArticleContent {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw = Value::deserialize(deserializer)?;
+        let blocks = Self::parse_blocks(&raw).map_err(D::Error::custom)?;
+        Ok(Self { blocks, raw })
+    }
+}
+
+impl PartialEq for ArticleContent {
+    fn eq(&self, other: &Self) -> bool {
+        // blocks are parsed from the raw value
+        self.raw == other.raw
+    }
+}
+
+impl serde::Serialize for ArticleContent {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.raw.serialize(serializer)
+    }
+}
+
+impl<'c> IntoIterator for &'c ArticleContent {
+    type Item = &'c ContentBlock;
+    type IntoIter = std::slice::Iter<'c, ContentBlock>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.blocks.iter()
+    }
+}
+
+/// A single editor block of an article.
+///
+/// Text fields contain HTML fragments, exactly as they were returned by the site.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum ContentBlock {
+    /// Regular text paragraph
+    Paragraph {
+        /// Paragraph text
+        text: String,
+    },
+    /// Section heading
+    Heading {
+        /// Heading level, `1` being the largest
+        level: u8,
+        /// Heading text
+        text: String,
+    },
+    /// An image, optionally captioned
+    Image {
+        /// Image location
+        url: MaybeUrl,
+        /// Image caption
+        caption: Option<String>,
+    },
+    /// A quotation
+    Quote {
+        /// Quoted text
+        text: String,
+        /// Quote caption, usually an author
+        caption: Option<String>,
+    },
+    /// Preformatted code block
+    Code {
+        /// Code text. Unlike other text fields, this is not HTML
+        code: String,
+    },
+    /// Ordered or unordered list
+    List {
+        /// Is the list ordered (numbered)
+        ordered: bool,
+        /// List items
+        items: Vec<String>,
+    },
+    /// Embedded third-party content, like a YouTube video
+    Embed {
+        /// Service name, like `youtube`
+        service: String,
+        /// Original link to the content
+        source: MaybeUrl,
+        /// Link used to actually embed the content
+        embed: MaybeUrl,
+        /// Embed caption
+        caption: Option<String>,
+    },
+    /// Section delimiter
+    Delimiter,
+    /// Block of a type this crate does not know about yet
+    Unknown {
+        /// Block type, as reported by the site
+        r#type: String,
+        /// Block data
+        data: Value,
+    },
+}
+
+#[derive(Deserialize)]
+struct RawBlock {
+    r#type: String,
+    #[serde(default)]
+    data: Value,
+}
+
+#[derive(Deserialize)]
+struct TextData {
+    #[serde(default)]
+    text: String,
+}
+
+#[derive(Deserialize)]
+struct HeadingData {
+    #[serde(default)]
+    text: String,
+    level: u8,
+}
+
+#[derive(Deserialize)]
+struct ImageFile {
+    url: MaybeUrl,
+}
+
+#[derive(Deserialize)]
+struct ImageData {
+    file: Option<ImageFile>,
+    url: Option<MaybeUrl>,
+    #[serde(default)]
+    caption: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct QuoteData {
+    #[serde(default)]
+    text: String,
+    #[serde(default)]
+    caption: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct CodeData {
+    #[serde(default)]
+    code: String,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ListItem {
+    Plain(String),
+    Nested { content: String },
+}
+
+#[derive(Deserialize)]
+struct ListData {
+    #[serde(default)]
+    style: Option<String>,
+    items: Vec<ListItem>,
+}
+
+#[derive(Deserialize)]
+struct EmbedData {
+    service: String,
+    source: MaybeUrl,
+    embed: MaybeUrl,
+    #[serde(default)]
+    caption: Option<String>,
+}
+
+fn non_empty(caption: Option<String>) -> Option<String> {
+    caption.filter(|caption| !caption.trim().is_empty())
+}
+
+impl<'de> Deserialize<'de> for ContentBlock {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        fn data<T: DeserializeOwned, E: serde::de::Error>(data: Value) -> Result<T, E> {
+            T::deserialize(data).map_err(E::custom)
+        }
+
+        let RawBlock { r#type, data: raw } = RawBlock::deserialize(deserializer)?;
+        Ok(match r#type.as_str() {
+            "paragraph" => {
+                let TextData { text } = data(raw)?;
+                Self::Paragraph { text }
+            }
+            "header" | "heading" => {
+                let HeadingData { text, level } = data(raw)?;
+                Self::Heading { level, text }
+            }
+            "image" => {
+                let ImageData { file, url, caption } = data(raw)?;
+                let url = file
+                    .map(|file| file.url)
+                    .or(url)
+                    .ok_or_else(|| D::Error::missing_field("url"))?;
+                Self::Image {
+                    url,
+                    caption: non_empty(caption),
+                }
+            }
+            "quote" => {
+                let QuoteData { text, caption } = data(raw)?;
+                Self::Quote {
+                    text,
+                    caption: non_empty(caption),
+                }
+            }
+            "code" => {
+                let CodeData { code } = data(raw)?;
+                Self::Code { code }
+            }
+            "list" => {
+                let ListData { style, items } = data(raw)?;
+                Self::List {
+                    ordered: style.as_deref() == Some("ordered"),
+                    items: items
+                        .into_iter()
+                        .map(|item| match item {
+                            ListItem::Plain(text) | ListItem::Nested { content: text } => text,
+                        })
+                        .collect(),
+                }
+            }
+            "embed" => {
+                let EmbedData {
+                    service,
+                    source,
+                    embed,
+                    caption,
+                } = data(raw)?;
+                Self::Embed {
+                    service,
+                    source,
+                    embed,
+                    caption: non_empty(caption),
+                }
+            }
+            "delimiter" => Self::Delimiter,
+            _ => Self::Unknown { r#type, data: raw },
+        })
+    }
+}
+
+impl ContentBlock {
+    /// Images of this block, see [`ArticleContent::images`]
+    pub fn images(&self) -> Vec<MaybeUrl> {
+        let mut images = match self {
+            Self::Image { url, .. } => vec![url.clone()],
+            _ => vec![],
+        };
+        images.extend(self.inline_references("img", "src"));
+        images
+    }
+
+    /// Links of this block, see [`ArticleContent::links`]
+    pub fn links(&self) -> Vec<MaybeUrl> {
+        let mut links = match self {
+            Self::Embed { source, .. } => vec![source.clone()],
+            Self::Unknown { data, .. } => {
+                let mut found = vec![];
+                url_fields(data, &mut found);
+                found.into_iter().map(reference).collect()
+            }
+            _ => vec![],
+        };
+        links.extend(self.inline_references("a", "href"));
+        links
+    }
+
+    /// Inline HTML fragments of this block, that might contain links and images
+    fn inline_html(&self) -> Vec<&str> {
+        match self {
+            Self::Paragraph { text } | Self::Heading { text, .. } => vec![text],
+            Self::Quote { text, caption } => std::iter::once(text)
+                .chain(caption)
+                .map(String::as_str)
+                .collect(),
+            Self::List { items, .. } => items.iter().map(String::as_str).collect(),
+            Self::Image { caption, .. } | Self::Embed { caption, .. } => {
+                caption.iter().map(String::as_str).collect()
+            }
+            Self::Code { .. } | Self::Delimiter => vec![],
+            Self::Unknown { data, .. } => {
+                let mut strings = vec![];
+                string_values(data, &mut strings);
+                strings
+            }
+        }
+    }
+
+    /// Attribute values of the elements inlined into this block, parsed as urls
+    fn inline_references(&self, element: &str, attribute: &str) -> Vec<MaybeUrl> {
+        let mut values = vec![];
+        for html in self.inline_html() {
+            attribute_values(&inline_nodes(html), element, attribute, &mut values);
+        }
+        values.into_iter().map(reference).collect()
+    }
+
+    /// Text of this block, without any formatting
+    ///
+    /// Returns `None` for blocks that carry no text, like images and delimiters.
+    pub fn plain_text(&self) -> Option<String> {
+        match self {
+            Self::Paragraph { text } | Self::Heading { text, .. } => Some(plain_inline(text)),
+            Self::Quote { text, caption } => {
+                let mut quote = plain_inline(text);
+                if let Some(caption) = caption {
+                    quote.push('\n');
+                    quote.push_str(&plain_inline(caption));
+                }
+                Some(quote)
+            }
+            Self::Code { code } => Some(code.clone()),
+            Self::List { items, .. } => Some(
+                items
+                    .iter()
+                    .map(|item| plain_inline(item))
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+            ),
+            Self::Image { .. } | Self::Embed { .. } | Self::Delimiter | Self::Unknown { .. } => {
+                None
+            }
+        }
+    }
+
+    /// Renders this block as CommonMark
+    pub fn to_markdown(&self) -> String {
+        match self {
+            Self::Paragraph { text } => markdown_inline(text),
+            Self::Heading { level, text } => {
+                format!(
+                    "{} {}",
+                    "#".repeat((*level).clamp(1, 6).into()),
+                    markdown_inline(text)
+                )
+            }
+            Self::Image { url, caption } => {
+                let alt = caption.as_deref().map(markdown_inline).unwrap_or_default();
+                format!("![{alt}]({})", markdown_destination(url.source()))
+            }
+            Self::Quote { text, caption } => {
+                let mut quote = markdown_inline(text);
+                if let Some(caption) = caption {
+                    quote.push_str("\n\n— ");
+                    quote.push_str(&markdown_inline(caption));
+                }
+                quote
+                    .lines()
+                    .map(|line| {
+                        if line.is_empty() {
+                            ">".to_owned()
+                        } else {
+                            format!("> {line}")
+                        }
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            }
+            Self::Code { code } => {
+                let fence = "`".repeat(std::cmp::max(3, longest_backtick_run(code) + 1));
+                format!("{fence}\n{code}\n{fence}")
+            }
+            Self::List { ordered, items } => items
+                .iter()
+                .enumerate()
+                .map(|(index, item)| {
+                    let marker = if *ordered {
+                        format!("{}.", index + 1)
+                    } else {
+                        "-".to_owned()
+                    };
+                    // continuation lines should be indented to stay inside the list item
+                    let indent = " ".repeat(marker.len() + 1);
+                    format!(
+                        "{marker} {}",
+                        markdown_inline(item).replace('\n', &format!("\n{indent}"))
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("\n"),
+            Self::Embed {
+                service,
+                source,
+                caption,
+                ..
+            } => {
+                let text = caption
+                    .as_deref()
+                    .map_or_else(|| escape_markdown(service), markdown_inline);
+                format!("[{text}]({})", markdown_destination(source.source()))
+            }
+            Self::Delimiter => "---".to_owned(),
+            Self::Unknown { r#type, data } => {
+                // "-->" inside of the data would end the comment prematurely
+                let data = data.to_string().replace("-->", "--&gt;");
+                format!("<!-- {type}: {data} -->")
+            }
+        }
+    }
+
+    /// Renders this block as a sanitized HTML fragment, see [`ArticleContent::to_html`]
+    pub fn to_html(&self) -> String {
+        match self {
+            Self::Paragraph { text } => format!("<p>{}</p>", html_inline(text)),
+            Self::Heading { level, text } => {
+                let level = (*level).clamp(1, 6);
+                format!("<h{level}>{}</h{level}>", html_inline(text))
+            }
+            Self::Image { url, caption } => {
+                let alt = caption.as_deref().map(plain_inline).unwrap_or_default();
+                let image = safe_link(url.source()).map_or_else(String::new, |src| {
+                    format!(
+                        "<img src=\"{}\" alt=\"{}\">",
+                        escape_html(src),
+                        escape_html(&alt)
+                    )
+                });
+                let caption = caption.as_deref().map_or_else(String::new, |caption| {
+                    format!("<figcaption>{}</figcaption>", html_inline(caption))
+                });
+                format!("<figure>{image}{caption}</figure>")
+            }
+            Self::Quote { text, caption } => {
+                let caption = caption.as_deref().map_or_else(String::new, |caption| {
+                    format!("<footer>{}</footer>", html_inline(caption))
+                });
+                format!(
+                    "<blockquote><p>{}</p>{caption}</blockquote>",
+                    html_inline(text)
+                )
+            }
+            Self::Code { code } => format!("<pre><code>{}</code></pre>", escape_html(code)),
+            Self::List { ordered, items } => {
+                let tag = if *ordered { "ol" } else { "ul" };
+                let items: String = items
+                    .iter()
+                    .map(|item| format!("<li>{}</li>", html_inline(item)))
+                    .collect();
+                format!("<{tag}>{items}</{tag}>")
+            }
+            Self::Embed {
+                service,
+                source,
+                caption,
+                ..
+            } => {
+                let text = caption
+                    .as_deref()
+                    .map_or_else(|| escape_html(service), html_inline);
+                match safe_link(source.source()) {
+                    Some(href) => format!("<p><a href=\"{}\">{text}</a></p>", escape_html(href)),
+                    None => format!("<p>{text}</p>"),
+                }
+            }
+            Self::Delimiter => "<hr>".to_owned(),
+            Self::Unknown { r#type, data } => {
+                // "--" is not allowed inside of the comments
+                let comment = format!("{type}: {data}").replace("--", "&#45;&#45;");
+                format!("<!-- {comment} -->")
+            }
+        }
+    }
+}
+
+/// Escapes text, so that it could be placed both into element's content and into a quoted attribute
+pub(crate) fn escape_html(text: &str) -> String {
+    let mut res = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => res.push_str("&amp;"),
+            '<' => res.push_str("&lt;"),
+            '>' => res.push_str("&gt;"),
+            '"' => res.push_str("&quot;"),
+            '\'' => res.push_str("&#39;"),
+            c => res.push(c),
+        }
+    }
+    res
+}
+
+/// Link, if it is safe to follow. Relative links are kept, and absolute ones should be `http(s)` or `mailto`
+fn safe_link(link: &str) -> Option<&str> {
+    match url::Url::parse(link) {
+        Ok(url) => matches!(url.scheme(), "http" | "https" | "mailto").then_some(link),
+        Err(url::ParseError::RelativeUrlWithoutBase) => Some(link),
+        Err(_) => None,
+    }
+}
+
+fn html_inline(html: &str) -> String {
+    let mut res = String::new();
+    html_nodes(&inline_nodes(html), &mut res);
+    res
+}
+
+fn html_nodes(nodes: &[Node], out: &mut String) {
+    for node in nodes {
+        match node {
+            Node::Text(text) => out.push_str(&escape_html(&decode_entities(text))),
+            Node::Element(element) => {
+                let name = element.name.to_ascii_lowercase();
+                match name.as_str() {
+                    "b" | "strong" | "i" | "em" | "u" | "s" | "code" | "mark" | "sub" | "sup" => {
+                        out.push_str(&format!("<{name}>"));
+                        html_nodes(&element.children, out);
+                        out.push_str(&format!("</{name}>"));
+                    }
+                    "a" => {
+                        let href = element
+                            .attributes
+                            .get("href")
+                            .cloned()
+                            .flatten()
+                            .map(|href| decode_entities(&href));
+                        match href.as_deref().and_then(safe_link) {
+                            Some(href) => {
+                                out.push_str(&format!("<a href=\"{}\">", escape_html(href)));
+                                html_nodes(&element.children, out);
+                                out.push_str("</a>");
+                            }
+                            None => html_nodes(&element.children, out),
+                        }
+                    }
+                    "br" => out.push_str("<br>"),
+                    _ => html_nodes(&element.children, out),
+                }
+            }
+            Node::Comment(_) => {}
+        }
+    }
+}
+
+fn escape_markdown(text: &str) -> String {
+    let mut res = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '\\' | '`' | '*' | '_' | '[' | ']' | '<') {
+            res.push('\\');
+        }
+        res.push(c);
+    }
+    res
+}
+
+fn longest_backtick_run(text: &str) -> usize {
+    text.split(|c| c != '`').map(str::len).max().unwrap_or(0)
+}
+
+/// Link destination, wrapped in angle brackets if it would otherwise break the link
+fn markdown_destination(url: &str) -> String {
+    if url.contains(|c: char| c.is_whitespace() || c == '(' || c == ')') {
+        format!("<{}>", url.replace('<', "%3C").replace('>', "%3E"))
+    } else {
+        url.to_owned()
+    }
+}
+
+fn markdown_inline(html: &str) -> String {
+    let mut res = String::new();
+    markdown_nodes(&inline_nodes(html), &mut res);
+    escape_line_starts(&res)
+}
+
+/// Escapes markers that would start a block at the beginning of a line
+///
+/// These are headings (`#`), quotes (`>`), list items (`-`, `+`, `1.`, `1)`), thematic breaks and setext underlines (`-`, `=`).
+fn escape_line_starts(markdown: &str) -> String {
+    markdown
+        .split('\n')
+        .map(|line| {
+            let (indent, rest) =
+                line.split_at(line.len() - line.trim_start_matches([' ', '\t']).len());
+            let digits = rest.len() - rest.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+            let marker = match rest[digits..].chars().next() {
+                Some('.' | ')') if digits > 0 => Some(digits),
+                Some('#' | '>' | '-' | '+' | '=') if digits == 0 => Some(0),
+                _ => None,
+            };
+            match marker {
+                Some(at) => format!("{indent}{}\\{}", &rest[..at], &rest[at..]),
+                None => line.to_owned(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Wraps markdown into a delimiter, keeping surrounding whitespace outside of it
+fn push_delimited(out: &mut String, delimiter: &str, inner: &str) {
+    let trimmed = inner.trim();
+    if trimmed.is_empty() {
+        out.push_str(inner);
+        return;
+    }
+    let leading = &inner[..inner.len() - inner.trim_start().len()];
+    let trailing = &inner[inner.trim_end().len()..];
+    out.push_str(leading);
+    out.push_str(delimiter);
+    out.push_str(trimmed);
+    out.push_str(delimiter);
+    out.push_str(trailing);
+}
+
+fn markdown_nodes(nodes: &[Node], out: &mut String) {
+    for node in nodes {
+        match node {
+            Node::Text(text) => out.push_str(&escape_markdown(&decode_entities(text))),
+            Node::Element(element) => {
+                let mut inner = String::new();
+                match element.name.to_ascii_lowercase().as_str() {
+                    "b" | "strong" => {
+                        markdown_nodes(&element.children, &mut inner);
+                        push_delimited(out, "**", &inner);
+                    }
+                    "i" | "em" => {
+                        markdown_nodes(&element.children, &mut inner);
+                        push_delimited(out, "_", &inner);
+                    }
+                    "code" => {
+                        plain_nodes(&element.children, &mut inner);
+                        let fence = "`".repeat(longest_backtick_run(&inner) + 1);
+                        let pad = if inner.starts_with('`') || inner.ends_with('`') {
+                            " "
+                        } else {
+                            ""
+                        };
+                        out.push_str(&format!("{fence}{pad}{inner}{pad}{fence}"));
+                    }
+                    "a" => {
+                        markdown_nodes(&element.children, &mut inner);
+                        match element.attributes.get("href").cloned().flatten() {
+                            Some(href) => out.push_str(&format!(
+                                "[{inner}]({})",
+                                markdown_destination(&decode_entities(&href))
+                            )),
+                            None => out.push_str(&inner),
+                        }
+                    }
+                    "br" => out.push_str("\\\n"),
+                    _ => markdown_nodes(&element.children, out),
+                }
+            }
+            Node::Comment(_) => {}
+        }
+    }
+}
+
+fn count_words(text: &str) -> usize {
+    let mut count = 0;
+    let mut chars = text.chars().peekable();
+    let mut in_word = false;
+    while let Some(c) = chars.next() {
+        if c.is_alphanumeric() {
+            if !in_word {
+                count += 1;
+                in_word = true;
+            }
+        } else if in_word
+            && matches!(c, '\'' | '’' | 'ʼ' | '-')
+            && chars.peek().is_some_and(|next| next.is_alphanumeric())
+        {
+            // joiner inside of a word, like in "пам'ять"
+        } else {
+            in_word = false;
+        }
+    }
+    count
+}
+
+/// Parses a reference found in content, resolving the ones relative to the site
+fn reference(source: String) -> MaybeUrl {
+    if source.starts_with('/') && !source.starts_with("//") {
+        return MaybeUrl::parse(format!("https://{}{source}", super::DEFAULT_HOST));
+    }
+    MaybeUrl::parse(source)
+}
+
+/// Drops repeated urls, keeping the first occurrence
+fn unique(urls: impl Iterator<Item = MaybeUrl>) -> Vec<MaybeUrl> {
+    let mut res: Vec<MaybeUrl> = vec![];
+    for url in urls {
+        if !res.contains(&url) {
+            res.push(url);
+        }
+    }
+    res
+}
+
+/// Collects all of the strings in a JSON value
+fn string_values<'v>(value: &'v Value, out: &mut Vec<&'v str>) {
+    match value {
+        Value::String(s) => out.push(s),
+        Value::Array(items) => items.iter().for_each(|item| string_values(item, out)),
+        Value::Object(object) => object.values().for_each(|item| string_values(item, out)),
+        Value::Null | Value::Bool(_) | Value::Number(_) => {}
+    }
+}
+
+/// Collects strings of `url`, `href` and `link` fields in a JSON value, however deep they are
+fn url_fields(value: &Value, out: &mut Vec<String>) {
+    match value {
+        Value::Array(items) => items.iter().for_each(|item| url_fields(item, out)),
+        Value::Object(object) => {
+            for (key, item) in object {
+                match item {
+                    Value::String(url) if matches!(key.as_str(), "url" | "href" | "link") => {
+                        out.push(url.trim().to_owned());
+                    }
+                    other => url_fields(other, out),
+                }
+            }
+        }
+        Value::Null | Value::Bool(_) | Value::Number(_) | Value::String(_) => {}
+    }
+}
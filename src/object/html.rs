@@ -0,0 +1,170 @@
+use html_parser::{Dom, Node};
+
+/// Parses an inline HTML fragment, treating it as plain text if it's not valid HTML
+pub(super) fn inline_nodes(html: &str) -> Vec<Node> {
+    Dom::parse(html).map_or_else(|_| vec![Node::Text(html.to_owned())], |dom| dom.children)
+}
+
+/// Decodes HTML character references
+pub(super) fn decode_entities(text: &str) -> String {
+    let mut res = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        res.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let decoded = rest.find(';').filter(|&end| end <= 10).and_then(|end| {
+            let entity = &rest[1..end];
+            let c = match entity {
+                "amp" => Some('&'),
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                "nbsp" => Some('\u{a0}'),
+                "ndash" => Some('–'),
+                "mdash" => Some('—'),
+                "hellip" => Some('…'),
+                "laquo" => Some('«'),
+                "raquo" => Some('»'),
+                "lsquo" => Some('‘'),
+                "rsquo" => Some('’'),
+                "ldquo" => Some('“'),
+                "rdquo" => Some('”'),
+                _ => entity
+                    .strip_prefix("#x")
+                    .or_else(|| entity.strip_prefix("#X"))
+                    .map(|hex| u32::from_str_radix(hex, 16))
+                    .or_else(|| entity.strip_prefix('#').map(str::parse))
+                    .and_then(Result::ok)
+                    .and_then(char::from_u32),
+            }?;
+            Some((c, end))
+        });
+        match decoded {
+            Some((c, end)) => {
+                res.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                res.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    res.push_str(rest);
+    res
+}
+
+pub(super) fn plain_inline(html: &str) -> String {
+    let mut res = String::new();
+    plain_nodes(&inline_nodes(html), &mut res);
+    res
+}
+
+/// Collects text of the nodes, without any formatting
+pub(super) fn plain_nodes(nodes: &[Node], out: &mut String) {
+    for node in nodes {
+        match node {
+            Node::Text(text) => out.push_str(&decode_entities(text)),
+            Node::Element(element) if element.name.eq_ignore_ascii_case("br") => out.push('\n'),
+            Node::Element(element) => plain_nodes(&element.children, out),
+            Node::Comment(_) => {}
+        }
+    }
+}
+
+/// Collects text of the nodes as it would be displayed: block elements start new lines,
+/// whitespace is collapsed and empty lines are dropped
+pub(super) fn display_text(nodes: &[Node]) -> String {
+    fn collect(nodes: &[Node], out: &mut String) {
+        for node in nodes {
+            match node {
+                Node::Text(text) => out.push_str(&decode_entities(text).replace('\n', " ")),
+                Node::Element(element) => match element.name.to_ascii_lowercase().as_str() {
+                    "br" => out.push('\n'),
+                    "p" | "div" | "blockquote" | "li" | "ul" | "ol" | "pre" | "h1" | "h2"
+                    | "h3" | "h4" | "h5" | "h6" => {
+                        out.push('\n');
+                        collect(&element.children, out);
+                        out.push('\n');
+                    }
+                    _ => collect(&element.children, out),
+                },
+                Node::Comment(_) => {}
+            }
+        }
+    }
+
+    let mut text = String::new();
+    collect(nodes, &mut text);
+    text.lines()
+        .map(|line| line.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Collects usernames mentioned with `@username` links, in order of appearance and without repetitions
+pub(super) fn mentions(nodes: &[Node]) -> Vec<String> {
+    fn collect(nodes: &[Node], out: &mut Vec<String>) {
+        for node in nodes {
+            let Node::Element(element) = node else {
+                continue;
+            };
+            if !element.name.eq_ignore_ascii_case("a") {
+                collect(&element.children, out);
+                continue;
+            }
+            let from_href = element
+                .attributes
+                .get("href")
+                .cloned()
+                .flatten()
+                .and_then(|href| {
+                    let href = decode_entities(&href);
+                    let path = href.split(['?', '#']).next().unwrap_or_default();
+                    let last = path.trim_end_matches('/').rsplit('/').next()?;
+                    last.strip_prefix('@').map(str::to_owned)
+                });
+            let from_text = || {
+                let mut text = String::new();
+                plain_nodes(&element.children, &mut text);
+                text.trim().strip_prefix('@').map(str::to_owned)
+            };
+            if let Some(name) = from_href.or_else(from_text).filter(|name| !name.is_empty()) {
+                if !out.contains(&name) {
+                    out.push(name);
+                }
+            }
+        }
+    }
+
+    let mut res = vec![];
+    collect(nodes, &mut res);
+    res
+}
+
+/// Collects values of an attribute of the elements with a given name (like `href` of `a`), in order of appearance
+///
+/// Character references in values are decoded, and empty values are skipped.
+pub(super) fn attribute_values(
+    nodes: &[Node],
+    element_name: &str,
+    attribute: &str,
+    out: &mut Vec<String>,
+) {
+    for node in nodes {
+        let Node::Element(element) = node else {
+            continue;
+        };
+        if element.name.eq_ignore_ascii_case(element_name) {
+            let value = element.attributes.get(attribute).cloned().flatten();
+            if let Some(value) = value.map(|value| decode_entities(value.trim())) {
+                if !value.is_empty() {
+                    out.push(value);
+                }
+            }
+        }
+        attribute_values(&element.children, element_name, attribute, out);
+    }
+}
@@ -5,6 +5,7 @@ super::id_type! {"user list"}
 super::str_type! {Name, "name", "list"}
 
 data_type! {
+    "A bookmark list of authorized user",
     Full,
     id,
     name,
@@ -1,3 +1,5 @@
+#![deny(missing_docs)]
+
 mod user;
 
 use std::{borrow::Cow, str::FromStr};
@@ -5,13 +7,15 @@ use std::{borrow::Cow, str::FromStr};
 use derive_getters::Getters;
 use email_address::EmailAddress;
 use secrecy::{Secret, SecretString};
+use time::OffsetDateTime;
 use url::Url;
 pub use user::{
     Article as ArticleUser, Authorized as AuthorizedUser, Comment as CommentUser,
     Description as UserDescription, DisplayName as UserDisplayName, Follower as FollowerUser,
-    Full as FullUser, Id as UserId, Name as UserName, Short as ShortUser,
-    ShortDescription as UserShortDescription, Socials as UserSocials,
+    Full as FullUser, Id as UserId, Name as UserName, Plain as PlainShortUser, Short as ShortUser,
+    ShortDescription as UserShortDescription, SocialPlatform, Socials as UserSocials,
 };
+use zeroize::{Zeroize, ZeroizeOnDrop};
 
 mod tag;
 
@@ -22,35 +26,58 @@ pub use tag::{
 
 mod comment;
 
-pub use comment::{Article as ArticleComment, Id as CommentId, Reply as ReplyComment};
+pub use comment::{
+    Article as ArticleComment, CommentListExt, CommentThread, HtmlParseError, Id as CommentId,
+    LazyDom, LikeState as CommentLikeState, Reply as ReplyComment, ThreadNode,
+};
 
 mod article;
 
 pub use article::{
-    Author as AuthorArticle, Description as ArticleDescription, Feed as FeedArticle,
-    Full as FullArticle, Id as ArticleId, List as ListArticle, Recommended as RecommendedArticle,
-    Search as SearchArticle, Short as ShortArticle, Slug as ArticleSlug, Tag as TagArticle,
-    Title as ArticleTitle,
+    AsSummary as AsArticleSummary, Author as AuthorArticle, Description as ArticleDescription,
+    Feed as FeedArticle, Full as FullArticle, Id as ArticleId, LikeAmount, List as ListArticle,
+    Recommended as RecommendedArticle, Search as SearchArticle, Short as ShortArticle,
+    Slug as ArticleSlug, Summary as ArticleSummary, Tag as TagArticle, Title as ArticleTitle,
 };
 
+mod content;
+
+mod html;
+
+pub mod text;
+
+#[cfg(feature = "export")]
+pub(crate) use content::escape_html;
+pub use content::{ArticleContent, ContentBlock};
+
 mod list;
 
 pub use list::{Full as FullList, Id as ListId};
 
+mod schema;
+
+pub use schema::schema_versions;
+
+mod notification;
+
+pub use notification::{
+    Details as NotificationDetails, Full as FullNotification, Id as NotificationId,
+    Kind as NotificationKind,
+};
+
 mod bookmark;
 
 pub use bookmark::{Full as FullBookmark, Id as BookmarkId};
 
 macro_rules! id_type {
     {$object_type:literal} => {
-        #[derive(Debug, ::derive_more::Into, ::derive_more::AsRef, ::derive_more::Display, ::serde::Deserialize, ::derives::HexId, Clone, PartialEq, Eq, Hash)]
+        #[derive(Debug, ::derive_more::Into, ::derive_more::AsRef, ::derive_more::Display, ::derives::HexId, Clone, PartialEq, Eq, Hash)]
         #[display(fmt = "{}", "self.display_as_hex()")]
-        #[serde(transparent)]
         #[doc = concat!("Represents an id of some ", $object_type)]
-        pub struct Id(#[serde(with = "::serde_hex::SerHex::<::serde_hex::config::Strict>")] [u8; 12]);
+        pub struct Id([u8; 12]);
     };
 }
-pub(self) use id_type;
+pub(crate) use id_type;
 
 macro_rules! str_type {
     {$type_name:ident, $object_type:literal, $owner_object:literal} => {
@@ -60,6 +87,7 @@ macro_rules! str_type {
             ::derive_more::AsRef,
             ::derive_more::Display,
             ::serde::Deserialize,
+            ::serde::Serialize,
             Clone,
             PartialEq,
             Eq,
@@ -70,10 +98,195 @@ macro_rules! str_type {
         pub struct $type_name(String);
     };
 }
-pub(self) use str_type;
+pub(crate) use str_type;
+
+/// An object fetched from the site at some point in time
+///
+/// Implemented for all of the data types, via `#[derive(Aged)]`.
+pub trait Aged {
+    /// Time the object was fetched at
+    fn fetched_at(&self) -> OffsetDateTime;
+
+    /// Provides a way to know a `time::Duration` since the object was fetched
+    ///
+    /// May be used to, for example, re-fetch it once it gets too old, and thus probably outdated
+    fn get_age(&self) -> time::Duration {
+        OffsetDateTime::now_utc() - self.fetched_at()
+    }
+
+    /// Checks if the object was fetched more than `age` ago
+    fn is_older_than(&self, age: time::Duration) -> bool {
+        self.get_age() > age
+    }
+}
+
+/// An object, identified by 12-byte id
+pub trait HasId {
+    /// Object's id as raw bytes
+    fn raw_id(&self) -> &[u8; 12];
+}
+
+/// An object, addressed by a slug (or username, for users) on a site
+pub trait HasSlug {
+    /// Object's slug as a string
+    fn slug_str(&self) -> &str;
+}
+
+/// An object that records it's creation time
+pub trait HasCreatedAt {
+    /// Object's creation time
+    fn created_at(&self) -> OffsetDateTime;
+}
+
+/// An article, filed under a main tag and, possibly, some other tags
+pub trait HasTags {
+    /// Id of the article's main tag
+    fn main_tag_id(&self) -> &TagId;
+
+    /// Ids of the tags listed on the article, as returned by the site
+    ///
+    /// Empty for the article types that do not list tags. Site usually lists main tag here as well.
+    fn listed_tag_ids(&self) -> Vec<&TagId>;
+
+    /// Ids of all of the article's tags, main tag first, without repetitions
+    fn tag_ids(&self) -> Vec<&TagId> {
+        let mut ids = vec![self.main_tag_id()];
+        for id in self.listed_tag_ids() {
+            if !ids.contains(&id) {
+                ids.push(id);
+            }
+        }
+        ids
+    }
+}
+
+/// Sorts objects by their creation time, newest first
+///
+/// Sort is stable, so objects created at the same time retain their relative order.
+pub fn sort_newest_first<T: HasCreatedAt>(objects: &mut [T]) {
+    objects.sort_by_key(|object| std::cmp::Reverse(object.created_at()));
+}
+
+/// Host objects are expected to be located at, unless other is specified
+pub const DEFAULT_HOST: &str = "drukarnia.com.ua";
+
+/// An error that might occur while extracting object's slug (or name) from it's url
+#[derive(Debug, thiserror::Error, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SlugFromUrlError {
+    /// Url does not point to the expected host
+    #[error("Expected url at {expected} host, found {found:?}")]
+    WrongHost {
+        /// Host url was expected to point to
+        expected: String,
+        /// Actual url's host, if any
+        found: Option<String>,
+    },
+    /// Url's path does not point to an object of requested type
+    #[error("Url path {0} does not point to an object of requested type")]
+    UnexpectedPath(String),
+    /// Url's path segment does not decode into UTF-8
+    #[error("Url path segment {0} is not a percent-encoded UTF-8 string")]
+    BadPathEncoding(String),
+}
+
+/// An error that might occur while parsing an id from it's hex representation
+#[derive(Debug, thiserror::Error, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum HexIdParseError {
+    /// Id should consist of exactly 24 hex digits
+    #[error("Expected 24 hex digits, found {0} characters")]
+    WrongLength(usize),
+    /// Id contains a character that is not a hex digit
+    #[error("Character {character:?} at position {position} is not a hex digit")]
+    InvalidCharacter {
+        /// Offending character
+        character: char,
+        /// Character's position in the string
+        position: usize,
+    },
+}
+
+/// An error that might occur while creating a [`LikeAmount`] from a number
+#[derive(Debug, thiserror::Error, Clone, PartialEq, Eq)]
+#[error("Expected at most {} likes, found {0}", LikeAmount::MAX.get())]
+pub struct LikeAmountError(pub u8);
+
+/// Returns non-empty path segments of the url, checking it's host first
+///
+/// `www.` subdomain is accepted as well. Segments are percent-decoded, since browsers encode non-ASCII slugs and names.
+fn url_path_segments(url: &Url, host: &str) -> Result<Vec<String>, SlugFromUrlError> {
+    let found = url.host_str();
+    if found.map(|found| found.strip_prefix("www.").unwrap_or(found)) != Some(host) {
+        return Err(SlugFromUrlError::WrongHost {
+            expected: host.to_owned(),
+            found: found.map(ToOwned::to_owned),
+        });
+    }
+    url.path_segments()
+        .into_iter()
+        .flatten()
+        .filter(|segment| !segment.is_empty())
+        .map(|segment| {
+            percent_encoding::percent_decode_str(segment)
+                .decode_utf8()
+                .map(Cow::into_owned)
+                .map_err(|_| SlugFromUrlError::BadPathEncoding(segment.to_owned()))
+        })
+        .collect()
+}
+
+/// Attaches response JSON to the objects parsed from it
+#[cfg(feature = "raw-json")]
+pub(crate) trait AttachRaw {
+    fn attach_raw(&mut self, raw: &str);
+}
+
+/// Each element gets it's own part of the array
+#[cfg(feature = "raw-json")]
+impl<T: AttachRaw> AttachRaw for Vec<T> {
+    fn attach_raw(&mut self, raw: &str) {
+        let Ok(elements) = serde_json::from_str::<Vec<&serde_json::value::RawValue>>(raw) else {
+            return;
+        };
+        for (object, element) in self.iter_mut().zip(elements) {
+            object.attach_raw(element.get());
+        }
+    }
+}
+
+/// Projects richer representation of some object onto a poorer one
+///
+/// Used by `From` implementations, generated by `data_type!` for downgrades between representations.
+pub(crate) trait Project<T> {
+    fn project(self) -> T;
+}
+
+impl<T> Project<T> for T {
+    fn project(self) -> T {
+        self
+    }
+}
+
+impl<T> Project<Option<T>> for T {
+    fn project(self) -> Option<T> {
+        Some(self)
+    }
+}
 
 /// Represents user's attitude to some object (other user, tag, article, etc)
-#[derive(Debug, serde::Deserialize, derive_getters::Getters, Clone)]
+///
+/// Defaults to no subscription and no block, which is what an anonymous user has.
+#[derive(
+    Debug,
+    Default,
+    serde::Deserialize,
+    serde::Serialize,
+    derive_getters::Getters,
+    Clone,
+    PartialEq,
+    Eq,
+)]
 pub struct Relationships {
     #[serde(rename = "isSubscribed")]
     is_subscribed: bool,
@@ -81,63 +294,259 @@ pub struct Relationships {
     is_blocked: bool,
 }
 
+/// What user logs in with, along with the password
+///
+/// Site accepts either an email, or a username.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LoginIdentifier {
+    /// Email user had registered with
+    Email(EmailAddress),
+    /// User's username
+    Username(UserName),
+}
+
+impl std::fmt::Display for LoginIdentifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Email(email) => f.write_str(email.as_str()),
+            Self::Username(username) => f.write_str(username.as_ref()),
+        }
+    }
+}
+
+impl Zeroize for LoginIdentifier {
+    fn zeroize(&mut self) {
+        let identifier = std::mem::replace(self, Self::Email(EmailAddress::new_unchecked("")));
+        let mut identifier: String = match identifier {
+            Self::Email(email) => email.into(),
+            Self::Username(username) => username.into(),
+        };
+        identifier.zeroize();
+    }
+}
+
 /// Represents user credentials
-#[derive(Debug, serde::Deserialize, Getters)]
+///
+/// Both login identifier and password are zeroized once dropped, and are never shown in `Debug` output.
+///
+/// Deserializes from either `email` or `username` field, along with `password`.
+#[derive(serde::Deserialize, Getters, Clone)]
 pub struct Credentials {
-    email: EmailAddress,
+    #[serde(flatten)]
+    identifier: LoginIdentifier,
     password: SecretString,
 }
 
+impl std::fmt::Debug for Credentials {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Credentials")
+            .field("identifier", &format_args!("[REDACTED]"))
+            .field("password", &format_args!("[REDACTED]"))
+            .finish()
+    }
+}
+
+impl Zeroize for Credentials {
+    fn zeroize(&mut self) {
+        self.identifier.zeroize();
+        // `Secret` zeroizes it's contents once dropped
+        self.password = Secret::new(String::new());
+    }
+}
+
+impl Drop for Credentials {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for Credentials {}
+
 impl Credentials {
-    /// Create new credentials object
+    /// Create new credentials object, logging in with an email
     pub fn create<'e, 'p>(
         email: impl Into<Cow<'e, str>>,
         password: impl Into<Cow<'p, str>>,
     ) -> Result<Credentials, email_address::Error> {
-        Ok(Credentials {
-            email: EmailAddress::from_str(&email.into())?,
+        Ok(Self::with_identifier(
+            LoginIdentifier::Email(EmailAddress::from_str(&email.into())?),
+            password,
+        ))
+    }
+
+    /// Same as [`Credentials::create`], but logging in with a username
+    pub fn create_with_username<'p>(
+        username: UserName,
+        password: impl Into<Cow<'p, str>>,
+    ) -> Credentials {
+        Self::with_identifier(LoginIdentifier::Username(username), password)
+    }
+
+    /// Create new credentials object, with any kind of login identifier
+    pub fn with_identifier<'p>(
+        identifier: LoginIdentifier,
+        password: impl Into<Cow<'p, str>>,
+    ) -> Credentials {
+        Credentials {
+            identifier,
             password: Secret::new(password.into().to_string()),
-        })
+        }
+    }
+
+    /// Email to log in with, if credentials have one
+    pub fn email(&self) -> Option<&EmailAddress> {
+        match &self.identifier {
+            LoginIdentifier::Email(email) => Some(email),
+            LoginIdentifier::Username(_) => None,
+        }
     }
 }
 
 mod serde_utils {
-    use html_parser::Dom;
-    use serde::{Deserialize, Deserializer};
-    use time::{Duration, OffsetDateTime};
+    use serde::{
+        de::{Unexpected, Visitor},
+        Deserializer, Serializer,
+    };
+    use time::Duration;
 
+    /// Site represents durations (like `readTime`) as a number of seconds, which might be fractional
+    ///
+    /// Negative numbers, infinities and NaN are rejected.
     pub fn duration_from_seconds<'de, D: Deserializer<'de>>(
         deserializer: D,
     ) -> Result<Duration, D::Error> {
-        let integer: i64 = Deserialize::deserialize(deserializer)?;
-        Ok(Duration::seconds(integer))
+        struct SecondsVisitor;
+
+        impl Visitor<'_> for SecondsVisitor {
+            type Value = Duration;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("a non-negative number of seconds")
+            }
+
+            fn visit_u64<E: serde::de::Error>(self, seconds: u64) -> Result<Duration, E> {
+                i64::try_from(seconds)
+                    .map(Duration::seconds)
+                    .map_err(|_| E::invalid_value(Unexpected::Unsigned(seconds), &self))
+            }
+
+            fn visit_i64<E: serde::de::Error>(self, seconds: i64) -> Result<Duration, E> {
+                if seconds < 0 {
+                    return Err(E::invalid_value(Unexpected::Signed(seconds), &self));
+                }
+                Ok(Duration::seconds(seconds))
+            }
+
+            fn visit_f64<E: serde::de::Error>(self, seconds: f64) -> Result<Duration, E> {
+                if !seconds.is_finite() || seconds < 0.0 {
+                    return Err(E::invalid_value(Unexpected::Float(seconds), &self));
+                }
+                Ok(Duration::seconds_f64(seconds))
+            }
+        }
+
+        deserializer.deserialize_any(SecondsVisitor)
     }
 
-    // I have no idea how and why "isLiked" field is represented by a number on a site.
-    // This is weird
-    pub fn flag_from_number<'de, D: Deserializer<'de>>(deserializer: D) -> Result<bool, D::Error> {
-        let num: usize = Deserialize::deserialize(deserializer)?;
-        Ok(num > 0)
+    /// Whole durations are written as integers, as the site does, and the others as floats
+    #[allow(clippy::trivially_copy_pass_by_ref)] // signature is dictated by serde
+    pub fn duration_to_seconds<S: Serializer>(
+        duration: &Duration,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        if duration.subsec_nanoseconds() == 0 {
+            serializer.serialize_i64(duration.whole_seconds())
+        } else {
+            serializer.serialize_f64(duration.as_seconds_f64())
+        }
     }
 
-    pub fn html_from_str<'de, D: ::serde::de::Deserializer<'de>>(
-        deserializer: D,
-    ) -> Result<Dom, D::Error> {
-        use ::serde::de::Error;
-        let s: String = serde::Deserialize::deserialize(deserializer)?; // FIXME &str results in an error, for some reason
-        let s = s.as_str();
-        Dom::parse(s).map_err(|_| {
-            D::Error::invalid_value(::serde::de::Unexpected::Str(s), &"Valid html fragment")
-        })
+    /// Site represents some flags (like `isLiked`) either as a boolean, or as a number, so both are accepted
+    ///
+    /// Nonzero numbers are `true`, and `null` is `false`.
+    pub fn lenient_flag<'de, D: Deserializer<'de>>(deserializer: D) -> Result<bool, D::Error> {
+        struct FlagVisitor;
+
+        impl Visitor<'_> for FlagVisitor {
+            type Value = bool;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("a boolean, a non-negative number or null")
+            }
+
+            fn visit_bool<E: serde::de::Error>(self, flag: bool) -> Result<bool, E> {
+                Ok(flag)
+            }
+
+            fn visit_u64<E: serde::de::Error>(self, num: u64) -> Result<bool, E> {
+                Ok(num > 0)
+            }
+
+            fn visit_unit<E: serde::de::Error>(self) -> Result<bool, E> {
+                Ok(false)
+            }
+
+            fn visit_none<E: serde::de::Error>(self) -> Result<bool, E> {
+                Ok(false)
+            }
+        }
+
+        deserializer.deserialize_any(FlagVisitor)
     }
 
-    #[allow(clippy::unnecessary_wraps)]
-    pub fn optional_iso_time<'de, D: ::serde::de::Deserializer<'de>>(
-        deserializer: D,
-    ) -> Result<Option<OffsetDateTime>, D::Error> {
-        match Option::<OffsetDateTime>::deserialize(deserializer) {
-            Ok(option) => Ok(option),
-            Err(_) => Ok(None), // FIXME I guess, that's not the best practice, definitely should redo that
+    /// Timestamps, as the site emits them
+    ///
+    /// Site is not consistent about these: milliseconds might be omitted, and offset might be either `Z`, `+00:00`,
+    /// or missing altogether (that's UTC then). Formats are tried in order, and error lists all of them.
+    pub mod flexible_iso8601 {
+        use serde::{Deserialize, Deserializer};
+        use time::{
+            format_description::well_known::{Iso8601, Rfc3339},
+            OffsetDateTime, PrimitiveDateTime,
+        };
+
+        /// Names of the formats, in order they are tried
+        const ATTEMPTED: &str = "RFC 3339, ISO 8601, ISO 8601 without offset";
+
+        fn parse(source: &str) -> Option<OffsetDateTime> {
+            OffsetDateTime::parse(source, &Rfc3339)
+                .or_else(|_| OffsetDateTime::parse(source, &Iso8601::DEFAULT))
+                .or_else(|_| {
+                    PrimitiveDateTime::parse(source, &Iso8601::DEFAULT)
+                        .map(|time| time.assume_utc())
+                })
+                .ok()
+        }
+
+        fn invalid<E: serde::de::Error>(source: &str) -> E {
+            E::custom(format!("invalid timestamp {source:?}, tried {ATTEMPTED}"))
+        }
+
+        /// Deserializes a required timestamp
+        pub fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<OffsetDateTime, D::Error> {
+            let source = String::deserialize(deserializer)?;
+            parse(&source).ok_or_else(|| invalid(&source))
+        }
+
+        /// Absent and `null` timestamps are `None`, but present ones should be valid
+        pub mod option {
+            use serde::{Deserialize, Deserializer};
+            use time::OffsetDateTime;
+
+            use super::{invalid, parse};
+
+            /// Deserializes an optional timestamp
+            pub fn deserialize<'de, D: Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Option<OffsetDateTime>, D::Error> {
+                let Some(source) = Option::<String>::deserialize(deserializer)? else {
+                    return Ok(None);
+                };
+                parse(&source).map(Some).ok_or_else(|| invalid(&source))
+            }
         }
     }
 }
@@ -146,7 +555,7 @@ mod serde_utils {
 ///
 /// It turns out, users can specify invalid links in their profiles, so this is my way to remedy this.
 // TODO investigate
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum MaybeUrl {
     /// Valid [`url::Url`]
     Url(Url),
@@ -160,9 +569,65 @@ impl<'de> serde::Deserialize<'de> for MaybeUrl {
         D: serde::Deserializer<'de>,
     {
         let s: String = serde::Deserialize::deserialize(deserializer)?;
-        match s.parse() {
-            Ok(url) => Ok(MaybeUrl::Url(url)),
-            Err(err) => Ok(MaybeUrl::BadUrl(s, err.to_string())),
+        Ok(Self::parse(s))
+    }
+}
+
+impl serde::Serialize for MaybeUrl {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.source())
+    }
+}
+
+impl MaybeUrl {
+    /// Parses url, keeping the source string along with the error if it's not valid
+    pub(crate) fn parse(source: String) -> Self {
+        match source.parse() {
+            Ok(url) => MaybeUrl::Url(url),
+            Err(err) => MaybeUrl::BadUrl(source, err.to_string()),
+        }
+    }
+
+    /// String this url was parsed from (or at least attempted to)
+    pub(crate) fn source(&self) -> &str {
+        match self {
+            MaybeUrl::Url(url) => url.as_str(),
+            MaybeUrl::BadUrl(source, _) => source,
+        }
+    }
+
+    /// Parsed url, if it is valid
+    pub fn as_url(&self) -> Option<&Url> {
+        match self {
+            MaybeUrl::Url(url) => Some(url),
+            MaybeUrl::BadUrl(..) => None,
         }
     }
+
+    /// Parsed url, or source string along with error description for the invalid one
+    ///
+    /// # Errors
+    /// If url is not valid
+    pub fn into_result(self) -> Result<Url, (String, String)> {
+        match self {
+            MaybeUrl::Url(url) => Ok(url),
+            MaybeUrl::BadUrl(source, error) => Err((source, error)),
+        }
+    }
+}
+
+/// Shows the url, or the source string of the invalid one
+impl std::fmt::Display for MaybeUrl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.source())
+    }
+}
+
+impl AsRef<str> for MaybeUrl {
+    fn as_ref(&self) -> &str {
+        self.source()
+    }
 }
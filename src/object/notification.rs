@@ -0,0 +1,83 @@
+use derives::data_type;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+super::id_type! {"notification"}
+
+/// Kind of the notification, as encoded by the numeric `type` field
+///
+/// **Provisional**: site's notification types are undocumented and weren't confirmed yet, so the mapping
+/// of values `1..=5` below is a guess that might change. Anything else is kept as [`Kind::Other`],
+/// so rely on the raw value (`u64::from(kind)`) if exact meaning matters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Kind {
+    /// Someone followed the user
+    NewFollower,
+    /// Someone liked user's article
+    ArticleLiked,
+    /// Someone replied to user's comment
+    CommentReply,
+    /// Someone liked user's comment
+    CommentLiked,
+    /// Someone mentioned the user
+    Mention,
+    /// Kind that is not known yet. Contains raw `type` value
+    Other(u64),
+}
+
+impl From<u64> for Kind {
+    fn from(value: u64) -> Self {
+        match value {
+            1 => Self::NewFollower,
+            2 => Self::ArticleLiked,
+            3 => Self::CommentReply,
+            4 => Self::CommentLiked,
+            5 => Self::Mention,
+            other => Self::Other(other),
+        }
+    }
+}
+
+impl From<Kind> for u64 {
+    fn from(value: Kind) -> Self {
+        match value {
+            Kind::NewFollower => 1,
+            Kind::ArticleLiked => 2,
+            Kind::CommentReply => 3,
+            Kind::CommentLiked => 4,
+            Kind::Mention => 5,
+            Kind::Other(other) => other,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Kind {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        u64::deserialize(deserializer).map(Self::from)
+    }
+}
+
+impl Serialize for Kind {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64((*self).into())
+    }
+}
+
+data_type! {
+    "Notification's kind-specific details",
+    #[lenient]
+    Details,
+    action_owner,
+}
+
+data_type! {
+    "A notification of authorized user",
+    #[lenient]
+    Full,
+    id,
+    owner_id,
+    notification_kind,
+    seen,
+    created_at,
+    notification_details,
+}
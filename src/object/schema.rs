@@ -0,0 +1,37 @@
+use std::{collections::HashMap, sync::Mutex};
+
+use lazy_static::lazy_static;
+
+lazy_static! {
+    /// Last seen schema version (`__v` field) for each object type
+    static ref VERSIONS: Mutex<HashMap<&'static str, usize>> = Mutex::new(HashMap::new());
+}
+
+/// Records schema version of a freshly deserialized object, warning if it differs from the last seen one
+pub(super) fn record(kind: &'static str, version: usize) {
+    let mut versions = VERSIONS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    match versions.insert(kind, version) {
+        Some(previous) if previous != version => {
+            tracing::warn!(
+                kind,
+                previous,
+                version,
+                "Schema version of {kind} has changed, site's API might have changed too"
+            );
+        }
+        _ => {}
+    }
+}
+
+/// Last seen schema version (`__v` field) for each object type that has one
+///
+/// Types are named by their full path, like `type_matrux::object::tag::Popular`.
+/// Intended for diagnostics: schema versions are managed by the site, and might indicate API changes.
+pub fn schema_versions() -> HashMap<&'static str, usize> {
+    VERSIONS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .clone()
+}
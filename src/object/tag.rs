@@ -17,6 +17,35 @@ impl FromStr for Name {
 
 super::str_type! {Slug, "slug", "tag"}
 
+impl Slug {
+    /// Extracts tag's slug from it's url, like `https://drukarnia.com.ua/tags/{slug}`
+    ///
+    /// # Errors
+    /// [`SlugFromUrlError`](super::SlugFromUrlError) if url does not point to a tag at [`DEFAULT_HOST`](super::DEFAULT_HOST)
+    pub fn from_url(url: &url::Url) -> Result<Self, super::SlugFromUrlError> {
+        Self::from_url_at(url, super::DEFAULT_HOST)
+    }
+
+    /// Same as [`Slug::from_url`], but expects url at specified host
+    ///
+    /// # Errors
+    /// [`SlugFromUrlError`](super::SlugFromUrlError) if url does not point to a tag at the host
+    pub fn from_url_at(url: &url::Url, host: &str) -> Result<Self, super::SlugFromUrlError> {
+        let segments = super::url_path_segments(url, host)?;
+        match segments
+            .iter()
+            .map(String::as_str)
+            .collect::<Vec<_>>()
+            .as_slice()
+        {
+            ["tags", slug] => Ok(Self((*slug).to_owned())),
+            _ => Err(super::SlugFromUrlError::UnexpectedPath(
+                url.path().to_owned(),
+            )),
+        }
+    }
+}
+
 impl FromStr for Slug {
     type Err = Infallible;
 
@@ -27,6 +56,7 @@ impl FromStr for Slug {
 }
 
 data_type! {
+    "A tag, as listed in article",
     Article,
     id,
     name,
@@ -40,13 +70,27 @@ data_type! {
 }
 
 data_type! {
+    "A tag, as listed on a user's page",
     User,
     id,
     name,
     slug,
 }
 
+impl super::Project<Vec<Id>> for Vec<Article> {
+    fn project(self) -> Vec<Id> {
+        self.into_iter().map(|tag| tag.id).collect()
+    }
+}
+
+impl super::Project<Vec<Id>> for Vec<User> {
+    fn project(self) -> Vec<Id> {
+        self.into_iter().map(|tag| tag.id).collect()
+    }
+}
+
 data_type! {
+    "A tag, as listed among currently popular tags",
     Popular,
     id,
     name,
@@ -56,11 +100,12 @@ data_type! {
 }
 
 data_type! {
+    "A tag, as returned by it's own endpoint",
     Full,
     id,
     name,
     slug,
     mentions_num,
-    relationships,
+    relationships_opt,
     article_tags,
 }
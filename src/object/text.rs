@@ -0,0 +1,73 @@
+//! Plain text helpers, like previews for feeds and bots
+
+/// Character appended to truncated excerpts
+pub const ELLIPSIS: char = '…';
+
+/// Makes a short preview of a text, at most `max_chars` characters long (including the ellipsis)
+///
+/// Whitespace is collapsed into single spaces. If text does not fit, it is cut at the last word boundary
+/// (or at a character boundary, if the first word is too long already) and [`ELLIPSIS`] is appended.
+/// Combining marks are never separated from the character they modify.
+pub fn excerpt(text: &str, max_chars: usize) -> String {
+    let collapsed = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if collapsed.chars().count() <= max_chars {
+        return collapsed;
+    }
+    if max_chars == 0 {
+        return String::new();
+    }
+
+    // find the longest prefix ending at a grapheme boundary, that leaves space for the ellipsis
+    let mut cut = 0;
+    for (count, (index, _)) in collapsed.char_indices().enumerate() {
+        if count > max_chars - 1 {
+            break;
+        }
+        if is_grapheme_boundary(&collapsed, index) {
+            cut = index;
+        }
+    }
+    let prefix = &collapsed[..cut];
+    // prefer cutting at a word boundary, unless that would leave nothing
+    let prefix = if collapsed[cut..].starts_with(' ') {
+        prefix
+    } else {
+        prefix.rsplit_once(' ').map_or(prefix, |(words, _)| words)
+    };
+    let mut excerpt = prefix
+        .trim_end_matches(|c: char| c.is_whitespace() || c.is_ascii_punctuation())
+        .to_owned();
+    excerpt.push(ELLIPSIS);
+    excerpt
+}
+
+/// Checks if text can be split at the `index` without separating a combining mark from it's base
+///
+/// Index should be a char boundary.
+fn is_grapheme_boundary(text: &str, index: usize) -> bool {
+    text[index..]
+        .chars()
+        .next()
+        .is_none_or(|next| !is_combining(next))
+        && !text[..index].ends_with(ZERO_WIDTH_JOINER)
+}
+
+const ZERO_WIDTH_JOINER: char = '\u{200D}';
+
+/// Approximation of characters that extend previous grapheme: combining marks (including cyrillic ones),
+/// variation selectors and zero width joiner
+fn is_combining(c: char) -> bool {
+    matches!(
+        c,
+        '\u{0300}'..='\u{036F}'
+            | '\u{0483}'..='\u{0489}'
+            | '\u{1AB0}'..='\u{1AFF}'
+            | '\u{1DC0}'..='\u{1DFF}'
+            | '\u{200C}'..='\u{200D}'
+            | '\u{20D0}'..='\u{20FF}'
+            | '\u{FE00}'..='\u{FE0F}'
+            | '\u{FE20}'..='\u{FE2F}'
+            | '\u{1F3FB}'..='\u{1F3FF}'
+            | '\u{E0100}'..='\u{E01EF}'
+    )
+}
@@ -1,9 +1,15 @@
-use std::{collections::HashMap, convert::Infallible, str::FromStr};
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, HashSet},
+    convert::Infallible,
+    str::FromStr,
+};
 
 use derive_more::{AsRef, Into};
 use derives::data_type;
+use email_address::EmailAddress;
 use secrecy::SecretString;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use super::MaybeUrl;
 
@@ -22,6 +28,36 @@ impl FromStr for DisplayName {
 
 super::str_type! {Name, "username", "user"}
 
+impl Name {
+    /// Extracts user's name from it's url, like `https://drukarnia.com.ua/@{username}` or `https://drukarnia.com.ua/users/{username}`
+    ///
+    /// # Errors
+    /// [`SlugFromUrlError`](super::SlugFromUrlError) if url does not point to a user at [`DEFAULT_HOST`](super::DEFAULT_HOST)
+    pub fn from_url(url: &url::Url) -> Result<Self, super::SlugFromUrlError> {
+        Self::from_url_at(url, super::DEFAULT_HOST)
+    }
+
+    /// Same as [`Name::from_url`], but expects url at specified host
+    ///
+    /// # Errors
+    /// [`SlugFromUrlError`](super::SlugFromUrlError) if url does not point to a user at the host
+    pub fn from_url_at(url: &url::Url, host: &str) -> Result<Self, super::SlugFromUrlError> {
+        let segments = super::url_path_segments(url, host)?;
+        match segments
+            .iter()
+            .map(String::as_str)
+            .collect::<Vec<_>>()
+            .as_slice()
+        {
+            ["users", name] => Ok(Self((*name).to_owned())),
+            [name] if name.len() > 1 && name.starts_with('@') => Ok(Self(name[1..].to_owned())),
+            _ => Err(super::SlugFromUrlError::UnexpectedPath(
+                url.path().to_owned(),
+            )),
+        }
+    }
+}
+
 impl FromStr for Name {
     type Err = Infallible;
 
@@ -35,21 +71,273 @@ super::str_type! {ShortDescription, "short description", "user"}
 
 super::str_type! {Description, "description", "user"}
 
+/// Platform of the user's social link
+///
+/// Keys are matched case-insensitively. Unknown keys are kept as-is in [`SocialPlatform::Other`].
+///
+/// Platforms are ordered as listed here, with unknown ones last, ordered by their keys.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum SocialPlatform {
+    /// Telegram
+    Telegram,
+    /// Instagram
+    Instagram,
+    /// Facebook
+    Facebook,
+    /// Twitter
+    Twitter,
+    /// YouTube
+    Youtube,
+    /// TikTok
+    Tiktok,
+    /// Personal site
+    Site,
+    /// Platform that is not known yet. Contains the original key
+    Other(String),
+}
+
+impl SocialPlatform {
+    /// Key this platform is represented with on the site
+    pub fn key(&self) -> &str {
+        match self {
+            Self::Telegram => "telegram",
+            Self::Instagram => "instagram",
+            Self::Facebook => "facebook",
+            Self::Twitter => "twitter",
+            Self::Youtube => "youtube",
+            Self::Tiktok => "tiktok",
+            Self::Site => "site",
+            Self::Other(key) => key,
+        }
+    }
+}
+
+impl From<&str> for SocialPlatform {
+    fn from(key: &str) -> Self {
+        match key.to_lowercase().as_str() {
+            "telegram" => Self::Telegram,
+            "instagram" => Self::Instagram,
+            "facebook" => Self::Facebook,
+            "twitter" => Self::Twitter,
+            "youtube" => Self::Youtube,
+            "tiktok" => Self::Tiktok,
+            "site" => Self::Site,
+            _ => Self::Other(key.to_owned()),
+        }
+    }
+}
+
+impl From<&SocialPlatform> for SocialPlatform {
+    fn from(platform: &SocialPlatform) -> Self {
+        platform.clone()
+    }
+}
+
+impl FromStr for SocialPlatform {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(s.into())
+    }
+}
+
+impl std::fmt::Display for SocialPlatform {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.key())
+    }
+}
+
+impl<'de> Deserialize<'de> for SocialPlatform {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let key: Cow<'de, str> = Deserialize::deserialize(deserializer)?;
+        Ok(key.as_ref().into())
+    }
+}
+
+impl Serialize for SocialPlatform {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.key())
+    }
+}
+
 /// User's social links, like telegram and facebook
-#[derive(Debug, Into, AsRef, Deserialize, Default, Clone)]
+///
+/// Links are kept in [`SocialPlatform`] order, so iterating and serializing them is deterministic.
+///
+/// Keys are case-insensitive, so if the site sends the same platform twice (like `Telegram` and `telegram`),
+/// the first link is kept.
+#[derive(Debug, Into, AsRef, Serialize, Default, Clone, PartialEq, Eq)]
 #[serde(transparent)]
-pub struct Socials(HashMap<String, MaybeUrl>);
+pub struct Socials(BTreeMap<SocialPlatform, MaybeUrl>);
+
+impl<'de> Deserialize<'de> for Socials {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        /// Collects links, skipping keys that repeat an earlier one case-insensitively
+        struct SocialsVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for SocialsVisitor {
+            type Value = Socials;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("a map of social links")
+            }
+
+            fn visit_map<A: serde::de::MapAccess<'de>>(
+                self,
+                mut map: A,
+            ) -> Result<Self::Value, A::Error> {
+                let mut seen = HashSet::new();
+                let mut links = BTreeMap::new();
+                while let Some((platform, url)) = map.next_entry::<SocialPlatform, MaybeUrl>()? {
+                    if seen.insert(platform.key().to_lowercase()) {
+                        links.insert(platform, url);
+                    }
+                }
+                Ok(Socials(links))
+            }
+        }
+
+        deserializer.deserialize_map(SocialsVisitor)
+    }
+}
+
+impl Socials {
+    /// Link for the specified platform, if any
+    ///
+    /// Platform might be specified by it's key, which is matched case-insensitively, unknown platforms included.
+    pub fn get(&self, platform: impl Into<SocialPlatform>) -> Option<&MaybeUrl> {
+        match platform.into() {
+            SocialPlatform::Other(key) => self.others().find_map(|(other, url)| {
+                (other.to_lowercase() == key.to_lowercase()).then_some(url)
+            }),
+            known => self.0.get(&known),
+        }
+    }
+
+    /// All of the links, in [`SocialPlatform`] order
+    pub fn iter(&self) -> impl Iterator<Item = (&SocialPlatform, &MaybeUrl)> {
+        self.0.iter()
+    }
+
+    /// Number of links
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Whether there are no links at all
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Telegram link, if any
+    pub fn telegram(&self) -> Option<&MaybeUrl> {
+        self.get(SocialPlatform::Telegram)
+    }
+
+    /// Instagram link, if any
+    pub fn instagram(&self) -> Option<&MaybeUrl> {
+        self.get(SocialPlatform::Instagram)
+    }
+
+    /// Facebook link, if any
+    pub fn facebook(&self) -> Option<&MaybeUrl> {
+        self.get(SocialPlatform::Facebook)
+    }
+
+    /// Twitter link, if any
+    pub fn twitter(&self) -> Option<&MaybeUrl> {
+        self.get(SocialPlatform::Twitter)
+    }
+
+    /// YouTube link, if any
+    pub fn youtube(&self) -> Option<&MaybeUrl> {
+        self.get(SocialPlatform::Youtube)
+    }
+
+    /// TikTok link, if any
+    pub fn tiktok(&self) -> Option<&MaybeUrl> {
+        self.get(SocialPlatform::Tiktok)
+    }
+
+    /// Personal site link, if any
+    pub fn site(&self) -> Option<&MaybeUrl> {
+        self.get(SocialPlatform::Site)
+    }
+
+    /// Links for the platforms that are not known yet
+    pub fn others(&self) -> impl Iterator<Item = (&str, &MaybeUrl)> {
+        self.0.iter().filter_map(|(platform, url)| match platform {
+            SocialPlatform::Other(key) => Some((key.as_str(), url)),
+            _ => None,
+        })
+    }
+}
+
+/// User's own email, as returned after logging in
+///
+/// Accounts registered through social login might have an empty email, that's represented as `None`.
+///
+/// Address is redacted down to `a***@domain` in `Debug` output, so that it does not end up in logs.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct Email(Option<EmailAddress>);
+
+impl Email {
+    /// The address, if any
+    pub fn address(&self) -> Option<&EmailAddress> {
+        self.0.as_ref()
+    }
+}
+
+impl std::fmt::Debug for Email {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Some(address) = &self.0 else {
+            return f.write_str("None");
+        };
+        let first = address.local_part().chars().next().unwrap_or_default();
+        write!(f, "{first}***@{}", address.domain())
+    }
+}
+
+impl Serialize for Email {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.0.as_ref().map_or("", EmailAddress::as_str))
+    }
+}
+
+impl<'de> Deserialize<'de> for Email {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let email = Cow::<str>::deserialize(deserializer)?;
+        if email.is_empty() {
+            return Ok(Self(None));
+        }
+        EmailAddress::from_str(&email)
+            .map(|address| Self(Some(address)))
+            .map_err(|err| serde::de::Error::custom(format_args!("invalid email address: {err}")))
+    }
+}
 
 data_type! {
+    "A user, as returned by user search",
     Short,
     id,
     username,
     display_name,
     avatar,
-    relationships
+    relationships_opt
 }
 
 data_type! {
+    "A user, as returned by user search without relationships",
+    Plain,
+    id,
+    username,
+    display_name,
+    avatar;
+    from Short
+}
+
+data_type! {
+    "A user, as shown next to their comment",
     Comment,
     id,
     username,
@@ -57,17 +345,79 @@ data_type! {
     avatar
 }
 
+impl super::Project<Id> for Article {
+    fn project(self) -> Id {
+        self.id
+    }
+}
+
+impl super::Project<Id> for Comment {
+    fn project(self) -> Id {
+        self.id
+    }
+}
+
 data_type! {
+    "A user, as listed among someone's followers",
     Follower,
     opt_id,
     avatar,
     opt_username,
     opt_display_name,
     short_description,
-    relationships,
+    relationships_opt,
+}
+
+impl Follower {
+    /// Label used by [`Follower::display_label`] when neither display name nor username is present
+    pub const PLACEHOLDER_LABEL: &'static str = "deleted account";
+
+    /// Name to display this follower with
+    ///
+    /// Falls back to username, and then to [`Follower::PLACEHOLDER_LABEL`].
+    pub fn display_label(&self) -> &str {
+        self.name
+            .as_ref()
+            .map(|name| name.0.as_str())
+            .or_else(|| self.username.as_ref().map(|username| username.0.as_str()))
+            .unwrap_or(Self::PLACEHOLDER_LABEL)
+    }
+
+    /// Whether this follower's account seems to be deleted
+    ///
+    /// That's a heuristic: site returns no identity fields (id, username and display name) for deleted accounts.
+    pub fn is_deleted_account(&self) -> bool {
+        self.id.is_none() && self.username.is_none() && self.name.is_none()
+    }
+
+    /// Converts follower into a [`Short`] user, if all of it's identity fields are present
+    ///
+    /// # Errors
+    /// Returns follower back, if any of id, username or display name is missing
+    #[allow(clippy::result_large_err)] // follower is returned back as-is, so it can still be used
+    pub fn try_into_short_user(self) -> Result<Short, Follower> {
+        let (Some(id), Some(username), Some(name)) =
+            (self.id.clone(), self.username.clone(), self.name.clone())
+        else {
+            return Err(self);
+        };
+        Ok(Short {
+            id,
+            username,
+            name,
+            avatar: self.avatar,
+            relationships: self.relationships,
+            fetched_at: self.fetched_at,
+            #[cfg(feature = "raw-json")]
+            raw: self.raw,
+            #[cfg(feature = "lenient")]
+            extra: self.extra,
+        })
+    }
 }
 
 data_type! {
+    "A user that had logged in",
     Authorized,
     id,
     username,
@@ -94,7 +444,17 @@ data_type! {
     unused___v,
 }
 
+impl Authorized {
+    /// User's email, if account has one
+    ///
+    /// Might be absent for the accounts registered through social login.
+    pub fn email(&self) -> Option<&EmailAddress> {
+        self.email.address()
+    }
+}
+
 data_type! {
+    "A user, as shown as article's author",
     Article,
     id,
     display_name,
@@ -110,13 +470,14 @@ data_type! {
 }
 
 data_type! {
+    "A user, as returned by their own endpoint",
     Full,
     id,
     display_name,
     avatar,
     username,
     short_description,
-    user_description,
+    description(optional),
     following_num,
     followers_num,
     read_num,
@@ -124,6 +485,25 @@ data_type! {
     created_at,
     socials,
     donate_url,
-    relationships,
+    relationships_opt,
     user_articles,
 }
+
+impl Full {
+    /// Downloads user's avatar
+    ///
+    /// # Returns
+    /// `None`, if user has no avatar
+    ///
+    /// # Errors
+    /// Same as [`ArticlesApi::download_image`](crate::client::ArticlesApi::download_image)
+    pub async fn avatar_image(
+        &self,
+        api: &(impl crate::DrukarniaApi + ?Sized),
+    ) -> Result<Option<crate::client::ImageBytes>, crate::client::Error> {
+        match self.avatar.as_ref() {
+            Some(url) => api.download_image(url).await.map(Some),
+            None => Ok(None),
+        }
+    }
+}
@@ -0,0 +1,21 @@
+//! ```no_run
+//! use type_matrux::prelude::*;
+//!
+//! # async fn example() -> Result<(), Error> {
+//! let client = ReqwestApi::new();
+//! let slug: ArticleSlug = "gitlab-istoriya-uspikhu-ukrayinskogo-konkurenta-github-t7agP"
+//!     .parse()
+//!     .expect("Should be a valid slug");
+//! let article: FullArticle = client.get_article(&slug).await?;
+//! let first_feed_article = client.feed().flat().next().await;
+//! # Ok(())
+//! # }
+//! ```
+
+pub use futures::StreamExt;
+
+pub use crate::{
+    client::{ArticlesApi, AuthDrukarnia, BaseApi, CommentsApi, Error, FeedApi, TagsApi, UsersApi},
+    object::{ArticleSlug, FullArticle, FullUser, TagSlug, UserName},
+    DrukarniaApi, DrukarniaClient, ReqwestApi,
+};
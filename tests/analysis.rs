@@ -0,0 +1,151 @@
+//! Offline tests for aggregate analyses, over synthetic articles and checked-in fixtures.
+
+use serde_json::json;
+use type_matrux::{
+    analysis::TagGraph,
+    id_hex,
+    object::{FeedArticle, FullArticle, HasTags, TagId},
+};
+
+mod support;
+
+use support::fixture;
+
+const TECHNOLOGIES: TagId = id_hex!(TagId, "64ff1f2a1272bd9066a1aa01");
+const PROGRAMMING: TagId = id_hex!(TagId, "64ff1f2a1272bd9066a1aa02");
+const GAMES: TagId = id_hex!(TagId, "64ff1f2a1272bd9066a1aa03");
+const MUSIC: TagId = id_hex!(TagId, "64ff1f2a1272bd9066a1aa04");
+
+/// Synthetic article, listing tags the way site does
+struct Tagged {
+    main: TagId,
+    listed: Vec<TagId>,
+}
+
+impl Tagged {
+    fn new(main: TagId, listed: &[TagId]) -> Self {
+        Self {
+            main,
+            listed: listed.to_vec(),
+        }
+    }
+}
+
+impl HasTags for Tagged {
+    fn main_tag_id(&self) -> &TagId {
+        &self.main
+    }
+
+    fn listed_tag_ids(&self) -> Vec<&TagId> {
+        self.listed.iter().collect()
+    }
+}
+
+fn graph_of(articles: &[Tagged]) -> TagGraph {
+    let mut graph = TagGraph::new();
+    for article in articles {
+        graph.ingest(article);
+    }
+    graph
+}
+
+#[test]
+fn tag_ids_should_start_with_main_tag_and_skip_repetitions() {
+    // Arrange
+    let article = Tagged::new(PROGRAMMING, &[TECHNOLOGIES, PROGRAMMING, TECHNOLOGIES]);
+
+    // Act
+    let ids = article.tag_ids();
+
+    // Assert
+    assert_eq!(ids, [&PROGRAMMING, &TECHNOLOGIES]);
+}
+
+#[test]
+fn co_occurrences_should_count_shared_articles() {
+    // Arrange
+    let graph = graph_of(&[
+        Tagged::new(TECHNOLOGIES, &[TECHNOLOGIES, PROGRAMMING]),
+        Tagged::new(TECHNOLOGIES, &[TECHNOLOGIES, PROGRAMMING, GAMES]),
+        Tagged::new(GAMES, &[GAMES, TECHNOLOGIES]),
+        Tagged::new(MUSIC, &[MUSIC]),
+    ]);
+
+    // Act
+    let technologies = graph.co_occurrences(&TECHNOLOGIES);
+    let music = graph.co_occurrences(&MUSIC);
+
+    // Assert
+    assert_eq!(graph.articles(), 4);
+    assert_eq!(technologies, [(PROGRAMMING, 2), (GAMES, 2)]);
+    assert!(music.is_empty());
+}
+
+#[test]
+fn top_pairs_should_order_by_count_then_ids() {
+    // Arrange
+    let graph = graph_of(&[
+        Tagged::new(MUSIC, &[GAMES]),
+        Tagged::new(GAMES, &[MUSIC, PROGRAMMING]),
+        Tagged::new(PROGRAMMING, &[TECHNOLOGIES]),
+        Tagged::new(PROGRAMMING, &[GAMES]),
+    ]);
+
+    // Act
+    let top = graph.top_pairs(3);
+
+    // Assert
+    assert_eq!(
+        top,
+        [
+            (PROGRAMMING, GAMES, 2),
+            (GAMES, MUSIC, 2),
+            (TECHNOLOGIES, PROGRAMMING, 1)
+        ]
+    );
+}
+
+#[test]
+fn graph_should_serialize_all_pairs() {
+    // Arrange
+    let graph = graph_of(&[
+        Tagged::new(PROGRAMMING, &[TECHNOLOGIES]),
+        Tagged::new(TECHNOLOGIES, &[PROGRAMMING, GAMES]),
+    ]);
+
+    // Act
+    let serialized = serde_json::to_value(&graph).expect("Graph should serialize");
+
+    // Assert
+    assert_eq!(
+        serialized,
+        json!({
+            "articles": 2,
+            "pairs": [
+                {"tags": ["64ff1f2a1272bd9066a1aa01", "64ff1f2a1272bd9066a1aa02"], "count": 2},
+                {"tags": ["64ff1f2a1272bd9066a1aa01", "64ff1f2a1272bd9066a1aa03"], "count": 1},
+                {"tags": ["64ff1f2a1272bd9066a1aa02", "64ff1f2a1272bd9066a1aa03"], "count": 1},
+            ],
+        })
+    );
+}
+
+#[test]
+fn fetched_articles_should_expose_tags() {
+    // Arrange
+    let feed: Vec<FeedArticle> = fixture("feed/page.json");
+    let full: FullArticle = fixture("full_article.json");
+    let mut graph = TagGraph::new();
+
+    // Act
+    for article in &feed {
+        graph.ingest(article);
+    }
+    graph.ingest(&full);
+
+    // Assert
+    assert_eq!(feed[0].tag_ids(), [&TECHNOLOGIES]);
+    assert_eq!(full.tag_ids(), [&TECHNOLOGIES, &PROGRAMMING]);
+    assert_eq!(graph.articles(), feed.len() + 1);
+    assert_eq!(graph.top_pairs(10), [(TECHNOLOGIES, PROGRAMMING, 1)]);
+}
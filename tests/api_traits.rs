@@ -0,0 +1,305 @@
+//! Offline tests for the per-domain accessor traits, and [`DrukarniaApi`] umbrella over them.
+
+use std::{
+    num::NonZeroUsize,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use async_trait::async_trait;
+use futures::StreamExt;
+use type_matrux::{
+    client::{
+        ArticlesApi, BaseApi, CachedDrukarnia, CommentsApi, Error, FeedApi, ImageBytes, Page,
+        TagsApi, UsersApi,
+    },
+    object::{
+        ArticleSlug, ArticleTitle, CommentId, FeedArticle, FollowerUser, FullArticle, FullTag,
+        FullUser, MaybeUrl, PopularTag, RecommendedArticle, ReplyComment, ShortUser, TagSlug,
+        UserId, UserName,
+    },
+    DrukarniaApi, DrukarniaClient, ReqwestApi,
+};
+
+mod support;
+
+use support::fixture;
+
+fn slug(slug: &str) -> ArticleSlug {
+    slug.parse().expect("Should be a valid slug")
+}
+
+/// Knows nothing but articles
+#[derive(Default)]
+struct ArticlesOnly {
+    articles: AtomicUsize,
+    search_pages: AtomicUsize,
+}
+
+#[async_trait]
+impl BaseApi for ArticlesOnly {
+    type Auth = ();
+}
+
+#[async_trait]
+impl ArticlesApi for ArticlesOnly {
+    async fn get_article(&self, _slug: &ArticleSlug) -> Result<FullArticle, Error> {
+        self.articles.fetch_add(1, Ordering::SeqCst);
+        Ok(fixture("full_article.json"))
+    }
+
+    async fn search_article_page(
+        &self,
+        _name: &ArticleTitle,
+        page: NonZeroUsize,
+    ) -> Result<Vec<RecommendedArticle>, Error> {
+        self.search_pages.fetch_add(1, Ordering::SeqCst);
+        if page.get() == 1 {
+            Ok(fixture("search/recommended_page.json"))
+        } else {
+            Ok(vec![])
+        }
+    }
+
+    async fn download_image(&self, _url: &MaybeUrl) -> Result<ImageBytes, Error> {
+        Err(Error::NoObject)
+    }
+}
+
+/// An example of library code, that only needs articles
+async fn titles<A: ArticlesApi + Sync>(api: &A, slugs: &[ArticleSlug]) -> Vec<String> {
+    api.get_articles(slugs, 2)
+        .await
+        .into_iter()
+        .filter_map(|(_, article)| article.ok())
+        .map(|article| article.title().as_ref().to_owned())
+        .collect()
+}
+
+#[tokio::test]
+async fn articles_only_accessor_should_fetch_articles() {
+    // Arrange
+    let api = ArticlesOnly::default();
+    let slugs = [slug("first"), slug("second")];
+
+    // Act
+    let titles = titles(&api, &slugs).await;
+    let url = api
+        .base_url()
+        .join("/articles/some-article")
+        .expect("Should be a valid url");
+    let by_url = api.get_article_by_url(&url).await;
+
+    // Assert
+    assert_eq!(titles.len(), 2);
+    assert!(by_url.is_ok());
+    assert_eq!(api.articles.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn articles_only_accessor_should_stream_search() {
+    // Arrange
+    let api = ArticlesOnly::default();
+    let title: ArticleTitle = serde_json::from_str("\"Git\"").expect("Should be a valid title");
+
+    // Act
+    let found: Vec<_> = api.search_article(title).flat().collect().await;
+
+    // Assert
+    assert!(!found.is_empty());
+    assert!(found.iter().all(Result::is_ok));
+    // the empty one ends the stream
+    assert_eq!(api.search_pages.load(Ordering::SeqCst), 2);
+}
+
+/// Same as [`ArticlesOnly`], but knows the page size
+#[derive(Default)]
+struct SizedArticles(ArticlesOnly);
+
+#[async_trait]
+impl BaseApi for SizedArticles {
+    type Auth = ();
+}
+
+#[async_trait]
+impl ArticlesApi for SizedArticles {
+    async fn get_article(&self, slug: &ArticleSlug) -> Result<FullArticle, Error> {
+        self.0.get_article(slug).await
+    }
+
+    async fn search_article_page(
+        &self,
+        name: &ArticleTitle,
+        page: NonZeroUsize,
+    ) -> Result<Vec<RecommendedArticle>, Error> {
+        self.0.search_article_page(name, page).await
+    }
+
+    async fn search_article_page_meta(
+        &self,
+        name: &ArticleTitle,
+        page: NonZeroUsize,
+    ) -> Result<Page<RecommendedArticle>, Error> {
+        let articles = self.search_article_page(name, page).await?;
+        Ok(Page::with_page_size(articles, page, 10))
+    }
+
+    async fn download_image(&self, url: &MaybeUrl) -> Result<ImageBytes, Error> {
+        self.0.download_image(url).await
+    }
+}
+
+#[tokio::test]
+async fn known_last_page_should_save_a_request() {
+    // Arrange
+    let unsized_api = ArticlesOnly::default();
+    let sized_api = SizedArticles::default();
+    let title: ArticleTitle = serde_json::from_str("\"Git\"").expect("Should be a valid title");
+
+    // Act
+    let unsized_found: Vec<_> = unsized_api
+        .search_article(title.clone())
+        .flat()
+        .collect()
+        .await;
+    let sized_found: Vec<_> = sized_api.search_article(title).flat().collect().await;
+
+    // Assert
+    assert_eq!(unsized_found.len(), sized_found.len());
+    assert_eq!(unsized_api.search_pages.load(Ordering::SeqCst), 2);
+    // fixture page is shorter than 10, so it's known to be the last one
+    assert_eq!(sized_api.0.search_pages.load(Ordering::SeqCst), 1);
+}
+
+/// Articles from [`ArticlesOnly`], along with replies, and nothing else
+#[derive(Default)]
+struct FullStub {
+    articles: ArticlesOnly,
+    replies: AtomicUsize,
+}
+
+#[async_trait]
+impl BaseApi for FullStub {
+    type Auth = ();
+}
+
+#[async_trait]
+impl ArticlesApi for FullStub {
+    async fn get_article(&self, slug: &ArticleSlug) -> Result<FullArticle, Error> {
+        self.articles.get_article(slug).await
+    }
+
+    async fn search_article_page(
+        &self,
+        name: &ArticleTitle,
+        page: NonZeroUsize,
+    ) -> Result<Vec<RecommendedArticle>, Error> {
+        self.articles.search_article_page(name, page).await
+    }
+
+    async fn download_image(&self, url: &MaybeUrl) -> Result<ImageBytes, Error> {
+        self.articles.download_image(url).await
+    }
+}
+
+#[async_trait]
+impl CommentsApi for FullStub {
+    async fn get_replies(&self, _comment: &CommentId) -> Result<Vec<ReplyComment>, Error> {
+        self.replies.fetch_add(1, Ordering::SeqCst);
+        Ok(fixture("comments/replies.json"))
+    }
+}
+
+#[async_trait]
+impl UsersApi for FullStub {
+    async fn get_user(&self, _name: &UserName) -> Result<FullUser, Error> {
+        unimplemented!()
+    }
+
+    async fn search_user_page(
+        &self,
+        _name: &UserName,
+        _page: NonZeroUsize,
+    ) -> Result<Vec<ShortUser>, Error> {
+        unimplemented!()
+    }
+
+    async fn get_followers_page(
+        &self,
+        _id: &UserId,
+        _page: NonZeroUsize,
+    ) -> Result<Vec<FollowerUser>, Error> {
+        unimplemented!()
+    }
+}
+
+#[async_trait]
+impl TagsApi for FullStub {
+    async fn popular_tags(&self) -> Result<Vec<PopularTag>, Error> {
+        unimplemented!()
+    }
+
+    async fn get_tag(&self, _slug: &TagSlug) -> Result<FullTag, Error> {
+        unimplemented!()
+    }
+}
+
+#[async_trait]
+impl FeedApi for FullStub {
+    async fn feed_page(&self, _page: NonZeroUsize) -> Result<Vec<FeedArticle>, Error> {
+        unimplemented!()
+    }
+}
+
+fn assert_umbrella<A: DrukarniaApi>() {}
+
+#[test]
+fn all_accessors_should_implement_umbrella() {
+    assert_umbrella::<ReqwestApi>();
+    assert_umbrella::<DrukarniaClient>();
+    assert_umbrella::<CachedDrukarnia<ReqwestApi>>();
+    assert_umbrella::<FullStub>();
+}
+
+#[tokio::test]
+async fn umbrella_methods_should_combine_sub_traits() {
+    // Arrange
+    let api = FullStub::default();
+
+    // Act
+    let (_, thread) = api
+        .get_article_thread(&slug("some-article"))
+        .await
+        .expect("Should fetch thread");
+
+    // Assert
+    // only one of the fixture comments has replies
+    assert_eq!(api.replies.load(Ordering::SeqCst), 1);
+    assert_eq!(thread.roots().len(), 2);
+    assert_eq!(thread.roots()[0].reply_count(), 3);
+}
+
+#[tokio::test]
+async fn umbrella_should_be_usable_as_a_bound() {
+    async fn thread_size<A: DrukarniaApi + Sync>(api: &A) -> usize {
+        // sub-trait methods are available through the umbrella bound
+        let article = api
+            .get_article(&slug("some-article"))
+            .await
+            .expect("Should fetch article");
+        let with_replies = api
+            .get_article_with_replies(article.slug(), 4)
+            .await
+            .expect("Should fetch article with replies");
+        with_replies.thread().roots().len()
+    }
+
+    // Arrange
+    let api = FullStub::default();
+
+    // Act
+    let size = thread_size(&api).await;
+
+    // Assert
+    assert_eq!(size, 2);
+    assert_eq!(api.articles.articles.load(Ordering::SeqCst), 2);
+}
@@ -0,0 +1,270 @@
+//! Offline tests for automatic re-login.
+//!
+//! Stub accessor hands out sessions that expire after the first one, counting the logins.
+
+use std::{
+    num::NonZeroUsize,
+    ops::Deref,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use async_trait::async_trait;
+use type_matrux::{
+    client::{
+        ArticlesApi, AuthDrukarnia, AutoAuth, BaseApi, CommentsApi, Error, FeedApi, ImageBytes,
+        LoginOptions, TagsApi, UsersApi,
+    },
+    object::{
+        ArticleId, ArticleSlug, ArticleTitle, AuthorizedUser, CommentId, CommentLikeState,
+        Credentials, FeedArticle, FollowerUser, FullArticle, FullBookmark, FullList, FullTag,
+        FullUser, LikeAmount, ListArticle, ListId, MaybeUrl, PopularTag, RecommendedArticle,
+        ReplyComment, ShortUser, TagSlug, UserId, UserName,
+    },
+};
+
+mod support;
+
+use support::fixture;
+
+/// Logs in without reaching the site, counting the logins
+struct StubApi {
+    logins: AtomicUsize,
+    /// Sessions starting from this one stay valid
+    valid_from: usize,
+}
+
+impl StubApi {
+    fn new(valid_from: usize) -> Self {
+        Self {
+            logins: AtomicUsize::new(0),
+            valid_from,
+        }
+    }
+}
+
+/// Session, that is expired, unless it's login number is high enough
+struct StubAuth {
+    user: AuthorizedUser,
+    expired: bool,
+}
+
+impl Deref for StubAuth {
+    type Target = ();
+
+    fn deref(&self) -> &Self::Target {
+        &()
+    }
+}
+
+#[async_trait]
+impl BaseApi for StubApi {
+    type Auth = StubAuth;
+
+    async fn login(&self, _credentials: Credentials) -> Result<StubAuth, Error> {
+        let login = self.logins.fetch_add(1, Ordering::SeqCst);
+        let user = fixture("users/authorized.json");
+        Ok(StubAuth {
+            user,
+            expired: login < self.valid_from,
+        })
+    }
+}
+
+#[async_trait]
+impl UsersApi for StubApi {
+    async fn get_user(&self, _name: &UserName) -> Result<FullUser, Error> {
+        unimplemented!()
+    }
+
+    async fn search_user_page(
+        &self,
+        _name: &UserName,
+        _page: NonZeroUsize,
+    ) -> Result<Vec<ShortUser>, Error> {
+        unimplemented!()
+    }
+
+    async fn get_followers_page(
+        &self,
+        _id: &UserId,
+        _page: NonZeroUsize,
+    ) -> Result<Vec<FollowerUser>, Error> {
+        unimplemented!()
+    }
+}
+
+#[async_trait]
+impl ArticlesApi for StubApi {
+    async fn get_article(&self, _slug: &ArticleSlug) -> Result<FullArticle, Error> {
+        unimplemented!()
+    }
+
+    async fn search_article_page(
+        &self,
+        _name: &ArticleTitle,
+        _page: NonZeroUsize,
+    ) -> Result<Vec<RecommendedArticle>, Error> {
+        unimplemented!()
+    }
+
+    async fn download_image(&self, _url: &MaybeUrl) -> Result<ImageBytes, Error> {
+        unimplemented!()
+    }
+}
+
+#[async_trait]
+impl TagsApi for StubApi {
+    async fn popular_tags(&self) -> Result<Vec<PopularTag>, Error> {
+        unimplemented!()
+    }
+
+    async fn get_tag(&self, _slug: &TagSlug) -> Result<FullTag, Error> {
+        unimplemented!()
+    }
+}
+
+#[async_trait]
+impl CommentsApi for StubApi {
+    async fn get_replies(&self, _comment: &CommentId) -> Result<Vec<ReplyComment>, Error> {
+        unimplemented!()
+    }
+}
+
+#[async_trait]
+impl FeedApi for StubApi {
+    async fn feed_page(&self, _page: NonZeroUsize) -> Result<Vec<FeedArticle>, Error> {
+        unimplemented!()
+    }
+}
+
+#[async_trait]
+impl AuthDrukarnia for StubAuth {
+    type Downgrade = ();
+
+    fn authorized_user(&self) -> &AuthorizedUser {
+        &self.user
+    }
+
+    async fn user_set_following(&self, _id: &UserId, _follow: bool) -> Result<(), Error> {
+        unimplemented!()
+    }
+
+    async fn get_bookmark_lists(&self) -> Result<Vec<FullList>, Error> {
+        // give concurrent calls a chance to hit the same session
+        tokio::task::yield_now().await;
+        if self.expired {
+            Err(Error::SessionExpired)
+        } else {
+            Ok(Vec::new())
+        }
+    }
+
+    async fn bookmark_article(
+        &self,
+        _list: &ListId,
+        _article: &ArticleId,
+    ) -> Result<FullBookmark, Error> {
+        unimplemented!()
+    }
+
+    async fn unbookmark_article(&self, _article: &ArticleId) -> Result<FullBookmark, Error> {
+        unimplemented!()
+    }
+
+    async fn get_list_articles(&self, _list: &ListId) -> Result<Vec<ListArticle>, Error> {
+        unimplemented!()
+    }
+
+    async fn like_article(&self, _article: &ArticleId, _amount: LikeAmount) -> Result<(), Error> {
+        unimplemented!()
+    }
+
+    async fn set_comment_liked(
+        &self,
+        _article: &ArticleId,
+        _comment: &CommentId,
+        _liked: bool,
+    ) -> Result<CommentLikeState, Error> {
+        unimplemented!()
+    }
+}
+
+fn credentials() -> Credentials {
+    Credentials::create("user@example.com", "password").expect("Should be a valid email")
+}
+
+async fn bookmark_lists(auth: &AutoAuth<StubApi>) -> Result<Vec<FullList>, Error> {
+    auth.call(|auth| auth.get_bookmark_lists()).await
+}
+
+#[tokio::test]
+async fn valid_session_is_not_renewed() {
+    let auth = AutoAuth::login(StubApi::new(0), credentials())
+        .await
+        .expect("Should log in");
+
+    bookmark_lists(&auth).await.expect("Should succeed");
+
+    assert_eq!(auth.api().logins.load(Ordering::SeqCst), 1);
+    assert_eq!(auth.relogins().await, 0);
+}
+
+#[tokio::test]
+async fn expired_session_is_renewed_once() {
+    let auth = AutoAuth::login(StubApi::new(1), credentials())
+        .await
+        .expect("Should log in");
+
+    bookmark_lists(&auth)
+        .await
+        .expect("Should succeed after re-login");
+    bookmark_lists(&auth)
+        .await
+        .expect("Should reuse renewed session");
+
+    assert_eq!(auth.api().logins.load(Ordering::SeqCst), 2);
+    assert_eq!(auth.relogins().await, 1);
+}
+
+#[tokio::test]
+async fn concurrent_calls_share_relogin() {
+    let auth = AutoAuth::login(StubApi::new(1), credentials())
+        .await
+        .expect("Should log in");
+
+    let (first, second) = futures::join!(bookmark_lists(&auth), bookmark_lists(&auth));
+    first.expect("First call should succeed after re-login");
+    second.expect("Second call should succeed after re-login");
+
+    assert_eq!(auth.api().logins.load(Ordering::SeqCst), 2);
+    assert_eq!(auth.relogins().await, 1);
+}
+
+#[tokio::test]
+async fn relogin_is_not_looped() {
+    let auth = AutoAuth::login(StubApi::new(usize::MAX), credentials())
+        .await
+        .expect("Should log in");
+
+    let result = bookmark_lists(&auth).await;
+
+    assert!(
+        matches!(result, Err(Error::SessionExpired)),
+        "Should give up after a single re-login, got {result:?}"
+    );
+    assert_eq!(auth.api().logins.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn login_with_is_unsupported_unless_implemented() {
+    let api = StubApi::new(0);
+
+    let result = api.login_with(credentials(), LoginOptions::default()).await;
+
+    assert!(
+        matches!(result, Err(Error::Unsupported("login"))),
+        "Should report unsupported login, got {:?}",
+        result.map(|auth| auth.user)
+    );
+    assert_eq!(api.logins.load(Ordering::SeqCst), 0);
+}
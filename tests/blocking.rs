@@ -0,0 +1,168 @@
+//! Offline tests for the blocking accessor.
+//!
+//! Stub accessor serves a few feed pages, optionally failing at some page, and counts the requests.
+#![cfg(feature = "blocking")]
+
+use std::{
+    num::NonZeroUsize,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use async_trait::async_trait;
+use type_matrux::{
+    blocking::BlockingDrukarnia,
+    client::{ArticlesApi, BaseApi, CommentsApi, Error, FeedApi, ImageBytes, TagsApi, UsersApi},
+    object::{
+        ArticleSlug, ArticleTitle, CommentId, FeedArticle, FollowerUser, FullArticle, FullTag,
+        FullUser, MaybeUrl, PopularTag, RecommendedArticle, ReplyComment, ShortUser, TagSlug,
+        UserId, UserName,
+    },
+};
+
+mod support;
+
+use support::fixture;
+
+/// Serves `pages` non-empty feed pages of two articles each, followed by an empty one
+struct StubApi {
+    pages: usize,
+    fail_at: Option<usize>,
+    requests: AtomicUsize,
+}
+
+impl StubApi {
+    fn new(pages: usize, fail_at: Option<usize>) -> Self {
+        Self {
+            pages,
+            fail_at,
+            requests: AtomicUsize::new(0),
+        }
+    }
+}
+
+fn feed_article() -> FeedArticle {
+    fixture("drift/feed_article.json")
+}
+
+#[async_trait]
+impl BaseApi for StubApi {
+    type Auth = ();
+}
+
+#[async_trait]
+impl UsersApi for StubApi {
+    async fn get_user(&self, _name: &UserName) -> Result<FullUser, Error> {
+        unimplemented!()
+    }
+
+    async fn search_user_page(
+        &self,
+        _name: &UserName,
+        _page: NonZeroUsize,
+    ) -> Result<Vec<ShortUser>, Error> {
+        unimplemented!()
+    }
+
+    async fn get_followers_page(
+        &self,
+        _id: &UserId,
+        _page: NonZeroUsize,
+    ) -> Result<Vec<FollowerUser>, Error> {
+        unimplemented!()
+    }
+}
+
+#[async_trait]
+impl ArticlesApi for StubApi {
+    async fn get_article(&self, _slug: &ArticleSlug) -> Result<FullArticle, Error> {
+        unimplemented!()
+    }
+
+    async fn search_article_page(
+        &self,
+        _name: &ArticleTitle,
+        _page: NonZeroUsize,
+    ) -> Result<Vec<RecommendedArticle>, Error> {
+        unimplemented!()
+    }
+
+    async fn download_image(&self, _url: &MaybeUrl) -> Result<ImageBytes, Error> {
+        unimplemented!()
+    }
+}
+
+#[async_trait]
+impl TagsApi for StubApi {
+    async fn popular_tags(&self) -> Result<Vec<PopularTag>, Error> {
+        unimplemented!()
+    }
+
+    async fn get_tag(&self, _slug: &TagSlug) -> Result<FullTag, Error> {
+        unimplemented!()
+    }
+}
+
+#[async_trait]
+impl CommentsApi for StubApi {
+    async fn get_replies(&self, _comment: &CommentId) -> Result<Vec<ReplyComment>, Error> {
+        unimplemented!()
+    }
+}
+
+#[async_trait]
+impl FeedApi for StubApi {
+    async fn feed_page(&self, page: NonZeroUsize) -> Result<Vec<FeedArticle>, Error> {
+        self.requests.fetch_add(1, Ordering::SeqCst);
+        if self.fail_at == Some(page.get()) {
+            return Err(Error::NoObject);
+        }
+        if page.get() > self.pages {
+            return Ok(Vec::new());
+        }
+        Ok(vec![feed_article(), feed_article()])
+    }
+}
+
+#[test]
+fn feed_page_should_block_on_api() {
+    let client = BlockingDrukarnia::with_api(StubApi::new(1, None)).expect("Should start");
+
+    let page = client.feed_page(NonZeroUsize::new(1).expect("1 != 0"));
+
+    assert_eq!(page.expect("Should get a page").len(), 2);
+}
+
+#[test]
+fn feed_should_end_on_empty_page() {
+    let client = BlockingDrukarnia::with_api(StubApi::new(3, None)).expect("Should start");
+
+    let pages: Vec<_> = client.feed().collect();
+
+    assert_eq!(pages.len(), 3);
+    assert!(pages.iter().all(Result::is_ok));
+    // three pages and an empty one
+    assert_eq!(client.api().requests.load(Ordering::SeqCst), 4);
+}
+
+#[test]
+fn feed_should_stop_after_error() {
+    let client = BlockingDrukarnia::with_api(StubApi::new(5, Some(2))).expect("Should start");
+
+    let mut pages = client.feed();
+
+    assert!(matches!(pages.next(), Some(Ok(_))));
+    assert!(matches!(pages.next(), Some(Err(Error::NoObject))));
+    assert!(pages.next().is_none());
+    assert_eq!(client.api().requests.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn flat_feed_should_yield_articles() {
+    let client = BlockingDrukarnia::with_api(StubApi::new(2, Some(3))).expect("Should start");
+
+    let articles: Vec<_> = client.feed().flat().collect();
+
+    assert_eq!(articles.len(), 5);
+    assert!(articles[..4].iter().all(Result::is_ok));
+    assert!(matches!(articles[4], Err(Error::NoObject)));
+}
@@ -0,0 +1,184 @@
+//! Offline tests for the bulk fetch helpers.
+//!
+//! Stub accessor takes longer for the earlier objects, so that requests complete out of order, and tracks how many of them run at once.
+
+use std::{
+    num::NonZeroUsize,
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+use type_matrux::{
+    client::{ArticlesApi, BaseApi, CommentsApi, Error, FeedApi, ImageBytes, TagsApi, UsersApi},
+    object::{
+        ArticleSlug, ArticleTitle, CommentId, FeedArticle, FollowerUser, FullArticle, FullTag,
+        FullUser, MaybeUrl, PopularTag, RecommendedArticle, ReplyComment, ShortUser, TagSlug,
+        UserId, UserName,
+    },
+};
+
+mod support;
+
+use support::fixture;
+
+/// Serves fixtures for keys like `{number}`, and fails for keys like `missing-{number}`.
+/// Objects with larger numbers are served faster
+#[derive(Default)]
+struct StubApi {
+    running: AtomicUsize,
+    max_running: AtomicUsize,
+}
+
+impl StubApi {
+    async fn serve<T: DeserializeOwned>(&self, key: &str, fixture_name: &str) -> Result<T, Error> {
+        let running = self.running.fetch_add(1, Ordering::SeqCst) + 1;
+        self.max_running.fetch_max(running, Ordering::SeqCst);
+        let (missing, number) = match key.strip_prefix("missing-") {
+            Some(number) => (true, number),
+            None => (false, key),
+        };
+        let number: u64 = number.parse().expect("Key should end with a number");
+        tokio::time::sleep(Duration::from_millis(50_u64.saturating_sub(number * 5))).await;
+        self.running.fetch_sub(1, Ordering::SeqCst);
+        if missing {
+            Err(Error::NoObject)
+        } else {
+            Ok(fixture(fixture_name))
+        }
+    }
+}
+
+#[async_trait]
+impl BaseApi for StubApi {
+    type Auth = ();
+}
+
+#[async_trait]
+impl UsersApi for StubApi {
+    async fn get_user(&self, name: &UserName) -> Result<FullUser, Error> {
+        self.serve(name.as_ref(), "full_user.json").await
+    }
+
+    async fn search_user_page(
+        &self,
+        _name: &UserName,
+        _page: NonZeroUsize,
+    ) -> Result<Vec<ShortUser>, Error> {
+        unimplemented!()
+    }
+
+    async fn get_followers_page(
+        &self,
+        _id: &UserId,
+        _page: NonZeroUsize,
+    ) -> Result<Vec<FollowerUser>, Error> {
+        unimplemented!()
+    }
+}
+
+#[async_trait]
+impl ArticlesApi for StubApi {
+    async fn get_article(&self, slug: &ArticleSlug) -> Result<FullArticle, Error> {
+        self.serve(slug.as_ref(), "full_article.json").await
+    }
+
+    async fn search_article_page(
+        &self,
+        _name: &ArticleTitle,
+        _page: NonZeroUsize,
+    ) -> Result<Vec<RecommendedArticle>, Error> {
+        unimplemented!()
+    }
+
+    async fn download_image(&self, _url: &MaybeUrl) -> Result<ImageBytes, Error> {
+        unimplemented!()
+    }
+}
+
+#[async_trait]
+impl TagsApi for StubApi {
+    async fn popular_tags(&self) -> Result<Vec<PopularTag>, Error> {
+        unimplemented!()
+    }
+
+    async fn get_tag(&self, slug: &TagSlug) -> Result<FullTag, Error> {
+        self.serve(slug.as_ref(), "tags/full_tag.json").await
+    }
+}
+
+#[async_trait]
+impl CommentsApi for StubApi {
+    async fn get_replies(&self, _comment: &CommentId) -> Result<Vec<ReplyComment>, Error> {
+        unimplemented!()
+    }
+}
+
+#[async_trait]
+impl FeedApi for StubApi {
+    async fn feed_page(&self, _page: NonZeroUsize) -> Result<Vec<FeedArticle>, Error> {
+        unimplemented!()
+    }
+}
+
+fn keys<K: std::str::FromStr>(raw: &[&str]) -> Vec<K>
+where
+    K::Err: std::fmt::Debug,
+{
+    raw.iter()
+        .map(|key| key.parse().expect("Should be a valid key"))
+        .collect()
+}
+
+#[tokio::test]
+async fn articles_should_keep_input_order() {
+    let api = StubApi::default();
+    let slugs: Vec<ArticleSlug> = keys(&["1", "2", "missing-3", "4", "5", "6"]);
+
+    let articles = api.get_articles(&slugs, 3).await;
+
+    let returned: Vec<_> = articles.iter().map(|(slug, _)| slug.clone()).collect();
+    assert_eq!(returned, slugs);
+    assert!(matches!(articles[2].1, Err(Error::NoObject)));
+    assert!(articles
+        .iter()
+        .enumerate()
+        .all(|(index, (_, article))| index == 2 || article.is_ok()));
+}
+
+#[tokio::test]
+async fn concurrency_should_be_capped() {
+    let api = StubApi::default();
+    let slugs: Vec<ArticleSlug> = keys(&["1", "2", "3", "4", "5", "6", "7", "8"]);
+
+    api.get_articles(&slugs, 3).await;
+
+    assert_eq!(api.max_running.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn zero_concurrency_should_fetch_one_by_one() {
+    let api = StubApi::default();
+    let names: Vec<UserName> = keys(&["1", "2", "3"]);
+
+    let users = api.get_users(&names, 0).await;
+
+    assert_eq!(users.len(), 3);
+    assert_eq!(api.max_running.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn tags_should_keep_errors_with_their_slugs() {
+    let api = StubApi::default();
+    let slugs: Vec<TagSlug> = keys(&["missing-1", "2", "missing-3"]);
+
+    let tags = api.get_tags(&slugs, 5).await;
+
+    let failed: Vec<_> = tags
+        .iter()
+        .filter(|(_, tag)| tag.is_err())
+        .map(|(slug, _)| slug.as_ref().as_str())
+        .collect();
+    assert_eq!(failed, ["missing-1", "missing-3"]);
+}
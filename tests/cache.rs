@@ -0,0 +1,155 @@
+//! Offline tests for the caching accessor.
+//!
+//! Objects are served by the in-memory mock, which counts the calls that reached it.
+#![cfg(feature = "mock")]
+
+use std::num::NonZeroUsize;
+
+use type_matrux::{
+    client::TagsApi,
+    client::{CachedDrukarnia, Error, MockDrukarnia},
+    object::{FullTag, TagSlug},
+};
+
+mod support;
+
+use support::fixture_edited;
+
+fn tag(slug: &str) -> FullTag {
+    fixture_edited("tags/full_tag.json", |value| {
+        value["slug"] = slug.into();
+    })
+}
+
+fn slug(slug: &str) -> TagSlug {
+    slug.parse().expect("Should be a valid slug")
+}
+
+fn cached(mock: &MockDrukarnia, ttl: time::Duration) -> CachedDrukarnia<MockDrukarnia> {
+    CachedDrukarnia::new(mock.clone(), ttl)
+}
+
+#[tokio::test]
+async fn second_get_within_ttl_should_not_reach_inner() {
+    // Arrange
+    let mock = MockDrukarnia::new();
+    mock.expect_tag(tag("rust"));
+    let api = cached(&mock, time::Duration::minutes(10));
+
+    // Act
+    let first = api.get_tag(&slug("rust")).await.expect("Should fetch tag");
+    let second = api.get_tag(&slug("rust")).await.expect("Should fetch tag");
+
+    // Assert
+    assert_eq!(first, second);
+    assert_eq!(mock.calls("get_tag"), 1);
+    assert_eq!(*api.cache_stats().hits(), 1);
+    assert_eq!(*api.cache_stats().misses(), 1);
+}
+
+#[tokio::test]
+async fn expired_object_should_be_refetched() {
+    // Arrange
+    let mock = MockDrukarnia::new();
+    mock.expect_tag(tag("rust"));
+    let api = cached(&mock, time::Duration::ZERO);
+
+    // Act
+    api.get_tag(&slug("rust")).await.expect("Should fetch tag");
+    tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+    api.get_tag(&slug("rust")).await.expect("Should fetch tag");
+
+    // Assert
+    assert_eq!(mock.calls("get_tag"), 2);
+    assert_eq!(*api.cache_stats().hits(), 0);
+}
+
+#[tokio::test]
+async fn invalidated_object_should_be_refetched() {
+    // Arrange
+    let mock = MockDrukarnia::new();
+    mock.expect_tag(tag("rust")).expect_tag(tag("go"));
+    let api = cached(&mock, time::Duration::minutes(10));
+    api.get_tag(&slug("rust")).await.expect("Should fetch tag");
+    api.get_tag(&slug("go")).await.expect("Should fetch tag");
+
+    // Act
+    api.invalidate_tag(&slug("rust"));
+    api.get_tag(&slug("rust")).await.expect("Should fetch tag");
+    api.get_tag(&slug("go")).await.expect("Should fetch tag");
+
+    // Assert
+    assert_eq!(mock.calls("get_tag"), 3);
+}
+
+#[tokio::test]
+async fn least_recently_used_object_should_be_evicted() {
+    // Arrange
+    let mock = MockDrukarnia::new();
+    mock.expect_tag(tag("rust"))
+        .expect_tag(tag("go"))
+        .expect_tag(tag("zig"));
+    let api = cached(&mock, time::Duration::minutes(10))
+        .with_capacity(NonZeroUsize::new(2).expect("2 != 0"));
+    api.get_tag(&slug("rust")).await.expect("Should fetch tag");
+    api.get_tag(&slug("go")).await.expect("Should fetch tag");
+    // makes "go" the least recently used one
+    api.get_tag(&slug("rust")).await.expect("Should fetch tag");
+
+    // Act
+    api.get_tag(&slug("zig")).await.expect("Should fetch tag");
+    api.get_tag(&slug("rust")).await.expect("Should fetch tag");
+    api.get_tag(&slug("go")).await.expect("Should fetch tag");
+
+    // Assert
+    // rust, go, zig and go again
+    assert_eq!(mock.calls("get_tag"), 4);
+}
+
+#[tokio::test]
+async fn errors_should_not_be_cached() {
+    // Arrange
+    let mock = MockDrukarnia::new();
+    let api = cached(&mock, time::Duration::minutes(10));
+
+    // Act
+    let first = api.get_tag(&slug("missing")).await;
+    let second = api.get_tag(&slug("missing")).await;
+
+    // Assert
+    assert!(matches!(first, Err(Error::NoObject)));
+    assert!(matches!(second, Err(Error::NoObject)));
+    assert_eq!(mock.calls("get_tag"), 2);
+}
+
+#[tokio::test]
+async fn other_calls_should_pass_through() {
+    // Arrange
+    let mock = MockDrukarnia::new();
+    mock.expect_popular_tags(vec![]);
+    let api = cached(&mock, time::Duration::minutes(10));
+
+    // Act
+    api.popular_tags().await.expect("Should fetch popular tags");
+    api.popular_tags().await.expect("Should fetch popular tags");
+
+    // Assert
+    assert_eq!(mock.calls("popular_tags"), 2);
+    assert_eq!(api.cache_stats(), Default::default());
+}
+
+#[tokio::test]
+async fn bulk_fetch_should_use_cache() {
+    // Arrange
+    let mock = MockDrukarnia::new();
+    mock.expect_tag(tag("rust")).expect_tag(tag("go"));
+    let api = cached(&mock, time::Duration::minutes(10));
+    api.get_tag(&slug("rust")).await.expect("Should fetch tag");
+
+    // Act
+    let tags = api.get_tags(&[slug("rust"), slug("go")], 2).await;
+
+    // Assert
+    assert!(tags.iter().all(|(_, tag)| tag.is_ok()));
+    assert_eq!(mock.calls("get_tag"), 2);
+}
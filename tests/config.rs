@@ -0,0 +1,255 @@
+//! Tests for runtime configuration loading.
+//!
+//! Environment is passed to [`Config::merge_env`] explicitly, so that tests do not interfere with each other
+//! through process environment.
+#![cfg(feature = "config")]
+
+use std::time::Duration;
+
+use secrecy::ExposeSecret;
+use type_matrux::config::{Config, ConfigError};
+
+const FULL: &str = r#"
+base_url = "http://localhost:8080/"
+user_agent = "config-test/1.0"
+min_request_interval = 500
+
+[timeouts]
+request = 10000
+connect = 3000
+
+[credentials]
+email = "reader@example.com"
+password = "hunter2-secret"
+"#;
+
+fn vars(vars: &[(&str, &str)]) -> Vec<(String, String)> {
+    vars.iter()
+        .map(|&(name, value)| (name.to_owned(), value.to_owned()))
+        .collect()
+}
+
+#[test]
+fn file_should_configure_everything() {
+    // Arrange
+    let config = Config::from_toml(FULL).expect("Should be a valid config");
+
+    // Act
+    let client = config.build_client().expect("Should build a client");
+
+    // Assert
+    assert_eq!(
+        config.base_url().as_ref().map(|url| url.as_str()),
+        Some("http://localhost:8080/")
+    );
+    assert_eq!(client.user_agent(), "config-test/1.0");
+    assert_eq!(
+        *config.min_request_interval(),
+        Some(Duration::from_millis(500))
+    );
+    assert_eq!(*config.timeouts().request(), Some(Duration::from_secs(10)));
+    assert_eq!(*config.timeouts().connect(), Some(Duration::from_secs(3)));
+    let credentials = config
+        .credentials()
+        .as_ref()
+        .expect("Should have credentials");
+    assert_eq!(credentials.identifier().to_string(), "reader@example.com");
+}
+
+#[test]
+fn empty_file_should_use_defaults() {
+    // Arrange
+    let config = Config::from_toml("").expect("Empty config should be valid");
+
+    // Act
+    let client = config.build_client().expect("Should build a client");
+
+    // Assert
+    assert!(config.credentials().is_none());
+    assert!(config.base_url().is_none());
+    assert!(client.user_agent().starts_with("type-matrux/"));
+}
+
+#[test]
+fn env_should_take_precedence_over_file() {
+    // Arrange
+    let config = Config::from_toml(FULL).expect("Should be a valid config");
+
+    // Act
+    let config = config
+        .merge_env(vars(&[
+            ("TYPE_MATRUX_USER_AGENT", "from-env/2.0"),
+            ("TYPE_MATRUX_TIMEOUT", "250"),
+            ("TYPE_MATRUX_PASSWORD", "env-secret"),
+            ("USER_AGENT", "not-prefixed"),
+        ]))
+        .expect("Should merge env");
+
+    // Assert
+    assert_eq!(config.user_agent().as_deref(), Some("from-env/2.0"));
+    assert_eq!(
+        *config.timeouts().request(),
+        Some(Duration::from_millis(250))
+    );
+    // not overridden
+    assert_eq!(*config.timeouts().connect(), Some(Duration::from_secs(3)));
+    let credentials = config
+        .credentials()
+        .as_ref()
+        .expect("Should have credentials");
+    assert_eq!(credentials.identifier().to_string(), "reader@example.com");
+    assert_eq!(credentials.password().expose_secret(), "env-secret");
+}
+
+#[test]
+fn env_alone_should_provide_credentials() {
+    // Arrange
+    let env = vars(&[
+        ("TYPE_MATRUX_EMAIL", "writer@example.com"),
+        ("TYPE_MATRUX_PASSWORD", "another-secret"),
+        ("TYPE_MATRUX_BASE_URL", "http://127.0.0.1:1/"),
+    ]);
+
+    // Act
+    let config = Config::default().merge_env(env).expect("Should merge env");
+
+    // Assert
+    let credentials = config
+        .credentials()
+        .as_ref()
+        .expect("Should have credentials");
+    assert_eq!(credentials.identifier().to_string(), "writer@example.com");
+    assert_eq!(
+        config.base_url().as_ref().map(|url| url.as_str()),
+        Some("http://127.0.0.1:1/")
+    );
+}
+
+#[test]
+fn username_should_provide_credentials() {
+    // Arrange
+    let toml = "[credentials]\nusername = \"reader\"\npassword = \"hunter2-secret\"\n";
+
+    // Act
+    let from_file = Config::from_toml(toml).expect("Should be a valid config");
+    let from_env = Config::default()
+        .merge_env(vars(&[
+            ("TYPE_MATRUX_USERNAME", "writer"),
+            ("TYPE_MATRUX_PASSWORD", "another-secret"),
+        ]))
+        .expect("Should merge env");
+
+    // Assert
+    for (config, username) in [(from_file, "reader"), (from_env, "writer")] {
+        let credentials = config
+            .credentials()
+            .as_ref()
+            .expect("Should have credentials");
+        assert!(credentials.email().is_none());
+        assert_eq!(credentials.identifier().to_string(), username);
+    }
+}
+
+#[test]
+fn env_email_without_password_should_fail() {
+    // Arrange
+    let env = vars(&[("TYPE_MATRUX_EMAIL", "writer@example.com")]);
+
+    // Act
+    let res = Config::default().merge_env(env);
+
+    // Assert
+    assert!(matches!(res, Err(ConfigError::MissingField("password"))));
+}
+
+#[test]
+fn file_credentials_without_password_should_fail() {
+    // Arrange
+    let toml = "[credentials]\nemail = \"reader@example.com\"\n";
+
+    // Act
+    let res = Config::from_toml(toml);
+
+    // Assert
+    let err = res.expect_err("Should require a password");
+    assert!(matches!(err, ConfigError::Parse(_)));
+    assert!(err.to_string().contains("password"), "{err}");
+}
+
+#[test]
+fn unknown_field_should_fail() {
+    // Arrange
+    let toml = "user_agnet = \"typo/1.0\"\n";
+
+    // Act
+    let res = Config::from_toml(toml);
+
+    // Assert
+    assert!(matches!(res, Err(ConfigError::Parse(_))));
+}
+
+#[test]
+fn bad_env_values_should_fail() {
+    for (name, value) in [
+        ("TYPE_MATRUX_BASE_URL", "not a url"),
+        ("TYPE_MATRUX_MIN_REQUEST_INTERVAL", "half a second"),
+        ("TYPE_MATRUX_CONNECT_TIMEOUT", "-1"),
+    ] {
+        // Act
+        let res = Config::default().merge_env(vars(&[(name, value)]));
+
+        // Assert
+        assert!(
+            matches!(&res, Err(ConfigError::BadVar { name: bad, .. }) if bad == name),
+            "{name}={value} should be rejected, got {res:?}"
+        );
+    }
+}
+
+#[test]
+fn missing_file_should_fail() {
+    // Arrange
+    let path = std::env::temp_dir().join("type-matrux-config-test-missing.toml");
+
+    // Act
+    let res = Config::from_file(path);
+
+    // Assert
+    assert!(matches!(res, Err(ConfigError::Io(_))));
+}
+
+#[test]
+fn debug_should_not_show_secrets() {
+    // Arrange
+    let config = Config::from_toml(FULL)
+        .expect("Should be a valid config")
+        .merge_env(vars(&[("TYPE_MATRUX_PASSWORD", "env-secret")]))
+        .expect("Should merge env");
+
+    // Act
+    let debug = format!("{config:?}");
+
+    // Assert
+    assert!(!debug.contains("hunter2-secret"), "{debug}");
+    assert!(!debug.contains("env-secret"), "{debug}");
+    assert!(!debug.contains("reader@example.com"), "{debug}");
+    assert!(debug.contains("config-test/1.0"), "{debug}");
+}
+
+#[test]
+fn config_file_should_be_read() {
+    // Arrange
+    let path = std::env::temp_dir().join(format!(
+        "type-matrux-config-test-{}.toml",
+        std::process::id()
+    ));
+    std::fs::write(&path, FULL).expect("Should be able to write config file");
+
+    // Act
+    let res = Config::from_file(&path);
+    std::fs::remove_file(&path).expect("Should be able to remove config file");
+
+    // Assert
+    let config = res.expect("Should read config file");
+    assert!(config.credentials().is_some());
+}
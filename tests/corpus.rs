@@ -0,0 +1,243 @@
+//! Regression corpus for data objects.
+//!
+//! Each file in `tests/fixtures/corpus/` is a known-good payload, named after the type it should deserialize
+//! into (with an optional edge case suffix, like `full_user_no_avatar.json`). Every file gets it's own test,
+//! so that a change breaking some older shape is reported by name.
+//!
+//! To add a live response to the corpus, save it's JSON to a file and run
+//! ```sh
+//! CORPUS_RESPONSE=response.json CORPUS_NAME=full_user_something cargo test --test corpus -- --ignored
+//! ```
+//! then list the new file in `corpus!` below.
+
+use std::path::{Path, PathBuf};
+
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+fn corpus_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/corpus")
+}
+
+fn load<T: DeserializeOwned>(name: &str) -> T {
+    let path = corpus_dir().join(format!("{name}.json"));
+    let text = std::fs::read_to_string(&path)
+        .unwrap_or_else(|err| panic!("Should be able to read {}: {err}", path.display()));
+    serde_json::from_str(&text)
+        .unwrap_or_else(|err| panic!("Corpus file {name}.json should deserialize: {err}"))
+}
+
+macro_rules! corpus {
+    ($($name:ident: $type:ty => |$obj:ident| $check:block)*) => {
+        const FILES: &[&str] = &[$(stringify!($name)),*];
+
+        $(
+            #[test]
+            fn $name() {
+                let $obj: $type = load(stringify!($name));
+                $check
+            }
+        )*
+    };
+}
+
+mod corpus {
+    use type_matrux::object::{
+        ArticleComment, ArticleTag, ArticleUser, AuthorArticle, AuthorizedUser, CommentUser,
+        FeedArticle, FollowerUser, FullArticle, FullBookmark, FullList, FullNotification, FullTag,
+        FullUser, ListArticle, MaybeUrl, NotificationDetails, PopularTag, RecommendedArticle,
+        ReplyComment, SearchArticle, ShortArticle, ShortUser, TagArticle, UserTag,
+    };
+
+    use super::load;
+
+    const GITLAB_SLUG: &str = "gitlab-istoriya-uspikhu-ukrayinskogo-konkurenta-github-t7agP";
+
+    corpus! {
+        full_article: FullArticle => |article| {
+            assert_eq!(article.id().to_string(), "6511e036280f4421025f09fd");
+            assert_eq!(article.slug().as_ref(), GITLAB_SLUG);
+            assert_eq!(*article.like_num(), 42);
+        }
+        search_article: SearchArticle => |article| {
+            assert_eq!(article.id().to_string(), "6511e036280f4421025f0a01");
+            assert_eq!(article.title().as_ref(), "Rust для початківців");
+        }
+        recommended_article: RecommendedArticle => |article| {
+            assert_eq!(
+                article.id().as_ref().map(ToString::to_string).as_deref(),
+                Some("6511e036280f4421025f0a02")
+            );
+            assert_eq!(*article.like_num(), 7);
+        }
+        recommended_article_no_id: RecommendedArticle => |article| {
+            assert!(article.id().is_none());
+            assert_eq!(article.slug().as_ref(), "yak-pracyuye-git-x9Y8z");
+        }
+        recommended_article_fractional_read_time: RecommendedArticle => |article| {
+            assert_eq!(*article.read_time(), time::Duration::milliseconds(3500));
+        }
+        author_article: AuthorArticle => |article| {
+            assert_eq!(article.slug().as_ref(), GITLAB_SLUG);
+            assert_eq!(*article.like_num(), 42);
+        }
+        tag_article: TagArticle => |article| {
+            assert_eq!(article.slug().as_ref(), GITLAB_SLUG);
+            assert_eq!(*article.like_num(), 42);
+        }
+        feed_article: FeedArticle => |article| {
+            assert_eq!(article.slug().as_ref(), GITLAB_SLUG);
+            assert_eq!(*article.like_num(), 42);
+        }
+        list_article: ListArticle => |article| {
+            assert_eq!(article.id().to_string(), "651f3c2a9b8e7d6c5b4a3f21");
+            assert_eq!(article.title().as_ref(), "Тестова стаття");
+        }
+        short_article: ShortArticle => |article| {
+            assert_eq!(article.slug().as_ref(), GITLAB_SLUG);
+            assert_eq!(*article.like_num(), 42);
+        }
+        article_user: ArticleUser => |user| {
+            assert_eq!(user.username().as_ref(), "OstanniyCapitalist");
+            assert_eq!(*user.read_num(), 15230);
+        }
+        article_user_bad_socials: ArticleUser => |user| {
+            assert!(matches!(
+                user.socials().telegram(),
+                Some(MaybeUrl::BadUrl(source, _)) if source == "t.me/no-scheme"
+            ));
+        }
+        full_user: FullUser => |user| {
+            assert_eq!(user.name().as_ref(), "Остап Капіталіст");
+            assert!(user.avatar().is_some());
+        }
+        full_user_no_avatar: FullUser => |user| {
+            assert_eq!(user.username().as_ref(), "OstanniyCapitalist");
+            assert!(user.avatar().is_none());
+        }
+        comment_user: CommentUser => |user| {
+            assert_eq!(user.id().to_string(), "643af9fc1272bd9066a1ff01");
+            assert_eq!(user.username().as_ref(), "taras");
+        }
+        follower_user: FollowerUser => |user| {
+            assert_eq!(user.display_label(), "Марія");
+            assert!(!user.is_deleted_account());
+        }
+        follower_user_deleted: FollowerUser => |user| {
+            assert!(user.is_deleted_account());
+            assert_eq!(user.display_label(), FollowerUser::PLACEHOLDER_LABEL);
+        }
+        authorized_user: AuthorizedUser => |user| {
+            assert_eq!(user.username().as_ref(), "ostap");
+            assert_eq!(*user.read_num(), 560);
+        }
+        short_user: ShortUser => |user| {
+            assert_eq!(user.id().to_string(), "643af9fc1272bd9066a1ff00");
+            assert_eq!(user.name().as_ref(), "Марія");
+        }
+        article_tag: ArticleTag => |tag| {
+            assert_eq!(tag.slug().as_ref(), "tekhnologiyi");
+            assert_eq!(*tag.mentions_num(), 1520);
+        }
+        user_tag: UserTag => |tag| {
+            assert_eq!(tag.id().to_string(), "64ff1f2a1272bd9066a1aa01");
+            assert_eq!(tag.name().as_ref(), "Технології");
+        }
+        full_tag: FullTag => |tag| {
+            assert_eq!(tag.slug().as_ref(), "tekhnologiyi");
+            assert_eq!(*tag.mentions_num(), 2);
+        }
+        popular_tag: PopularTag => |tag| {
+            assert_eq!(tag.name().as_ref(), "Технології");
+            assert_eq!(*tag.mentions_num(), 1520);
+        }
+        article_comment: ArticleComment => |comment| {
+            assert_eq!(comment.id().to_string(), "651ae7dc280f4421026b12c5");
+            assert_eq!(*comment.reply_num(), 1);
+        }
+        reply_comment: ReplyComment => |comment| {
+            assert_eq!(comment.id().to_string(), "651ae7dc280f4421026b12d1");
+            assert_eq!(*comment.reply_num(), 0);
+        }
+        full_list: FullList => |list| {
+            assert_eq!(list.name().as_ref(), "Прочитати пізніше");
+            assert_eq!(*list.articles_num(), 1);
+        }
+        full_bookmark: FullBookmark => |bookmark| {
+            assert_eq!(bookmark.id().to_string(), "65200e412e58dd5b6c1a4f77");
+        }
+        full_notification: FullNotification => |notification| {
+            assert_eq!(notification.id().to_string(), "652a10c0280f4421027a0002");
+            assert!(*notification.seen());
+        }
+        notification_details: NotificationDetails => |details| {
+            assert_eq!(
+                details.action_owner().as_ref().map(|owner| owner.username().as_ref().as_str()),
+                Some("taras")
+            );
+        }
+    }
+
+    #[test]
+    fn every_corpus_file_should_be_tested() {
+        let mut untested: Vec<_> = std::fs::read_dir(super::corpus_dir())
+            .expect("Corpus directory should exist")
+            .map(|entry| entry.expect("Should be able to list corpus").path())
+            .filter_map(|path| {
+                let name = path.file_stem()?.to_str()?.to_owned();
+                (path.extension()? == "json" && !FILES.contains(&name.as_str())).then_some(name)
+            })
+            .collect();
+        untested.sort();
+        assert!(
+            untested.is_empty(),
+            "Corpus files without a test: {untested:?}"
+        );
+    }
+}
+
+/// Sorts keys and hides values that should not end up in the repository
+fn normalize(value: Value) -> Value {
+    match value {
+        Value::Object(map) => Value::Object(
+            map.into_iter()
+                .map(|(key, value)| {
+                    let value = match key.as_str() {
+                        "email" if value.is_string() => "user@example.com".into(),
+                        _ => normalize(value),
+                    };
+                    (key, value)
+                })
+                .collect::<std::collections::BTreeMap<_, _>>()
+                .into_iter()
+                .collect(),
+        ),
+        Value::Array(items) => Value::Array(items.into_iter().map(normalize).collect()),
+        other => other,
+    }
+}
+
+#[test]
+#[ignore = "utility, adds a saved response to the corpus"]
+fn add_to_corpus() {
+    let response = std::env::var("CORPUS_RESPONSE")
+        .expect("CORPUS_RESPONSE should point to a saved response JSON");
+    let name = std::env::var("CORPUS_NAME").expect("CORPUS_NAME should name the corpus file");
+
+    let text = std::fs::read_to_string(&response)
+        .unwrap_or_else(|err| panic!("Should be able to read {response}: {err}"));
+    let value: Value = serde_json::from_str(&text).expect("Response should be JSON");
+    let mut normalized =
+        serde_json::to_string_pretty(&normalize(value)).expect("JSON value should serialize");
+    normalized.push('\n');
+
+    let path = corpus_dir().join(format!("{name}.json"));
+    assert!(
+        !path.exists(),
+        "{} already exists, remove it first to replace",
+        path.display()
+    );
+    std::fs::write(&path, normalized)
+        .unwrap_or_else(|err| panic!("Should be able to write {}: {err}", path.display()));
+    println!("Added {}, list it in tests/corpus.rs", path.display());
+}
@@ -0,0 +1,182 @@
+#![cfg(feature = "epub")]
+
+use std::cell::Cell;
+
+use time::{format_description::well_known::Rfc3339, OffsetDateTime};
+
+use type_matrux::{
+    client::ImageBytes,
+    export::epub::{build, build_with_images, BookMeta, EpubError},
+    object::FullArticle,
+};
+
+mod support;
+
+use support::{fixture, fixture_edited};
+
+fn article() -> FullArticle {
+    fixture("full_article.json")
+}
+
+fn u16_at(bytes: &[u8], at: usize) -> usize {
+    u16::from_le_bytes([bytes[at], bytes[at + 1]]).into()
+}
+
+fn u32_at(bytes: &[u8], at: usize) -> usize {
+    u32::from_le_bytes([bytes[at], bytes[at + 1], bytes[at + 2], bytes[at + 3]])
+        .try_into()
+        .expect("Should fit")
+}
+
+/// Reads entries of a ZIP archive with stored entries, in order, checking that headers agree
+fn entries(zip: &[u8]) -> Vec<(String, Vec<u8>)> {
+    let end = zip.len() - 22;
+    assert_eq!(u32_at(zip, end), 0x0605_4b50, "Should end with a directory");
+    let count = u16_at(zip, end + 10);
+    let mut at = u32_at(zip, end + 16);
+    let mut res = vec![];
+    for _ in 0..count {
+        assert_eq!(u32_at(zip, at), 0x0201_4b50, "Should be a central header");
+        assert_eq!(u16_at(zip, at + 10), 0, "Entries should be stored");
+        let size = u32_at(zip, at + 24);
+        let name_len = u16_at(zip, at + 28);
+        let offset = u32_at(zip, at + 42);
+        let name = String::from_utf8(zip[at + 46..at + 46 + name_len].to_vec())
+            .expect("Name should be UTF-8");
+        at += 46 + name_len;
+
+        assert_eq!(u32_at(zip, offset), 0x0403_4b50, "Should be a local header");
+        assert_eq!(u32_at(zip, offset + 22), size, "Sizes should agree");
+        let data_start = offset + 30 + u16_at(zip, offset + 26);
+        assert_eq!(&zip[offset + 30..offset + 30 + name_len], name.as_bytes());
+        res.push((name, zip[data_start..data_start + size].to_vec()));
+    }
+    res
+}
+
+fn entry<'e>(entries: &'e [(String, Vec<u8>)], name: &str) -> &'e str {
+    let (_, data) = entries
+        .iter()
+        .find(|(entry, _)| entry == name)
+        .unwrap_or_else(|| panic!("Should contain {name}"));
+    std::str::from_utf8(data).expect("Should be UTF-8")
+}
+
+#[test]
+fn book_should_have_epub_structure() {
+    // Arrange
+    let articles = [article(), article()];
+
+    // Act
+    let book = build(&articles, BookMeta::new("Збірка")).expect("Should build a book");
+
+    // Assert
+    let entries = entries(&book);
+    assert_eq!(entries[0].0, "mimetype", "mimetype should go first");
+    assert_eq!(entries[0].1, b"application/epub+zip");
+    assert!(entry(&entries, "META-INF/container.xml").contains("OEBPS/content.opf"));
+    let package = entry(&entries, "OEBPS/content.opf");
+    assert!(package.contains("<dc:title>Збірка</dc:title>"));
+    assert!(package.contains("<dc:creator>Остап Капіталіст</dc:creator>"));
+    assert!(package.contains("<meta property=\"dcterms:modified\">2023-09-25T19:30:30Z</meta>"));
+    assert!(entry(&entries, "OEBPS/nav.xhtml").contains("chapters/chapter-2.xhtml"));
+}
+
+#[test]
+fn unrepresentable_modification_time_should_fall_back() {
+    // Arrange
+    // year 0 is fine with it's own offset, but goes before year 0 in UTC
+    let articles = [fixture_edited::<FullArticle>(
+        "full_article.json",
+        |value| {
+            value["createdAt"] = "0000-01-01T00:30:00+01:00".into();
+        },
+    )];
+
+    // Act
+    let book = build(&articles, BookMeta::new("Збірка")).expect("Should build a book");
+
+    // Assert
+    let entries = entries(&book);
+    let package = entry(&entries, "OEBPS/content.opf");
+    let modified = package
+        .split("<meta property=\"dcterms:modified\">")
+        .nth(1)
+        .and_then(|rest| rest.split('<').next())
+        .expect("Modification time should be present");
+    let modified = OffsetDateTime::parse(modified, &Rfc3339).expect("Should be RFC 3339");
+    assert_eq!(modified.year(), OffsetDateTime::now_utc().year());
+    assert!(entry(&entries, "OEBPS/chapters/chapter-1.xhtml")
+        .contains("<time datetime=\"0000-01-01T00:30:00+01:00\">"));
+}
+
+#[test]
+fn chapter_count_should_match_articles() {
+    for count in 1..=3 {
+        // Arrange
+        let articles = vec![article(); count];
+
+        // Act
+        let book = build(&articles, BookMeta::new("Збірка")).expect("Should build a book");
+
+        // Assert
+        let chapters = entries(&book)
+            .into_iter()
+            .filter(|(name, _)| name.starts_with("OEBPS/chapters/"))
+            .count();
+        assert_eq!(chapters, count);
+    }
+}
+
+#[test]
+fn chapters_should_be_xhtml() {
+    // Arrange
+    let articles = [article()];
+
+    // Act
+    let book = build(&articles, BookMeta::new("Збірка")).expect("Should build a book");
+
+    // Assert
+    let entries = entries(&book);
+    let chapter = entry(&entries, "OEBPS/chapters/chapter-1.xhtml");
+    assert!(chapter.contains(
+        "<img src=\"https://images.drukarnia.com.ua/articles/cover-1.png\" alt=\"Логотип GitLab\"/>"
+    ));
+    assert!(
+        entry(&entries, "OEBPS/content.opf").contains("properties=\"remote-resources\""),
+        "Remote images should be declared"
+    );
+}
+
+#[test]
+fn images_should_be_bundled() {
+    // Arrange
+    let articles = [article(), article()];
+    let fetched = Cell::new(0);
+
+    // Act
+    let book = build_with_images(&articles, BookMeta::new("Збірка"), |_| {
+        fetched.set(fetched.get() + 1);
+        Some(ImageBytes::new(Some(mime::IMAGE_PNG), b"png".to_vec()))
+    })
+    .expect("Should build a book");
+
+    // Assert
+    assert_eq!(fetched.get(), 1, "Each image should be fetched once");
+    let entries = entries(&book);
+    assert_eq!(entry(&entries, "OEBPS/images/image-1.png"), "png");
+    assert!(entry(&entries, "OEBPS/chapters/chapter-2.xhtml")
+        .contains("<img src=\"../images/image-1.png\""));
+    let package = entry(&entries, "OEBPS/content.opf");
+    assert!(package.contains("media-type=\"image/png\""));
+    assert!(!package.contains("remote-resources"));
+}
+
+#[test]
+fn empty_book_should_error() {
+    // Act
+    let book = build(&[], BookMeta::new("Збірка"));
+
+    // Assert
+    assert_eq!(book, Err(EpubError::NoArticles));
+}
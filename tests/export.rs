@@ -0,0 +1,55 @@
+#![cfg(feature = "export")]
+
+use type_matrux::{client::ImageBytes, export::HtmlExportOptions, object::FullArticle};
+
+mod support;
+
+use support::fixture_text;
+
+fn article() -> FullArticle {
+    serde_json::from_str(&fixture_text("full_article.json")).expect("Fixture should deserialize")
+}
+
+#[test]
+fn html_should_match_golden_file() {
+    // Arrange
+    let article = article();
+    let expected = fixture_text("export/full_article.html");
+
+    // Act
+    let html = article.to_html(HtmlExportOptions::default());
+
+    // Assert
+    assert_eq!(html, expected);
+}
+
+#[test]
+fn cover_should_be_embedded() {
+    // Arrange
+    let article = article();
+    let cover = ImageBytes::new(Some(mime::IMAGE_PNG), b"png".to_vec());
+
+    // Act
+    let html = article.to_html(HtmlExportOptions::default().with_cover(cover));
+
+    // Assert
+    assert!(
+        html.contains("<img class=\"cover\" src=\"data:image/png;base64,cG5n\" alt=\"\">"),
+        "Cover should be embedded: {html}"
+    );
+}
+
+#[test]
+fn cover_link_should_be_optional() {
+    // Arrange
+    let article = article();
+
+    // Act
+    let html = article.to_html(HtmlExportOptions::default().with_link_cover(false));
+
+    // Assert
+    assert!(
+        !html.contains("class=\"cover\""),
+        "Cover should be skipped: {html}"
+    );
+}
@@ -0,0 +1,48 @@
+//! Offline tests for logging out on drop.
+//!
+//! Sessions are built without validation, so their logout requests never succeed, which is fine here.
+#![cfg(all(feature = "test-constructors", feature = "logout-on-drop"))]
+
+use reqwest::Client;
+use secrecy::{ExposeSecret, SecretString};
+use type_matrux::{
+    client::{AuthDrukarnia, ReqwestAuth},
+    object::AuthorizedUser,
+};
+
+mod support;
+
+use support::fixture;
+
+const TOKEN: &str = "token=session-secret";
+
+fn session() -> ReqwestAuth {
+    let user: AuthorizedUser = fixture("users/authorized.json");
+    ReqwestAuth::new_for_tests(Client::new(), user, SecretString::new(TOKEN.to_owned()))
+}
+
+#[test]
+fn drop_outside_runtime_does_not_panic() {
+    drop(session());
+}
+
+#[tokio::test]
+async fn drop_inside_runtime_does_not_panic() {
+    drop(session());
+}
+
+#[test]
+fn split_session_keeps_parts() {
+    let (_client, user, token) = session().into_parts();
+
+    assert_eq!(token.expose_secret(), TOKEN);
+    assert_eq!(user, session().authorized_user().clone());
+}
+
+#[test]
+fn debug_should_not_leak() {
+    let debug = format!("{:?}", session());
+
+    assert!(!debug.contains(TOKEN), "Token leaked: {debug}");
+    assert!(!debug.contains("example.com"), "Email leaked: {debug}");
+}
@@ -0,0 +1,129 @@
+//! Tests of the in-memory accessor, showing how code built on this crate could be tested offline.
+#![cfg(feature = "mock")]
+
+use futures::StreamExt;
+use type_matrux::{
+    client::{ArticlesApi, AuthDrukarnia, BaseApi, Error, MockDrukarnia, UsersApi},
+    object::{
+        Credentials, FeedArticle, FollowerUser, FullList, FullUser, RecommendedArticle, ShortUser,
+    },
+    DrukarniaApi,
+};
+
+mod support;
+
+use support::fixture;
+
+fn credentials() -> Credentials {
+    serde_json::from_str(r#"{"email": "reader@example.com", "password": "password"}"#)
+        .expect("Should be valid credentials")
+}
+
+/// An example of application logic, counting articles in the feed
+async fn count_feed_articles(api: &(impl DrukarniaApi + Sync)) -> Result<usize, Error> {
+    let mut articles = api.feed().flat();
+    let mut count = 0;
+    while let Some(article) = articles.next().await {
+        article?;
+        count += 1;
+    }
+    Ok(count)
+}
+
+#[tokio::test]
+async fn feed_stream_should_run_against_mock() {
+    // Arrange
+    let article: FeedArticle = fixture("drift/feed_article.json");
+    let api = MockDrukarnia::new();
+    api.expect_feed(vec![vec![article.clone(), article.clone()], vec![article]]);
+
+    // Act
+    let count = count_feed_articles(&api).await;
+
+    // Assert
+    assert_eq!(count.expect("Should count articles"), 3);
+    // last page is known to be the last one, so the empty one is not requested
+    assert_eq!(api.calls("feed_page"), 2);
+}
+
+#[tokio::test]
+async fn expected_objects_should_be_served() {
+    // Arrange
+    let user: FullUser = fixture("full_user.json");
+    let api = MockDrukarnia::new();
+    api.expect_user(user.clone());
+
+    // Act
+    let found = api.get_user(user.username()).await;
+    let missing = api
+        .get_user(&"nobody".parse().expect("Should be a valid username"))
+        .await;
+
+    // Assert
+    assert_eq!(found.expect("Should find the user").id(), user.id());
+    assert!(matches!(missing, Err(Error::NoObject)));
+    assert_eq!(api.calls("get_user"), 2);
+}
+
+#[tokio::test]
+async fn login_should_be_expected() {
+    // Arrange
+    let api = MockDrukarnia::new();
+    let lists: Vec<FullList> = fixture("bookmarks/lists.json");
+
+    // Act
+    let rejected = api.login(credentials()).await;
+    api.expect_login(fixture("users/authorized.json"))
+        .expect_bookmark_lists(lists.clone());
+    let auth = api.login(credentials()).await.expect("Should log in");
+
+    // Assert
+    assert!(matches!(rejected, Err(Error::BadCredentials)));
+    assert_eq!(
+        auth.get_bookmark_lists()
+            .await
+            .expect("Should get bookmark lists")
+            .len(),
+        lists.len()
+    );
+    assert_eq!(api.calls("login_with"), 2);
+    assert_eq!(auth.calls("get_bookmark_lists"), 1);
+}
+
+#[tokio::test]
+async fn search_streams_should_paginate() {
+    // Arrange
+    let user: ShortUser = fixture("corpus/short_user.json");
+    let article: RecommendedArticle = fixture("corpus/recommended_article.json");
+    let follower: FollowerUser = fixture("corpus/follower_user.json");
+    let name = user.username().clone();
+    let title = article.title().clone();
+    let id = user.id().clone();
+    let api = MockDrukarnia::new();
+    api.expect_user_search(
+        name.clone(),
+        vec![vec![user.clone(), user.clone()], vec![user]],
+    )
+    .expect_article_search(title.clone(), vec![vec![article.clone()], vec![article]])
+    .expect_followers(id.clone(), vec![vec![follower.clone(); 3], vec![follower]]);
+
+    // Act
+    let users: Vec<_> = api.search_user(name.clone()).flat().collect().await;
+    let plain_users: Vec<_> = api.search_user_plain(name).flat().collect().await;
+    let articles: Vec<_> = api.search_article(title).flat().collect().await;
+    let followers: Vec<_> = api.get_followers(id).flat().collect().await;
+
+    // Assert
+    assert_eq!(users.len(), 3);
+    assert_eq!(plain_users.len(), 3);
+    assert_eq!(articles.len(), 2);
+    assert_eq!(followers.len(), 4);
+    assert!(users.iter().all(Result::is_ok));
+    assert!(articles.iter().all(Result::is_ok));
+    assert!(followers.iter().all(Result::is_ok));
+    // two pages each, without the trailing empty ones
+    // plain search is served from the same pages
+    assert_eq!(api.calls("search_user_page"), 4);
+    assert_eq!(api.calls("search_article_page"), 2);
+    assert_eq!(api.calls("get_followers_page"), 2);
+}
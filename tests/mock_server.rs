@@ -0,0 +1,1297 @@
+//! Offline tests against a local mock server.
+//!
+//! Server is hand-written on top of tokio, serving fixtures by method and path, and recording the requests.
+
+use std::{
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use reqwest::Client;
+use secrecy::{ExposeSecret, SecretString};
+use tokio::{
+    io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader},
+    net::TcpListener,
+};
+use type_matrux::{
+    client::{AuthDrukarnia, BaseApi, DrukarniaClient, LoginOptions, ReqwestAuth, TagsApi},
+    object::{ArticleId, AuthorizedUser, CommentId, Credentials, LikeAmount},
+};
+use url::Url;
+
+mod support;
+
+use support::fixture_text;
+
+/// Request, as received by the mock server
+#[derive(Debug, Clone)]
+struct Recorded {
+    method: String,
+    /// Path, along with the query
+    path: String,
+    headers: Vec<(String, String)>,
+    body: String,
+}
+
+impl Recorded {
+    fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// Response to send back
+struct Reply {
+    status: u16,
+    headers: Vec<(&'static str, String)>,
+    body: String,
+}
+
+impl Reply {
+    fn json(status: u16, body: String) -> Self {
+        Self {
+            status,
+            headers: Vec::new(),
+            body,
+        }
+    }
+
+    fn fixture(name: &str) -> Self {
+        Self::json(200, fixture_text(name))
+    }
+
+    fn with_header(mut self, name: &'static str, value: impl Into<String>) -> Self {
+        self.headers.push((name, value.into()));
+        self
+    }
+}
+
+/// Responds to a request by it's method and path, if it's routed
+type Routes = fn(&str, &str) -> Option<Reply>;
+
+struct MockServer {
+    base_url: Url,
+    requests: Arc<Mutex<Vec<Recorded>>>,
+}
+
+impl MockServer {
+    /// Starts serving at a random local port, responding 404 to the requests that are not routed
+    async fn start(routes: Routes) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("Should be able to bind a local port");
+        let base_url = format!(
+            "http://{}/",
+            listener.local_addr().expect("Should have an address")
+        )
+        .parse()
+        .expect("Should be a valid url");
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&requests);
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let mut stream = BufReader::new(stream);
+                let Some(request) = read_request(&mut stream).await else {
+                    continue;
+                };
+                let path = request.path.split('?').next().unwrap_or_default();
+                let reply = routes(&request.method, path)
+                    .unwrap_or_else(|| Reply::json(404, String::from("{}")));
+                recorded
+                    .lock()
+                    .expect("Should not be poisoned")
+                    .push(request);
+                let headers: String = reply
+                    .headers
+                    .iter()
+                    .map(|(name, value)| format!("{name}: {value}\r\n"))
+                    .collect();
+                let response = format!(
+                    "HTTP/1.1 {} Mock\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n{headers}\r\n{}",
+                    reply.status,
+                    reply.body.len(),
+                    reply.body
+                );
+                stream.get_mut().write_all(response.as_bytes()).await.ok();
+            }
+        });
+        Self { base_url, requests }
+    }
+
+    fn requests(&self) -> Vec<Recorded> {
+        self.requests
+            .lock()
+            .expect("Should not be poisoned")
+            .clone()
+    }
+
+    fn api(&self) -> DrukarniaClient {
+        DrukarniaClient::with_base_url(self.base_url.clone())
+    }
+}
+
+async fn read_request(stream: &mut BufReader<tokio::net::TcpStream>) -> Option<Recorded> {
+    let mut line = String::new();
+    stream.read_line(&mut line).await.ok()?;
+    let mut parts = line.split_whitespace();
+    let method = parts.next()?.to_owned();
+    let path = parts.next()?.to_owned();
+    let mut headers = Vec::new();
+    loop {
+        line.clear();
+        stream.read_line(&mut line).await.ok()?;
+        let Some((key, value)) = line.trim_end().split_once(':') else {
+            break;
+        };
+        headers.push((key.trim().to_owned(), value.trim().to_owned()));
+    }
+    let length = headers
+        .iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case("content-length"))
+        .and_then(|(_, value)| value.parse().ok())
+        .unwrap_or(0);
+    let mut body = vec![0; length];
+    stream.read_exact(&mut body).await.ok()?;
+    Some(Recorded {
+        method,
+        path,
+        headers,
+        body: String::from_utf8(body).ok()?,
+    })
+}
+
+fn authorized_user() -> AuthorizedUser {
+    serde_json::from_str(&fixture_text("users/authorized.json"))
+        .expect("Authorized user fixture should deserialize")
+}
+
+const TOKEN: &str = "token=mock";
+
+fn bookmark_routes(method: &str, path: &str) -> Option<Reply> {
+    let fixture = match (method, path) {
+        ("GET", "/api/articles/tags/popular") => "popular_tags.json",
+        ("GET", "/api/articles/bookmarks/lists") => "bookmarks/lists.json",
+        ("GET", "/api/articles/bookmarks/lists/65200d9b2e58dd5b6c1a4f01") => {
+            "bookmarks/list_articles.json"
+        }
+        ("POST", "/api/articles/bookmarks")
+        | ("DELETE", "/api/articles/651f3c2a9b8e7d6c5b4a3f21/bookmarks") => {
+            "bookmarks/bookmark.json"
+        }
+        _ => return None,
+    };
+    Some(Reply::fixture(fixture))
+}
+
+#[tokio::test]
+async fn bookmark_flow_should_stay_at_base_url() {
+    // Arrange
+    let server = MockServer::start(bookmark_routes).await;
+    let article: ArticleId = "651f3c2a9b8e7d6c5b4a3f21"
+        .parse()
+        .expect("Should be a valid id");
+
+    // Act
+    let auth = ReqwestAuth::from_token(
+        server.api(),
+        authorized_user(),
+        SecretString::new(TOKEN.to_owned()),
+    )
+    .await
+    .expect("Should resume a session at mock server");
+    let lists = auth
+        .get_bookmark_lists()
+        .await
+        .expect("Should get bookmark lists");
+    let list = lists[0].id().clone();
+    let bookmark = auth
+        .bookmark_article(&list, &article)
+        .await
+        .expect("Should bookmark an article");
+    let articles = auth
+        .get_list_articles(&list)
+        .await
+        .expect("Should get list articles");
+    let unbookmark = auth
+        .unbookmark_article(&article)
+        .await
+        .expect("Should unbookmark an article");
+    let tags = auth
+        .downgrade()
+        .popular_tags()
+        .await
+        .expect("Should get popular tags anonymously");
+
+    // Assert
+    assert_eq!(bookmark.article(), &article);
+    assert_eq!(unbookmark.list(), &list);
+    assert_eq!(articles[0].id(), &article);
+    assert!(!tags.is_empty(), "Should parse popular tags fixture");
+
+    let requests = server.requests();
+    let methods: Vec<_> = requests
+        .iter()
+        .map(|request| (request.method.as_str(), request.path.as_str()))
+        .collect();
+    assert_eq!(
+        methods,
+        [
+            ("GET", "/api/articles/bookmarks/lists"),
+            ("GET", "/api/articles/bookmarks/lists"),
+            ("POST", "/api/articles/bookmarks"),
+            (
+                "GET",
+                "/api/articles/bookmarks/lists/65200d9b2e58dd5b6c1a4f01"
+            ),
+            ("DELETE", "/api/articles/651f3c2a9b8e7d6c5b4a3f21/bookmarks"),
+            ("GET", "/api/articles/tags/popular"),
+        ]
+    );
+    for request in &requests[..5] {
+        assert_eq!(request.header("cookie"), Some(TOKEN), "{request:?}");
+    }
+    assert_eq!(requests[5].header("cookie"), None);
+    let body: serde_json::Value =
+        serde_json::from_str(&requests[2].body).expect("Bookmark body should be JSON");
+    assert_eq!(
+        body,
+        serde_json::json!({
+            "article": "651f3c2a9b8e7d6c5b4a3f21",
+            "list": "65200d9b2e58dd5b6c1a4f01",
+        })
+    );
+}
+
+fn comment_like_routes(method: &str, path: &str) -> Option<Reply> {
+    match (method, path) {
+        ("GET", "/api/articles/bookmarks/lists") => Some(Reply::fixture("bookmarks/lists.json")),
+        (
+            "POST" | "DELETE",
+            "/api/articles/651f3c2a9b8e7d6c5b4a3f21/comments/651ae7dc280f4421026b12c5/likes",
+        ) => Some(Reply::fixture("comments/like_state.json")),
+        _ => None,
+    }
+}
+
+#[tokio::test]
+async fn comment_like_should_return_like_state() {
+    // Arrange
+    let server = MockServer::start(comment_like_routes).await;
+    let article: ArticleId = "651f3c2a9b8e7d6c5b4a3f21"
+        .parse()
+        .expect("Should be a valid id");
+    let comment: CommentId = "651ae7dc280f4421026b12c5"
+        .parse()
+        .expect("Should be a valid id");
+    let missing: CommentId = "651a00dc280f0021020012c5"
+        .parse()
+        .expect("Should be a valid id");
+    let auth = ReqwestAuth::from_token(
+        server.api(),
+        authorized_user(),
+        SecretString::new(TOKEN.to_owned()),
+    )
+    .await
+    .expect("Should resume a session at mock server");
+
+    // Act
+    let liked = auth.set_comment_liked(&article, &comment, true).await;
+    let missing = auth.set_comment_liked(&article, &missing, false).await;
+
+    // Assert
+    let liked = liked.expect("Should parse like state");
+    assert_eq!(*liked.likes_num(), 3);
+    assert!(liked.is_liked());
+    assert!(
+        matches!(missing, Err(type_matrux::client::Error::NoObject)),
+        "Should report missing comment, got {missing:?}"
+    );
+}
+
+fn article_like_routes(method: &str, path: &str) -> Option<Reply> {
+    match (method, path) {
+        ("GET", "/api/articles/bookmarks/lists") => Some(Reply::fixture("bookmarks/lists.json")),
+        ("POST", "/api/articles/651f3c2a9b8e7d6c5b4a3f21/like") => {
+            Some(Reply::json(200, String::from("{}")))
+        }
+        ("POST", "/api/articles/651f3c2a9b8e7d6c5b4a3f22/like") => {
+            Some(Reply::json(500, String::from("{}")))
+        }
+        _ => None,
+    }
+}
+
+#[tokio::test]
+async fn article_like_should_check_status() {
+    // Arrange
+    let server = MockServer::start(article_like_routes).await;
+    let article: ArticleId = "651f3c2a9b8e7d6c5b4a3f21"
+        .parse()
+        .expect("Should be a valid id");
+    let failing: ArticleId = "651f3c2a9b8e7d6c5b4a3f22"
+        .parse()
+        .expect("Should be a valid id");
+    let missing: ArticleId = "651a00dc280f0021020012c5"
+        .parse()
+        .expect("Should be a valid id");
+    let auth = ReqwestAuth::from_token(
+        server.api(),
+        authorized_user(),
+        SecretString::new(TOKEN.to_owned()),
+    )
+    .await
+    .expect("Should resume a session at mock server");
+
+    // Act
+    let liked = auth.like_article(&article, LikeAmount::ONE).await;
+    let failed = auth.like_article(&failing, LikeAmount::ONE).await;
+    let missing_like = auth.like_article(&missing, LikeAmount::ONE).await;
+    let missing_unlike = auth.unlike_article(&missing).await;
+
+    // Assert
+    assert!(liked.is_ok(), "Should like an article, got {liked:?}");
+    assert!(
+        matches!(failed, Err(type_matrux::client::Error::OnExecution(_))),
+        "Should report server error, got {failed:?}"
+    );
+    for missing in [missing_like, missing_unlike] {
+        assert!(
+            matches!(missing, Err(type_matrux::client::Error::NoObject)),
+            "Should report missing article, got {missing:?}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn expired_token_should_be_rejected() {
+    // Arrange
+    let server = MockServer::start(|_, _| Some(Reply::json(401, String::from("{}")))).await;
+
+    // Act
+    let auth = ReqwestAuth::from_token(
+        server.api(),
+        authorized_user(),
+        SecretString::new(TOKEN.to_owned()),
+    )
+    .await;
+
+    // Assert
+    assert!(
+        matches!(auth, Err(type_matrux::client::Error::SessionExpired)),
+        "Should report expired session, got {auth:?}"
+    );
+}
+
+#[tokio::test]
+async fn malformed_json_should_report_context() {
+    // Arrange
+    // error is right after a multibyte text, so that context cuts characters in half
+    let server = MockServer::start(|_, _| {
+        Some(Reply::json(
+            200,
+            String::from("[\n  {\"name\": \"Технології та наука\" \"slug\": 1}\n]"),
+        ))
+    })
+    .await;
+
+    // Act
+    let res = server.api().popular_tags().await;
+
+    // Assert
+    let Err(type_matrux::client::Error::BadJson(err, cause)) = res else {
+        panic!("Should fail to deserialize, got {res:?}");
+    };
+    assert_eq!(err.line(), 2);
+    assert!(cause.contains("\"slug\""), "{cause:?}");
+    assert!(cause.contains("наука"), "{cause:?}");
+}
+
+#[test]
+fn plain_client_should_call_the_site() {
+    let client = DrukarniaClient::from(Client::new());
+
+    assert_eq!(client.base_url(), Client::new().base_url());
+    assert_eq!(
+        client.user_agent(),
+        concat!("type-matrux/", env!("CARGO_PKG_VERSION"))
+    );
+}
+
+fn login_routes(method: &str, path: &str) -> Option<Reply> {
+    match (method, path) {
+        ("POST", "/api/users/login") => Some(
+            Reply::json(
+                200,
+                format!(r#"{{"user": {}}}"#, fixture_text("users/authorized.json")),
+            )
+            .with_header("Set-Cookie", TOKEN),
+        ),
+        _ => None,
+    }
+}
+
+fn credentials() -> Credentials {
+    Credentials::create("reader@example.com", "hunter2").expect("Should be a valid email")
+}
+
+#[tokio::test]
+async fn login_should_send_extra_headers() {
+    // Arrange
+    let server = MockServer::start(login_routes).await;
+    let options = LoginOptions::default()
+        .with_referer(
+            "https://drukarnia.com.ua/login"
+                .parse()
+                .expect("Should be a valid url"),
+        )
+        .with_user_agent("Mozilla/5.0 (mock)")
+        .with_header("X-Device-Id", "device-42");
+
+    // Act
+    let auth = server
+        .api()
+        .login_with(credentials(), options)
+        .await
+        .expect("Should log in at mock server");
+
+    // Assert
+    assert_eq!(auth.session_token().expose_secret(), TOKEN);
+    assert_eq!(auth.authorized_user(), &authorized_user());
+    let request = server
+        .requests()
+        .into_iter()
+        .find(|request| request.path == "/api/users/login")
+        .expect("Login request should be sent");
+    assert_eq!(request.method, "POST");
+    assert_eq!(
+        request.header("referer"),
+        Some("https://drukarnia.com.ua/login")
+    );
+    assert_eq!(request.header("origin"), Some("https://drukarnia.com.ua"));
+    assert_eq!(request.header("user-agent"), Some("Mozilla/5.0 (mock)"));
+    assert_eq!(request.header("x-device-id"), Some("device-42"));
+    let body: serde_json::Value =
+        serde_json::from_str(&request.body).expect("Login body should be JSON");
+    assert_eq!(
+        body,
+        serde_json::json!({"email": "reader@example.com", "password": "hunter2"})
+    );
+}
+
+#[tokio::test]
+async fn debug_output_should_not_leak_secrets() {
+    // Arrange
+    let server = MockServer::start(login_routes).await;
+    let options = LoginOptions::default().with_header("X-Device-Id", "device-42");
+    let auth = server
+        .api()
+        .login_with(credentials(), options.clone())
+        .await
+        .expect("Should log in at mock server");
+
+    // Act
+    let auth = format!("{auth:?}");
+    let user = format!("{:?}", authorized_user());
+    let options = format!("{options:?}");
+
+    // Assert
+    assert!(!auth.contains(TOKEN), "{auth}");
+    assert!(auth.contains("[REDACTED]"), "{auth}");
+    assert!(!auth.contains("ostap@example.com"), "{auth}");
+    assert!(!user.contains("ostap@example.com"), "{user}");
+    assert!(user.contains("o***@example.com"), "{user}");
+    assert!(!options.contains("device-42"), "{options}");
+    assert!(options.contains("X-Device-Id"), "{options}");
+}
+
+#[tokio::test]
+async fn login_should_default_options() {
+    // Arrange
+    let server = MockServer::start(login_routes).await;
+
+    // Act
+    server
+        .api()
+        .login(credentials())
+        .await
+        .expect("Should log in at mock server");
+
+    // Assert
+    let request = &server.requests()[0];
+    assert_eq!(request.header("referer"), None);
+    assert_eq!(request.header("x-device-id"), None);
+    assert!(
+        request
+            .header("user-agent")
+            .is_some_and(|agent| agent.starts_with("type-matrux")),
+        "{request:?}"
+    );
+}
+
+#[tokio::test]
+async fn login_should_send_username() {
+    // Arrange
+    let server = MockServer::start(login_routes).await;
+    let credentials = Credentials::create_with_username(
+        "reader".parse().expect("Should be a valid username"),
+        "hunter2",
+    );
+
+    // Act
+    server
+        .api()
+        .login(credentials)
+        .await
+        .expect("Should log in at mock server");
+
+    // Assert
+    let body: serde_json::Value =
+        serde_json::from_str(&server.requests()[0].body).expect("Login body should be JSON");
+    assert_eq!(
+        body,
+        serde_json::json!({"username": "reader", "password": "hunter2"})
+    );
+}
+
+#[tokio::test]
+async fn login_should_reject_bad_credentials() {
+    // Arrange
+    let server = MockServer::start(|_, _| None).await;
+
+    // Act
+    let auth = server.api().login(credentials()).await;
+
+    // Assert
+    assert!(
+        matches!(auth, Err(type_matrux::client::Error::BadCredentials)),
+        "Should report bad credentials, got {auth:?}"
+    );
+}
+
+#[tokio::test]
+async fn builder_config_should_reach_server() {
+    // Arrange
+    let server = MockServer::start(bookmark_routes).await;
+    let client = DrukarniaClient::builder()
+        .base_url(server.base_url.clone())
+        .user_agent("mock-scraper/1.0 (reader@example.com)")
+        .timeout(Duration::from_secs(5))
+        .build()
+        .expect("Should build a client");
+
+    // Act
+    client
+        .popular_tags()
+        .await
+        .expect("Should get popular tags");
+    let auth = ReqwestAuth::from_token(
+        client,
+        authorized_user(),
+        SecretString::new(TOKEN.to_owned()),
+    )
+    .await
+    .expect("Should resume a session");
+    auth.get_bookmark_lists()
+        .await
+        .expect("Should get bookmark lists");
+    drop(auth);
+    // logging out happens in the background
+    let expected = if cfg!(feature = "logout-on-drop") {
+        4
+    } else {
+        3
+    };
+    for _ in 0..100 {
+        if server.requests().len() >= expected {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    // Assert
+    let requests = server.requests();
+    assert_eq!(requests.len(), expected, "{requests:?}");
+    for request in requests {
+        assert_eq!(
+            request.header("user-agent"),
+            Some("mock-scraper/1.0 (reader@example.com)"),
+            "{request:?}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn transport_options_should_reach_server() {
+    // Arrange
+    let server = MockServer::start(bookmark_routes).await;
+    let client = DrukarniaClient::builder()
+        .base_url(server.base_url.clone())
+        .connect_timeout(Duration::from_secs(5))
+        .pool_max_idle_per_host(1)
+        .tcp_keepalive(Duration::from_secs(60))
+        .build()
+        .expect("Should build a client");
+
+    // Act
+    for _ in 0..3 {
+        client
+            .popular_tags()
+            .await
+            .expect("Should get popular tags");
+    }
+
+    // Assert
+    assert_eq!(server.requests().len(), 3);
+}
+
+#[tokio::test]
+async fn http2_prior_knowledge_should_be_applied() {
+    // Arrange
+    // mock server only speaks HTTP/1.1, so requests could only fail if the option reached the client
+    let server = MockServer::start(bookmark_routes).await;
+    let client = DrukarniaClient::builder()
+        .base_url(server.base_url.clone())
+        .http2_prior_knowledge()
+        .timeout(Duration::from_secs(5))
+        .build()
+        .expect("Should build a client");
+
+    // Act
+    let res = client.popular_tags().await;
+
+    // Assert
+    assert!(
+        matches!(res, Err(type_matrux::client::Error::OnExecution(_))),
+        "{res:?}"
+    );
+}
+
+#[tokio::test]
+async fn requests_should_be_spaced() {
+    // Arrange
+    let server = MockServer::start(bookmark_routes).await;
+    let interval = Duration::from_millis(50);
+    let client = DrukarniaClient::builder()
+        .base_url(server.base_url.clone())
+        .min_request_interval(interval)
+        .build()
+        .expect("Should build a client");
+    let clone = client.clone();
+
+    // Act
+    let start = Instant::now();
+    client
+        .popular_tags()
+        .await
+        .expect("Should get popular tags");
+    clone.popular_tags().await.expect("Should get popular tags");
+    client
+        .popular_tags()
+        .await
+        .expect("Should get popular tags");
+
+    // Assert
+    assert!(
+        start.elapsed() >= interval * 2,
+        "Requests were not spaced: {:?}",
+        start.elapsed()
+    );
+}
+
+/// Subset of live data representation tests, served from fixtures
+///
+/// Same tests might be run against any host with `DRUKARNIA_BASE_URL` set, see `tests/reqwest.rs`.
+mod data_representation {
+    use std::num::NonZeroUsize;
+
+    use futures::StreamExt;
+    use type_matrux::{
+        client::{ArticlesApi, CommentsApi, DrukarniaClient, Error, TagsApi, UsersApi},
+        object::CommentId,
+        DrukarniaApi,
+    };
+
+    use crate::{MockServer, Reply};
+
+    /// Mimics site's anonymous endpoints
+    fn site_routes(method: &str, path: &str) -> Option<Reply> {
+        if method != "GET" {
+            return None;
+        }
+        let fixture = match path {
+            "/api/articles/tags/popular" => "popular_tags.json",
+            "/api/articles/search" => "search/recommended_page.json",
+            "/api/users/info" => "users/search.json",
+            path if path.starts_with("/api/users/profile/") => "full_user.json",
+            path if path.starts_with("/api/relationships/") && path.ends_with("/followers") => {
+                "users/followers.json"
+            }
+            path if path.starts_with("/api/articles/000000000000000000000000/comments/")
+                && path.ends_with("/replies") =>
+            {
+                "comments/replies.json"
+            }
+            path if path.starts_with("/api/articles/") => "full_article.json",
+            _ => return None,
+        };
+        Some(Reply::fixture(fixture))
+    }
+
+    fn nonzero_one() -> NonZeroUsize {
+        NonZeroUsize::new(1).expect("1 != 0")
+    }
+
+    #[tokio::test]
+    async fn popular_tags_should_succeed() {
+        let server = MockServer::start(site_routes).await;
+
+        let popular_tags = server.api().popular_tags().await;
+
+        assert!(
+            popular_tags.is_ok(),
+            "Should be able to query popular tags: {}",
+            popular_tags.unwrap_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn get_user_should_succeed() {
+        let server = MockServer::start(site_routes).await;
+
+        let user_name = "OstanniyCapitalist"
+            .parse()
+            .expect("Should be a valid username");
+        let user = server.api().get_user(&user_name).await;
+
+        assert!(
+            user.is_ok(),
+            "Should be able to query user by username: {}",
+            user.unwrap_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn get_article_should_succeed() {
+        let server = MockServer::start(site_routes).await;
+
+        let article_slug = "gitlab-istoriya-uspikhu-ukrayinskogo-konkurenta-github-t7agP"
+            .parse()
+            .expect("Should be valid article slug");
+        let article = server.api().get_article(&article_slug).await;
+
+        assert!(
+            article.is_ok(),
+            "Should be able to get article by slug: {}",
+            article.unwrap_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn search_article_should_succeed() {
+        let server = MockServer::start(site_routes).await;
+
+        let article_name = "Дія".parse().expect("Should be a valid article name");
+        let articles = server
+            .api()
+            .search_article_page(&article_name, nonzero_one())
+            .await;
+
+        assert!(
+            articles.is_ok(),
+            "Should be able to search articles: {}",
+            articles.unwrap_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn search_user_should_request_relationships() {
+        // Arrange
+        let server = MockServer::start(site_routes).await;
+        let user_name = "maria".parse().expect("Should be a valid username");
+
+        // Act
+        let users = server
+            .api()
+            .search_user_page(&user_name, nonzero_one())
+            .await;
+
+        // Assert
+        let users = users.expect("Should be able to search users");
+        assert_eq!(users.len(), 2);
+        assert!(*users[0].relationships().is_subscribed());
+        assert!(server.requests()[0].path.contains("withRelationships=true"));
+    }
+
+    #[tokio::test]
+    async fn plain_search_user_should_not_request_relationships() {
+        // Arrange
+        let server = MockServer::start(|method, path| match (method, path) {
+            ("GET", "/api/users/info") => Some(Reply::fixture("users/search_plain.json")),
+            _ => None,
+        })
+        .await;
+        let user_name = "maria".parse().expect("Should be a valid username");
+
+        // Act
+        let users: Vec<_> = server
+            .api()
+            .search_user_plain(user_name)
+            .flat()
+            .collect()
+            .await;
+
+        // Assert
+        assert_eq!(users.len(), 2);
+        let maria = users[0].as_ref().expect("Should be able to search users");
+        assert_eq!(maria.username().as_ref(), "maria");
+        let requests = server.requests();
+        // page is shorter than the default page size, so it's the last one
+        assert_eq!(requests.len(), 1);
+        assert!(!requests[0].path.contains("withRelationships"));
+    }
+
+    #[tokio::test]
+    async fn short_page_should_end_stream() {
+        // Arrange
+        let server = MockServer::start(site_routes).await;
+        let article_name = "Дія".parse().expect("Should be a valid article name");
+
+        // Act
+        let api = server.api();
+        let articles: Vec<_> = api.search_article(article_name).flat().collect().await;
+
+        // Assert
+        // fixture page is shorter than the default page size, so the next one is not requested
+        assert_eq!(articles.len(), 3);
+        assert!(articles.iter().all(Result::is_ok));
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn full_page_should_not_be_known_as_last() {
+        // Arrange
+        let server = MockServer::start(site_routes).await;
+        let article_name = "Дія".parse().expect("Should be a valid article name");
+        let api = DrukarniaClient::builder()
+            .base_url(server.base_url.clone())
+            .page_size(3)
+            .build()
+            .expect("Should build a client");
+
+        // Act
+        let page = api
+            .search_article_page_meta(&article_name, nonzero_one())
+            .await
+            .expect("Should be able to search articles");
+
+        // Assert
+        assert_eq!(page.items().len(), 3);
+        assert_eq!(*page.has_more(), None);
+        assert_eq!(page.index().get(), 1);
+    }
+
+    #[tokio::test]
+    async fn get_followers_should_succeed() {
+        let server = MockServer::start(site_routes).await;
+
+        let user_id = "643af9fc1272bd9066a1ffdb"
+            .parse()
+            .expect("Should be a valid id");
+        let followers = server
+            .api()
+            .get_followers_page(&user_id, nonzero_one())
+            .await;
+
+        assert!(
+            followers.is_ok(),
+            "Should be able to get followers: {}",
+            followers.unwrap_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn get_replies_should_succeed() {
+        let server = MockServer::start(site_routes).await;
+
+        let comment_id = "651ae7dc280f4421026b12c5"
+            .parse()
+            .expect("Should be a valid id");
+        let replies = server.api().get_replies(&comment_id).await;
+
+        assert!(
+            replies.is_ok(),
+            "Should be able to get replies: {}",
+            replies.unwrap_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn get_author_portfolio_should_aggregate_articles() {
+        let server = MockServer::start(site_routes).await;
+
+        let user_name = "OstanniyCapitalist"
+            .parse()
+            .expect("Should be a valid username");
+        let portfolio = server.api().get_author_portfolio(&user_name).await;
+
+        let portfolio = portfolio.expect("Should be able to get author portfolio");
+        assert_eq!(*portfolio.total_likes(), 52, "Should sum up likes");
+        assert_eq!(*portfolio.total_comments(), 2, "Should sum up comments");
+        assert_eq!(*portfolio.total_reads(), 15230, "Should take user's reads");
+        let tag = "tekhnologiyi".parse().expect("Should be a valid tag slug");
+        assert_eq!(
+            portfolio.articles_per_tag().get(&tag),
+            Some(&1),
+            "Should count articles per tag"
+        );
+    }
+
+    #[cfg(feature = "rss")]
+    #[tokio::test]
+    async fn author_rss_should_link_to_accessor_host() {
+        let server = MockServer::start(site_routes).await;
+
+        let user_name = "OstanniyCapitalist"
+            .parse()
+            .expect("Should be a valid username");
+        let feed = server.api().author_rss(&user_name).await;
+
+        let feed = feed.expect("Should be able to render author's feed");
+        let link = format!(
+            "<link>{}articles/rust-dlya-pochatkivciv-a1b2C</link>",
+            server.base_url
+        );
+        assert!(
+            feed.contains(&link),
+            "Articles should link to accessor's host: {feed}"
+        );
+    }
+
+    #[tokio::test]
+    async fn get_article_with_replies_should_attach_replies() {
+        let server = MockServer::start(site_routes).await;
+
+        let slug = "some-article-t7agP"
+            .parse()
+            .expect("Should be a valid slug");
+        let article = server.api().get_article_with_replies(&slug, 4).await;
+
+        let article = article.expect("Should be able to get article with replies");
+        assert!(
+            article.partial_errors().is_empty(),
+            "Should fetch all replies"
+        );
+        let reply_count: usize = article
+            .thread()
+            .roots()
+            .iter()
+            .map(|root| root.reply_count())
+            .sum();
+        assert_eq!(
+            reply_count + article.thread().orphans().len(),
+            4,
+            "Should keep all of the replies"
+        );
+        assert_eq!(
+            server.requests().len(),
+            2,
+            "Should only request replies for comments that have them"
+        );
+    }
+
+    #[tokio::test]
+    async fn get_article_with_replies_should_report_failed_comments() {
+        fn no_replies(method: &str, path: &str) -> Option<Reply> {
+            if path.ends_with("/replies") {
+                return Some(Reply::json(401, "{}".to_string()));
+            }
+            site_routes(method, path)
+        }
+        let server = MockServer::start(no_replies).await;
+
+        let slug = "some-article-t7agP"
+            .parse()
+            .expect("Should be a valid slug");
+        let article = server.api().get_article_with_replies(&slug, 0).await;
+
+        let article = article.expect("Should not fail on replies");
+        let comment_id: CommentId = "651ae7dc280f4421026b12c5"
+            .parse()
+            .expect("Should be a valid id");
+        assert!(
+            matches!(
+                article.partial_errors().as_slice(),
+                [(id, Error::NoObject)] if *id == comment_id
+            ),
+            "Should report the failed comment: {:?}",
+            article.partial_errors()
+        );
+        assert_eq!(
+            article.thread().roots().len(),
+            article.article().comments().len(),
+            "Should still keep the comments"
+        );
+    }
+}
+
+/// Anonymous feed, kept consistent with the cookies site sets on it
+mod feed_session {
+    use std::num::NonZeroUsize;
+
+    use futures::StreamExt;
+    use type_matrux::client::{Error, FeedApi, FeedSession};
+
+    use crate::{MockServer, Reply};
+
+    fn feed_routes(method: &str, path: &str) -> Option<Reply> {
+        (method == "GET" && path == "/api/preferences/feed").then(|| {
+            Reply::fixture("feed/page.json")
+                .with_header("Set-Cookie", "feed=abc; Path=/; HttpOnly")
+                .with_header("Set-Cookie", "seed=42; Max-Age=3600")
+        })
+    }
+
+    fn nonzero_one() -> NonZeroUsize {
+        NonZeroUsize::new(1).expect("1 != 0")
+    }
+
+    #[tokio::test]
+    async fn feed_session_should_capture_cookies() {
+        // Arrange
+        let server = MockServer::start(feed_routes).await;
+
+        // Act
+        let session = server.api().feed_session().await;
+
+        // Assert
+        let session = session.expect("Should establish a session");
+        assert_eq!(session.cookie(), Some("feed=abc; seed=42"));
+        assert!(server.requests()[0].header("Cookie").is_none());
+    }
+
+    #[tokio::test]
+    async fn feed_session_should_keep_first_page() {
+        // Arrange
+        let server = MockServer::start(feed_routes).await;
+        let api = server.api();
+        let session = api
+            .feed_session()
+            .await
+            .expect("Should establish a session");
+
+        // Act
+        let first = api.feed_page_with(&session, nonzero_one()).await;
+
+        // Assert
+        let first = first.expect("Should serve first page");
+        assert_eq!(first.len(), 2);
+        assert_eq!(session.first_page(), Some(first.as_slice()));
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn feed_pages_within_session_should_send_cookies() {
+        // Arrange
+        let server = MockServer::start(feed_routes).await;
+        let api = server.api();
+        let session = api
+            .feed_session()
+            .await
+            .expect("Should establish a session");
+        let second_page = NonZeroUsize::new(2).expect("2 != 0");
+
+        // Act
+        let first = api.feed_page_with(&session, second_page).await;
+        let second = api.feed_page_with(&session, second_page).await;
+
+        // Assert
+        let ids = |page: Vec<type_matrux::object::FeedArticle>| {
+            page.into_iter().map(|a| a.id().clone()).collect::<Vec<_>>()
+        };
+        let first = ids(first.expect("Should load feed page"));
+        let second = ids(second.expect("Should load feed page"));
+        assert_eq!(first.len(), 2);
+        assert_eq!(first, second);
+        let requests = server.requests();
+        assert_eq!(requests.len(), 3);
+        for request in &requests[1..] {
+            assert_eq!(request.header("Cookie"), Some("feed=abc; seed=42"));
+            assert!(request.path.ends_with("page=2"), "{}", request.path);
+        }
+    }
+
+    #[tokio::test]
+    async fn feed_in_session_should_start_from_session_page() {
+        // Arrange
+        let server = MockServer::start(feed_routes).await;
+        let api = server.api();
+
+        // Act
+        let articles: Vec<_> = api
+            .feed_in_session()
+            .await
+            .expect("Should establish a session")
+            .flat()
+            .take(3)
+            .collect()
+            .await;
+
+        // Assert
+        assert_eq!(articles.len(), 2, "Fixture page is shorter than a full one");
+        assert!(articles.iter().all(Result::is_ok), "{articles:?}");
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1, "First page should come from the session");
+        assert!(requests[0].header("Cookie").is_none());
+    }
+
+    #[tokio::test]
+    async fn unexpected_feed_status_should_be_an_error() {
+        // Arrange
+        let server = MockServer::start(|_, _| Some(Reply::json(503, "{}".to_owned()))).await;
+        let api = server.api();
+
+        // Act
+        let session = api.feed_session().await;
+        let page = api
+            .feed_page_with(&FeedSession::default(), nonzero_one())
+            .await;
+
+        // Assert
+        assert!(matches!(session, Err(Error::OnExecution(_))), "{session:?}");
+        assert!(matches!(page, Err(Error::OnExecution(_))), "{page:?}");
+    }
+
+    #[tokio::test]
+    async fn default_session_should_send_no_cookies() {
+        // Arrange
+        let server = MockServer::start(feed_routes).await;
+
+        // Act
+        let page = server
+            .api()
+            .feed_page_with(&FeedSession::default(), nonzero_one())
+            .await;
+
+        // Assert
+        assert!(page.is_ok(), "{page:?}");
+        assert!(server.requests()[0].header("Cookie").is_none());
+        assert!(!FeedSession::default().is_established());
+        assert!(!FeedSession::new("").is_established());
+    }
+}
+
+/// Usernames and slugs are sent as a single path segment, whatever they contain
+mod path_segments {
+    use type_matrux::{
+        client::{ArticlesApi, Error, TagsApi, UsersApi},
+        object::{ArticleSlug, TagSlug, UserName},
+    };
+
+    use crate::{MockServer, Reply};
+
+    fn object_routes(method: &str, path: &str) -> Option<Reply> {
+        if method != "GET" {
+            return None;
+        }
+        let fixture = match path {
+            path if path.starts_with("/api/users/profile/") => "full_user.json",
+            path if path.starts_with("/api/articles/tags/") => "tags/full_tag.json",
+            path if path.starts_with("/api/articles/") => "full_article.json",
+            _ => return None,
+        };
+        Some(Reply::fixture(fixture))
+    }
+
+    const SEGMENTS: &[(&str, &str)] = &[
+        ("some/thing", "some%2Fthing"),
+        ("some thing", "some%20thing"),
+        ("100%", "100%25"),
+        ("що?#де", "%D1%89%D0%BE%3F%23%D0%B4%D0%B5"),
+    ];
+
+    #[tokio::test]
+    async fn usernames_should_be_percent_encoded() {
+        for (name, encoded) in SEGMENTS {
+            // Arrange
+            let server = MockServer::start(object_routes).await;
+            let name: UserName = name.parse().unwrap_or_else(|never| match never {});
+
+            // Act
+            let user = server.api().get_user(&name).await;
+
+            // Assert
+            assert!(user.is_ok(), "{user:?}");
+            assert_eq!(
+                server.requests()[0].path,
+                format!("/api/users/profile/{encoded}")
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn slugs_should_be_percent_encoded() {
+        for (slug, encoded) in SEGMENTS {
+            // Arrange
+            let server = MockServer::start(object_routes).await;
+            let article_slug: ArticleSlug = slug.parse().unwrap_or_else(|never| match never {});
+            let tag_slug: TagSlug = slug.parse().unwrap_or_else(|never| match never {});
+
+            // Act
+            let article = server.api().get_article(&article_slug).await;
+            let tag = server.api().get_tag(&tag_slug).await;
+
+            // Assert
+            assert!(article.is_ok(), "{article:?}");
+            assert!(tag.is_ok(), "{tag:?}");
+            let requests = server.requests();
+            assert_eq!(requests[0].path, format!("/api/articles/{encoded}"));
+            assert_eq!(
+                requests[1].path,
+                format!("/api/articles/tags/{encoded}?page=1")
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn browser_url_should_be_encoded_once() {
+        // Arrange
+        let server = MockServer::start(object_routes).await;
+        let url = server
+            .base_url
+            .join("articles/що-таке-rust-abc12")
+            .expect("Should be a valid url");
+
+        // Act
+        let article = server.api().get_article_by_url(&url).await;
+
+        // Assert
+        assert!(article.is_ok(), "{article:?}");
+        assert_eq!(
+            server.requests()[0].path,
+            "/api/articles/%D1%89%D0%BE-%D1%82%D0%B0%D0%BA%D0%B5-rust-abc12"
+        );
+    }
+
+    #[tokio::test]
+    async fn dot_segments_should_not_change_endpoint() {
+        // Arrange
+        let server = MockServer::start(object_routes).await;
+
+        for segment in ["", ".", ".."] {
+            // Act
+            let user = server
+                .api()
+                .get_user(&segment.parse().unwrap_or_else(|never| match never {}))
+                .await;
+
+            // Assert
+            assert!(
+                matches!(user, Err(Error::NoObject)),
+                "{segment:?}: {user:?}"
+            );
+        }
+        assert!(server.requests().is_empty());
+    }
+}
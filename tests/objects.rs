@@ -0,0 +1,2626 @@
+//! Offline tests for data objects.
+//!
+//! Unlike `tests/reqwest.rs`, these do not require network access - they deserialize checked-in fixtures
+//! from `tests/fixtures/` instead.
+
+mod support;
+
+use support::{fixture, fixture_text};
+
+mod content {
+    use std::num::NonZeroUsize;
+
+    use type_matrux::object::{ArticleContent, ContentBlock, FullArticle, MaybeUrl};
+
+    use crate::{fixture, fixture_text};
+
+    #[test]
+    fn all_block_types_should_be_typed() {
+        // Arrange
+        let content: ArticleContent = fixture("content/all_blocks.json");
+
+        // Act
+        let blocks = content.blocks();
+
+        // Assert
+        assert_eq!(blocks.len(), 10);
+        assert!(matches!(&blocks[0], ContentBlock::Heading { level: 2, text } if text == "Вступ"));
+        assert!(
+            matches!(&blocks[1], ContentBlock::Paragraph { text } if text.contains("<b>платформа</b>"))
+        );
+        assert!(matches!(
+            &blocks[2],
+            ContentBlock::Image { url: MaybeUrl::Url(_), caption: Some(caption) } if caption == "Логотип GitLab"
+        ));
+        assert!(
+            matches!(&blocks[3], ContentBlock::Quote { caption: Some(caption), .. } if caption == "GitLab")
+        );
+        assert!(matches!(&blocks[4], ContentBlock::Code { code } if code.starts_with("git clone")));
+        assert!(
+            matches!(&blocks[5], ContentBlock::List { ordered: true, items } if items.len() == 2)
+        );
+        assert!(
+            matches!(&blocks[6], ContentBlock::List { ordered: false, items } if items == &["Вкладений пункт"])
+        );
+        assert!(matches!(
+            &blocks[7],
+            ContentBlock::Embed { service, caption: None, .. } if service == "youtube"
+        ));
+        assert!(matches!(&blocks[8], ContentBlock::Delimiter));
+        assert!(matches!(&blocks[9], ContentBlock::Unknown { r#type, .. } if r#type == "warning"));
+    }
+
+    #[test]
+    fn raw_content_should_be_kept() {
+        // Arrange
+        let content: ArticleContent = fixture("content/all_blocks.json");
+
+        // Act
+        let raw = content.raw();
+
+        // Assert
+        assert_eq!(raw["version"], "2.26.5");
+        assert_eq!(raw["blocks"].as_array().map(Vec::len), Some(10));
+    }
+
+    #[test]
+    fn block_array_with_bad_image_url_should_parse() {
+        // Arrange
+        let content: ArticleContent = fixture("content/simple_image.json");
+
+        // Act
+        let blocks = content.blocks();
+
+        // Assert
+        assert_eq!(blocks.len(), 2);
+        assert!(matches!(
+            &blocks[1],
+            ContentBlock::Image { url: MaybeUrl::BadUrl(source, _), .. } if source == "not a url"
+        ));
+    }
+
+    #[test]
+    fn full_article_content_should_be_typed() {
+        // Arrange
+        let article: FullArticle = fixture("full_article.json");
+
+        // Act
+        let content = article.content();
+
+        // Assert
+        assert_eq!(content.blocks().len(), 4);
+        assert!(content
+            .into_iter()
+            .all(|block| !matches!(block, ContentBlock::Unknown { .. })));
+    }
+
+    #[test]
+    fn malformed_known_block_should_error() {
+        // Arrange
+        let json = r#"[{"type": "header", "data": {"text": "No level here"}}]"#;
+
+        // Act
+        let content = serde_json::from_str::<ArticleContent>(json);
+
+        // Assert
+        assert!(content.is_err(), "Heading without a level should not parse");
+    }
+
+    #[test]
+    fn markdown_should_match_golden_files() {
+        for name in ["all_blocks", "escaping"] {
+            // Arrange
+            let content: ArticleContent = fixture(&format!("content/{name}.json"));
+            let expected = fixture_text(&format!("content/{name}.md"));
+
+            // Act
+            let markdown = content.to_markdown();
+
+            // Assert
+            assert_eq!(markdown, expected, "Markdown for {name} does not match");
+        }
+    }
+
+    #[test]
+    fn html_should_match_golden_files() {
+        for name in ["all_blocks", "escaping"] {
+            // Arrange
+            let content: ArticleContent = fixture(&format!("content/{name}.json"));
+            let expected = fixture_text(&format!("content/{name}.html"));
+
+            // Act
+            let html = content.to_html();
+
+            // Assert
+            assert_eq!(html, expected, "HTML for {name} does not match");
+        }
+    }
+
+    #[test]
+    fn html_should_not_pass_unsafe_markup() {
+        // Arrange
+        let json = r#"[
+            {"type": "paragraph", "data": {"text": "<script>alert(1)</script><img src=x onerror=alert(2)><a href=\"javascript:alert(3)\">link</a>"}},
+            {"type": "image", "data": {"url": "javascript:alert(4)", "caption": "\" onload=\"alert(5)"}}
+        ]"#;
+        let content: ArticleContent = serde_json::from_str(json).expect("Should parse");
+
+        // Act
+        let html = content.to_html();
+
+        // Assert
+        assert_eq!(
+            html,
+            "<p>alert(1)link</p>\n<figure><figcaption>&quot; onload=&quot;alert(5)</figcaption></figure>\n"
+        );
+    }
+
+    #[test]
+    fn unknown_block_should_not_be_dropped_from_markdown() {
+        // Arrange
+        let json = r#"[{"type": "spoiler", "data": {"text": "a --> b"}}]"#;
+        let content: ArticleContent = serde_json::from_str(json).expect("Should parse");
+
+        // Act
+        let markdown = content.to_markdown();
+
+        // Assert
+        assert_eq!(markdown, "<!-- spoiler: {\"text\":\"a --&gt; b\"} -->\n");
+    }
+
+    #[test]
+    fn plain_text_should_skip_markup_and_media() {
+        // Arrange
+        let article: FullArticle = fixture("full_article.json");
+
+        // Act
+        let text = article.content().plain_text();
+
+        // Assert
+        assert!(text.starts_with("Вступ\n\nGitLab — це платформа для"));
+        assert!(!text.contains('<'), "Markup should be stripped: {text}");
+        assert!(
+            !text.contains("Логотип"),
+            "Image captions should be skipped: {text}"
+        );
+    }
+
+    #[test]
+    fn word_count_should_handle_mixed_scripts() {
+        // Arrange
+        let article: FullArticle = fixture("full_article.json");
+        let with_image: ArticleContent = fixture("content/simple_image.json");
+
+        // Act
+        let article_words = article.content().word_count();
+        let image_words = with_image.word_count();
+
+        // Assert
+        assert_eq!(article_words, 21);
+        assert_eq!(image_words, 4);
+    }
+
+    #[test]
+    fn estimated_read_time_should_follow_word_count() {
+        // Arrange
+        let article: FullArticle = fixture("full_article.json");
+        let wpm = NonZeroUsize::new(60).expect("60 != 0");
+
+        // Act
+        let estimate = article.estimated_read_time(wpm);
+
+        // Assert
+        assert_eq!(estimate, time::Duration::seconds(21));
+    }
+
+    fn sources(urls: &[MaybeUrl]) -> Vec<String> {
+        urls.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn content_images_should_be_collected() {
+        // Arrange
+        let content: ArticleContent = fixture("content/links.json");
+
+        // Act
+        let images = content.images();
+
+        // Assert
+        assert_eq!(
+            sources(&images),
+            [
+                "https://i.imgur.com/schema.png",
+                "https://images.drukarnia.com.ua/articles/cover-1.png",
+                "images/local.png",
+            ]
+        );
+        assert!(matches!(&images[2], MaybeUrl::BadUrl(..)));
+    }
+
+    #[test]
+    fn content_links_should_be_collected() {
+        // Arrange
+        let content: ArticleContent = fixture("content/links.json");
+
+        // Act
+        let links = content.links();
+
+        // Assert
+        assert_eq!(
+            sources(&links),
+            [
+                "https://gitlab.com/",
+                "https://drukarnia.com.ua/@maria",
+                "https://unsplash.com/photos/abc",
+                "https://docs.rs/time?search=a&b",
+                "http://exa mple.com",
+                "https://www.youtube.com/watch?v=dQw4w9WgXcQ",
+                "https://files.example.com/report.pdf",
+            ]
+        );
+        let bad: Vec<_> = links
+            .iter()
+            .filter(|link| link.as_url().is_none())
+            .collect();
+        assert_eq!(bad.len(), 1, "Malformed href should be kept: {bad:?}");
+    }
+
+    #[test]
+    fn external_links_should_skip_site_and_add_article_links() {
+        // Arrange
+        let mut article: serde_json::Value = fixture("full_article.json");
+        article["content"] = fixture("content/links.json");
+        article["canonical"] = "https://medium.com/@ostap/original".into();
+        let article: FullArticle =
+            serde_json::from_value(article).expect("Article should deserialize");
+
+        // Act
+        let links = article.external_links();
+
+        // Assert
+        let links = sources(&links);
+        assert_eq!(links.len(), 8, "{links:?}");
+        assert!(!links.iter().any(|link| link.contains("drukarnia.com.ua/@")));
+        assert_eq!(
+            &links[links.len() - 2..],
+            [
+                "https://medium.com/@ostap/original",
+                "https://send.monobank.ua/jar/ostap",
+            ]
+        );
+    }
+}
+
+mod comments {
+    use type_matrux::object::{
+        ArticleComment, CommentListExt, CommentThread, FullArticle, ReplyComment, UserName,
+    };
+
+    use crate::{fixture, fixture_text};
+
+    fn comment_with_html(html: &str) -> ArticleComment {
+        let mut value: serde_json::Value =
+            serde_json::from_str(&fixture_text("corpus/article_comment.json"))
+                .expect("Fixture should be JSON");
+        value["comment"] = html.into();
+        serde_json::from_value(value).expect("Comment should deserialize")
+    }
+
+    fn comment_from_json_text(html: &str) -> ArticleComment {
+        let mut value: serde_json::Value =
+            serde_json::from_str(&fixture_text("corpus/article_comment.json"))
+                .expect("Fixture should be JSON");
+        value["comment"] = html.into();
+        let text = serde_json::to_string(&value).expect("Comment should serialize");
+        serde_json::from_str(&text).expect("Comment should deserialize")
+    }
+
+    fn names(names: &[&str]) -> Vec<UserName> {
+        names
+            .iter()
+            .map(|name| name.parse().expect("Should be a valid username"))
+            .collect()
+    }
+
+    #[test]
+    fn comment_text_should_be_flattened() {
+        // Arrange
+        let article: FullArticle = fixture("full_article.json");
+
+        // Act
+        let texts: Vec<String> = article.comments().iter().map(|c| c.text()).collect();
+
+        // Assert
+        assert_eq!(texts, ["Дуже цікаво, дякую!", "А як щодо GitHub?"]);
+    }
+
+    #[test]
+    fn reply_text_should_handle_nesting_and_entities() {
+        // Arrange
+        let replies: Vec<ReplyComment> = fixture("comments/replies.json");
+
+        // Act
+        let texts: Vec<String> = replies.iter().map(|r| r.text()).collect();
+
+        // Assert
+        assert_eq!(texts[0], "@maria згоден! Деталі тут & там");
+        assert_eq!(texts[1], "Третій рівень — вкладеність працює");
+        assert_eq!(
+            texts[2],
+            "@taras, дякую дуже!\nДругий абзац\nз переносом, @maria"
+        );
+    }
+
+    #[test]
+    fn mentions_should_be_extracted_from_links() {
+        // Arrange
+        let replies: Vec<ReplyComment> = fixture("comments/replies.json");
+
+        // Act
+        let mentions: Vec<Vec<UserName>> = replies.iter().map(|r| r.mentions()).collect();
+
+        // Assert
+        // regular links are not mentions
+        assert_eq!(mentions[0], names(&["maria"]));
+        assert_eq!(mentions[1], names(&[]));
+        assert_eq!(mentions[2], names(&["taras", "maria"]));
+    }
+
+    #[test]
+    fn thread_should_nest_replies_and_collect_orphans() {
+        // Arrange
+        let article: FullArticle = fixture("full_article.json");
+        let replies: Vec<ReplyComment> = fixture("comments/replies.json");
+
+        // Act
+        let thread = CommentThread::build(article.comments().clone(), replies);
+
+        // Assert
+        let roots = thread.roots();
+        assert_eq!(roots.len(), 2);
+        assert_eq!(roots[0].reply_count(), 3);
+        assert_eq!(roots[1].reply_count(), 0);
+        let first = &roots[0].replies()[0];
+        let second = &first.replies()[0];
+        let third = &second.replies()[0];
+        assert_eq!(first.comment().id().to_string(), "651ae7dc280f4421026b12d1");
+        assert_eq!(
+            second.comment().id().to_string(),
+            "651ae7dc280f4421026b12d2"
+        );
+        assert_eq!(third.comment().id().to_string(), "651ae7dc280f4421026b12d3");
+        assert!(third.replies().is_empty());
+        assert_eq!(thread.orphans().len(), 1);
+        assert_eq!(
+            thread.orphans()[0].id().to_string(),
+            "651ae7dc280f4421026b12d4"
+        );
+        assert!(roots.iter().all(|root| !root.is_hidden()));
+    }
+
+    #[test]
+    fn hidden_and_blocked_comments_should_not_be_visible() {
+        // Arrange
+        let comments: Vec<ArticleComment> = fixture("comments/moderated.json");
+
+        // Act
+        let visible: Vec<String> = comments
+            .visible()
+            .map(|comment| comment.id().to_string())
+            .collect();
+
+        // Assert
+        assert!(!comments[0].is_visible());
+        assert!(comments[1].is_visible());
+        assert!(!comments[2].is_visible());
+        assert_eq!(visible, ["651ae7dc280f4421026b12c6"]);
+    }
+
+    #[test]
+    fn thread_should_mark_hidden_subtrees() {
+        // Arrange
+        let comments: Vec<ArticleComment> = fixture("comments/moderated.json");
+        let replies: Vec<ReplyComment> = fixture("comments/replies.json");
+
+        // Act
+        let thread = CommentThread::build(comments, replies);
+
+        // Assert
+        let roots = thread.roots();
+        assert!(roots[0].is_hidden());
+        assert!(!roots[1].is_hidden());
+        assert!(roots[2].is_hidden());
+        let first = &roots[0].replies()[0];
+        assert!(first.comment().is_visible());
+        assert!(first.is_hidden());
+        assert!(first.replies()[0].replies()[0].is_hidden());
+    }
+
+    #[test]
+    fn visible_comment_count_should_match_server() {
+        // Arrange
+        let article: FullArticle = fixture("full_article.json");
+
+        // Act
+        let count = article.visible_comment_count();
+
+        // Assert
+        assert_eq!(count, *article.comment_num());
+    }
+
+    #[test]
+    fn comment_html_should_be_parsed_on_access() {
+        // Arrange
+        let comment = comment_with_html("<p>Привіт, <b>світе</b>!</p>");
+        let untouched = comment.clone();
+
+        // Act
+        let text = comment.text();
+
+        // Assert
+        assert_eq!(text, "Привіт, світе!");
+        assert!(comment.comment().is_parsed());
+        assert!(!untouched.comment().is_parsed());
+        assert_eq!(comment, untouched);
+    }
+
+    #[test]
+    fn unescaped_comment_html_should_deserialize() {
+        // Arrange
+        let html = "<p>Привіт, <b>світе</b>!</p>";
+
+        // Act
+        let comment = comment_from_json_text(html);
+
+        // Assert
+        assert_eq!(comment.comment().source(), html);
+        assert_eq!(comment.text(), "Привіт, світе!");
+    }
+
+    #[test]
+    fn escaped_comment_html_should_deserialize() {
+        // Arrange
+        let html = "<p><a href=\"/@maria\">@maria</a>\n\t\\ <i>інші</i></p>";
+
+        // Act
+        let comment = comment_from_json_text(html);
+
+        // Assert
+        assert_eq!(comment.comment().source(), html);
+        assert!(comment.comment().dom().is_ok());
+        assert_eq!(comment.mentions(), names(&["maria"]));
+    }
+
+    #[test]
+    fn invalid_comment_html_should_fail_on_access() {
+        // Arrange
+        let html = "<p>Дивіться</p><!-- незакритий коментар";
+
+        // Act
+        let comment = comment_with_html(html);
+
+        // Assert
+        let err = comment
+            .comment()
+            .dom()
+            .expect_err("Should not be valid HTML");
+        assert!(err.to_string().contains("not valid HTML"), "{err}");
+        assert_eq!(comment.comment().source(), html);
+        // falls back to plain text
+        assert_eq!(comment.text(), html);
+        assert!(comment.mentions().is_empty());
+    }
+
+    #[test]
+    fn comment_html_should_serialize_as_is() {
+        // Arrange
+        let html = "<p><a href=\"/@maria\">@maria</a> &amp; <i>інші</i></p>";
+        let comment = comment_with_html(html);
+        comment.text();
+
+        // Act
+        let value = serde_json::to_value(&comment).expect("Should serialize");
+
+        // Assert
+        assert_eq!(value["comment"], html);
+    }
+}
+
+mod notifications {
+    use serde_json::Value;
+    use type_matrux::object::{FullNotification, NotificationKind};
+
+    use crate::fixture;
+
+    /// Asserts that all of the fixture fields are known to the model, and survive serialization
+    fn assert_fields_known(name: &str) {
+        let source: Value = fixture(name);
+        let parsed: FullNotification = fixture(name);
+        let serialized = serde_json::to_value(parsed).expect("Should be able to serialize");
+        let keys = |value: &Value| {
+            let mut keys: Vec<String> = value
+                .as_object()
+                .expect("Notification should be an object")
+                .keys()
+                .cloned()
+                .collect();
+            keys.sort();
+            keys
+        };
+        assert_eq!(keys(&source), keys(&serialized));
+        if let Some(details) = source
+            .get("details")
+            .filter(|d| !d.as_object().unwrap().is_empty())
+        {
+            assert_eq!(keys(details), keys(&serialized["details"]));
+        }
+    }
+
+    #[test]
+    fn new_follower_should_parse() {
+        // Arrange, Act
+        let notification: FullNotification = fixture("notifications/new_follower.json");
+
+        // Assert
+        assert_eq!(notification.kind(), &NotificationKind::NewFollower);
+        assert!(!notification.seen());
+        let action_owner = notification
+            .details()
+            .as_ref()
+            .and_then(|details| details.action_owner().as_ref())
+            .expect("Action owner should be present");
+        assert_eq!(action_owner.username().as_ref(), "maria");
+        assert_fields_known("notifications/new_follower.json");
+    }
+
+    #[test]
+    fn comment_reply_should_parse() {
+        // Arrange, Act
+        let notification: FullNotification = fixture("notifications/comment_reply.json");
+
+        // Assert
+        assert_eq!(notification.kind(), &NotificationKind::CommentReply);
+        assert!(notification.seen());
+        assert_eq!(notification.id().to_string(), "652a10c0280f4421027a0002");
+        assert_fields_known("notifications/comment_reply.json");
+    }
+
+    #[test]
+    fn empty_details_should_parse() {
+        // Arrange, Act
+        let notification: FullNotification = fixture("notifications/mention.json");
+
+        // Assert
+        assert_eq!(notification.kind(), &NotificationKind::Mention);
+        let details = notification
+            .details()
+            .as_ref()
+            .expect("Details should be present");
+        assert!(details.action_owner().is_none());
+        assert_fields_known("notifications/mention.json");
+    }
+
+    #[test]
+    fn unknown_kind_without_details_should_parse() {
+        // Arrange, Act
+        let notification: FullNotification = fixture("notifications/unknown_kind.json");
+
+        // Assert
+        assert_eq!(notification.kind(), &NotificationKind::Other(42));
+        assert!(notification.details().is_none());
+        assert_fields_known("notifications/unknown_kind.json");
+    }
+
+    #[test]
+    fn kind_should_serialize_as_number() {
+        // Arrange
+        let kinds = [
+            NotificationKind::NewFollower,
+            NotificationKind::ArticleLiked,
+            NotificationKind::CommentReply,
+            NotificationKind::CommentLiked,
+            NotificationKind::Mention,
+            NotificationKind::Other(42),
+        ];
+
+        for kind in kinds {
+            // Act
+            let serialized = serde_json::to_value(kind).expect("Should be able to serialize");
+            let parsed: NotificationKind =
+                serde_json::from_value(serialized.clone()).expect("Should deserialize back");
+
+            // Assert
+            assert!(serialized.is_u64());
+            assert_eq!(parsed, kind);
+        }
+    }
+}
+
+mod users {
+    use type_matrux::object::{
+        AuthorizedUser, FollowerUser, FullUser, MaybeUrl, PlainShortUser, Relationships, ShortUser,
+        SocialPlatform, UserSocials,
+    };
+
+    use crate::fixture;
+
+    #[test]
+    fn socials_should_match_keys_case_insensitively() {
+        // Arrange, Act
+        let user: FullUser = fixture("full_user.json");
+        let socials = user.socials();
+
+        // Assert
+        assert!(
+            matches!(socials.telegram(), Some(MaybeUrl::Url(url)) if url.as_str() == "https://t.me/ostap")
+        );
+        assert!(matches!(
+            socials.instagram(),
+            Some(MaybeUrl::BadUrl(source, _)) if source == "instagram.com/ostap"
+        ));
+        assert!(matches!(socials.youtube(), Some(MaybeUrl::Url(_))));
+        assert!(matches!(socials.site(), Some(MaybeUrl::BadUrl(source, _)) if source.is_empty()));
+        assert!(socials.facebook().is_none());
+        assert!(socials.twitter().is_none());
+        assert!(socials.tiktok().is_none());
+    }
+
+    #[test]
+    fn socials_should_iterate_in_platform_order() {
+        // Arrange
+        let shuffled = [
+            r#"{"Mastodon": "a", "site": "b", "telegram": "c", "Bluesky": "d", "YouTube": "e"}"#,
+            r#"{"YouTube": "e", "Bluesky": "d", "telegram": "c", "site": "b", "Mastodon": "a"}"#,
+        ];
+
+        // Act
+        let socials: Vec<UserSocials> = shuffled
+            .iter()
+            .map(|json| serde_json::from_str(json).expect("Should deserialize socials"))
+            .collect();
+
+        // Assert
+        let keys: Vec<_> = socials[0]
+            .iter()
+            .map(|(platform, _)| platform.key())
+            .collect();
+        assert_eq!(keys, ["telegram", "youtube", "site", "Bluesky", "Mastodon"]);
+        assert_eq!(socials[0], socials[1]);
+        assert_eq!(
+            serde_json::to_string(&socials[0]).expect("Should serialize"),
+            serde_json::to_string(&socials[1]).expect("Should serialize")
+        );
+        assert_eq!(socials[0].len(), 5);
+        assert!(!socials[0].is_empty());
+        assert!(UserSocials::default().is_empty());
+    }
+
+    #[test]
+    fn socials_should_keep_first_of_mixed_case_duplicates() {
+        // Arrange
+        let json = r#"{
+            "Telegram": "https://t.me/first",
+            "telegram": "https://t.me/second",
+            "Mastodon": "https://mastodon.social/@first",
+            "MASTODON": "https://mastodon.social/@second"
+        }"#;
+
+        // Act
+        let socials: UserSocials = serde_json::from_str(json).expect("Should deserialize socials");
+
+        // Assert
+        assert_eq!(socials.len(), 2);
+        assert_eq!(
+            socials.telegram().map(ToString::to_string).as_deref(),
+            Some("https://t.me/first")
+        );
+        assert_eq!(
+            socials.get("mastodon").map(ToString::to_string).as_deref(),
+            Some("https://mastodon.social/@first")
+        );
+    }
+
+    #[test]
+    fn socials_should_be_looked_up_by_key() {
+        // Arrange, Act
+        let user: FullUser = fixture("full_user.json");
+        let socials = user.socials();
+
+        // Assert
+        assert_eq!(socials.get("TELEGRAM"), socials.telegram());
+        assert_eq!(socials.get(&SocialPlatform::Youtube), socials.youtube());
+        assert_eq!(
+            socials.get("mastodon").map(ToString::to_string).as_deref(),
+            Some("https://mastodon.social/@ostap")
+        );
+        assert!(socials.get("facebook").is_none());
+    }
+
+    #[test]
+    fn maybe_url_should_round_trip_as_is() {
+        for source in ["https://t.me/ostap", "t.me/foo", ""] {
+            // Arrange
+            let json = serde_json::to_string(source).expect("Should serialize a string");
+
+            // Act
+            let url: MaybeUrl = serde_json::from_str(&json).expect("Should accept any string");
+            let serialized = serde_json::to_string(&url).expect("Should serialize");
+
+            // Assert
+            assert_eq!(serialized, json);
+            assert_eq!(url.to_string(), source);
+            assert_eq!(url.as_ref(), source);
+            assert_eq!(url.as_url().is_some(), url.clone().into_result().is_ok());
+        }
+    }
+
+    #[test]
+    fn maybe_url_should_tell_valid_from_invalid() {
+        // Arrange
+        let valid: MaybeUrl = serde_json::from_str(r#""https://t.me/ostap""#).expect("Valid url");
+        let invalid: MaybeUrl = serde_json::from_str(r#""t.me/foo""#).expect("Invalid url");
+
+        // Act, Assert
+        assert_eq!(valid.as_url().map(|url| url.host_str()), Some(Some("t.me")));
+        assert!(invalid.as_url().is_none());
+        let (source, error) = invalid
+            .into_result()
+            .expect_err("Should not be a valid url");
+        assert_eq!(source, "t.me/foo");
+        assert!(!error.is_empty());
+    }
+
+    #[test]
+    fn unknown_social_platforms_should_be_preserved() {
+        // Arrange
+        let user: FullUser = fixture("full_user.json");
+
+        // Act
+        let others: Vec<_> = user.socials().others().collect();
+        let serialized = serde_json::to_value(user.socials()).expect("Should serialize");
+
+        // Assert
+        assert_eq!(others.len(), 1);
+        assert_eq!(others[0].0, "Mastodon");
+        assert_eq!(serialized["Mastodon"], "https://mastodon.social/@ostap");
+        assert_eq!(serialized["instagram"], "instagram.com/ostap");
+        assert_eq!(
+            "Mastodon".parse::<SocialPlatform>(),
+            Ok(SocialPlatform::Other("Mastodon".to_owned()))
+        );
+        assert_eq!(SocialPlatform::from("TikTok"), SocialPlatform::Tiktok);
+    }
+
+    #[test]
+    fn populated_follower_should_convert_to_short_user() {
+        // Arrange
+        let followers: Vec<FollowerUser> = fixture("users/followers.json");
+        let follower = followers[0].clone();
+
+        // Act
+        let short = follower.clone().try_into_short_user();
+
+        // Assert
+        assert_eq!(follower.display_label(), "Марія");
+        assert!(!follower.is_deleted_account());
+        let short = short.expect("All identity fields are present");
+        assert_eq!(Some(short.id()), follower.id());
+        assert_eq!(short.username().as_ref(), "maria");
+        assert_eq!(short.name().as_ref(), "Марія");
+        assert_eq!(short.avatar(), follower.avatar());
+        assert_eq!(short.relationships(), follower.relationships());
+    }
+
+    #[test]
+    fn partial_follower_should_fall_back_to_username() {
+        // Arrange
+        let followers: Vec<FollowerUser> = fixture("users/followers.json");
+        let follower = followers[1].clone();
+
+        // Act
+        let short = follower.clone().try_into_short_user();
+
+        // Assert
+        assert_eq!(follower.display_label(), "taras");
+        assert!(!follower.is_deleted_account());
+        assert_eq!(short.expect_err("Display name is missing"), follower);
+    }
+
+    #[test]
+    fn ghost_follower_should_be_deleted_account() {
+        // Arrange
+        let followers: Vec<FollowerUser> = fixture("users/followers.json");
+        let ghost = followers[2].clone();
+
+        // Act, Assert
+        assert!(ghost.is_deleted_account());
+        assert_eq!(ghost.display_label(), FollowerUser::PLACEHOLDER_LABEL);
+        assert!(ghost.try_into_short_user().is_err());
+    }
+
+    #[test]
+    fn authorized_user_should_have_typed_email() {
+        // Act
+        let user: AuthorizedUser = fixture("users/authorized.json");
+
+        // Assert
+        let email = user.email().expect("Email should be present");
+        assert_eq!(email.as_str(), "ostap@example.com");
+        assert_eq!(email.domain(), "example.com");
+    }
+
+    #[test]
+    fn empty_email_should_be_absent() {
+        // Act
+        let user: AuthorizedUser = fixture("users/authorized_social.json");
+
+        // Assert
+        assert!(user.email().is_none());
+        let json = serde_json::to_value(&user).expect("Should serialize");
+        assert_eq!(json["email"], "");
+    }
+
+    #[test]
+    fn malformed_email_should_fail() {
+        // Arrange
+        let text = crate::fixture_text("users/authorized_bad_email.json");
+
+        // Act
+        let result = serde_json::from_str::<AuthorizedUser>(&text);
+
+        // Assert
+        let err = result.expect_err("Malformed email should be rejected");
+        assert!(err.to_string().contains("invalid email address"), "{err}");
+    }
+
+    #[test]
+    fn authorized_user_debug_should_redact_email() {
+        // Arrange
+        let user: AuthorizedUser = fixture("users/authorized.json");
+        let social: AuthorizedUser = fixture("users/authorized_social.json");
+
+        // Act
+        let debug = format!("{user:?}");
+        let social_debug = format!("{social:?}");
+
+        // Assert
+        assert!(!debug.contains("ostap@example.com"), "{debug}");
+        assert!(debug.contains("o***@example.com"), "{debug}");
+        assert!(social_debug.contains("email: None"), "{social_debug}");
+    }
+
+    #[test]
+    fn search_users_should_parse_without_relationships() {
+        // Arrange
+        let plain_text = crate::fixture_text("users/search_plain.json");
+
+        // Act
+        let short: Vec<ShortUser> = fixture("users/search.json");
+        let plain: Vec<PlainShortUser> = fixture("users/search_plain.json");
+        let anonymous = serde_json::from_str::<Vec<ShortUser>>(&plain_text)
+            .expect("Relationships should be optional");
+
+        // Assert
+        assert!(anonymous
+            .iter()
+            .all(|user| user.relationships() == &Relationships::default()));
+        assert_eq!(plain.len(), short.len());
+        for (plain, short) in plain.iter().zip(short) {
+            let downgraded = PlainShortUser::from(short);
+            assert_eq!(downgraded.id(), plain.id());
+            assert_eq!(downgraded.username(), plain.username());
+            assert_eq!(downgraded.name(), plain.name());
+            assert_eq!(downgraded.avatar(), plain.avatar());
+        }
+    }
+}
+
+mod conversions {
+    use type_matrux::object::{
+        Aged, AsArticleSummary, FullArticle, ListArticle, RecommendedArticle, ShortArticle,
+        TagArticle,
+    };
+
+    use crate::fixture;
+
+    #[test]
+    fn full_article_should_downgrade_to_short() {
+        // Arrange
+        let full: FullArticle = fixture("full_article.json");
+
+        // Act
+        let short = ShortArticle::from(full.clone());
+
+        // Assert
+        assert_eq!(short.id(), full.id());
+        assert_eq!(short.title(), full.title());
+        assert_eq!(short.description(), full.description());
+        assert_eq!(short.slug(), full.slug());
+        assert_eq!(short.owner(), full.owner().id());
+        assert_eq!(short.thumb_picture(), full.thumb_picture());
+        assert_eq!(short.main_tag(), full.main_tag());
+        assert_eq!(short.main_tag_slug(), full.main_tag_slug());
+        assert_eq!(short.main_tag_id(), full.main_tag_id());
+        assert_eq!(
+            short.tags(),
+            &full
+                .tags()
+                .iter()
+                .map(|tag| tag.id().clone())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(short.sensitive(), full.sensitive());
+        assert_eq!(short.like_num(), full.like_num());
+        assert_eq!(short.comment_num(), full.comment_num());
+        assert_eq!(short.read_time(), full.read_time());
+        assert_eq!(short.created_at(), full.created_at());
+        assert_eq!(short.is_bookmarked(), full.is_bookmarked());
+        assert_eq!(short.fetched_at(), full.fetched_at());
+    }
+
+    #[test]
+    fn full_article_should_downgrade_to_tag_and_recommended() {
+        // Arrange
+        let full: FullArticle = fixture("full_article.json");
+
+        // Act
+        let tag = TagArticle::from(full.clone());
+        let recommended = RecommendedArticle::from(tag.clone());
+
+        // Assert
+        assert_eq!(tag.owner(), full.owner());
+        assert_eq!(tag.relationships(), full.relationships());
+        assert_eq!(recommended.owner(), full.owner());
+        assert_eq!(recommended.title(), full.title());
+        assert_eq!(recommended.tags(), tag.tags());
+        assert_eq!(recommended, RecommendedArticle::from(full));
+    }
+
+    #[test]
+    fn downgrades_should_commute() {
+        // Arrange
+        let full: FullArticle = fixture("full_article.json");
+
+        // Act
+        let direct = ListArticle::from(full.clone());
+        let through_short = ListArticle::from(ShortArticle::from(full.clone()));
+        let search = full.author_articles()[0].clone();
+        let from_search = ListArticle::from(search.clone());
+
+        // Assert
+        assert_eq!(direct, through_short);
+        assert_eq!(from_search.id(), search.id());
+        assert_eq!(from_search.slug(), search.slug());
+    }
+
+    #[test]
+    fn summaries_should_agree() {
+        // Arrange
+        let full: FullArticle = fixture("full_article.json");
+        let short = ShortArticle::from(full.clone());
+        let list = ListArticle::from(full.clone());
+
+        // Act
+        let full_summary = full.as_summary();
+        let short_summary = short.as_summary();
+        let list_summary = list.as_summary();
+
+        // Assert
+        assert_eq!(full_summary, short_summary);
+        assert_eq!(full_summary.title(), full.title());
+        assert_eq!(full_summary.like_num(), Some(*full.like_num()));
+        assert_eq!(list_summary.like_num(), None);
+        assert_eq!(list_summary.comment_num(), None);
+        assert_eq!(list_summary.slug(), full_summary.slug());
+        assert_eq!(list_summary.read_time(), full_summary.read_time());
+    }
+}
+
+mod urls {
+    use type_matrux::object::{ArticleSlug, SlugFromUrlError, TagSlug, UserName};
+    use url::Url;
+
+    fn url(s: &str) -> Url {
+        s.parse().expect("Should be valid url")
+    }
+
+    #[test]
+    fn article_slug_should_be_extracted() {
+        // Arrange
+        let urls = [
+            "https://drukarnia.com.ua/articles/gitlab-istoriya-uspikhu-ukrayinskogo-konkurenta-github-t7agP",
+            "https://drukarnia.com.ua/articles/gitlab-istoriya-uspikhu-ukrayinskogo-konkurenta-github-t7agP/",
+            "https://www.drukarnia.com.ua/articles/gitlab-istoriya-uspikhu-ukrayinskogo-konkurenta-github-t7agP?utm_source=telegram#comments",
+        ];
+
+        for source in urls {
+            // Act
+            let slug = ArticleSlug::from_url(&url(source));
+
+            // Assert
+            assert_eq!(
+                slug.map(|slug| slug.to_string()),
+                Ok("gitlab-istoriya-uspikhu-ukrayinskogo-konkurenta-github-t7agP".to_owned()),
+                "{source}"
+            );
+        }
+    }
+
+    #[test]
+    fn tag_slug_should_be_extracted() {
+        // Arrange
+        let source = url("https://drukarnia.com.ua/tags/tekhnologiyi/?page=2");
+
+        // Act
+        let slug = TagSlug::from_url(&source);
+
+        // Assert
+        assert_eq!(
+            slug.map(|slug| slug.to_string()),
+            Ok("tekhnologiyi".to_owned())
+        );
+    }
+
+    #[test]
+    fn user_name_should_be_extracted() {
+        // Arrange
+        let urls = [
+            "https://drukarnia.com.ua/@ostap",
+            "https://drukarnia.com.ua/@ostap/",
+            "https://drukarnia.com.ua/users/ostap?tab=articles",
+        ];
+
+        for source in urls {
+            // Act
+            let name = UserName::from_url(&url(source));
+
+            // Assert
+            assert_eq!(
+                name.map(|name| name.to_string()),
+                Ok("ostap".to_owned()),
+                "{source}"
+            );
+        }
+    }
+
+    #[test]
+    fn non_ascii_segments_should_be_decoded() {
+        // Arrange
+        let article = url("https://drukarnia.com.ua/articles/що-таке-rust-abc12");
+        let tag = url("https://drukarnia.com.ua/tags/%D1%82%D0%B5%D1%85%D0%BD%D0%BE%D0%BB%D0%BE%D0%B3%D1%96%D1%97");
+        let users = [
+            url("https://drukarnia.com.ua/@іван"),
+            url("https://drukarnia.com.ua/users/%D1%96%D0%B2%D0%B0%D0%BD"),
+        ];
+
+        // Act
+        let article = ArticleSlug::from_url(&article);
+        let tag = TagSlug::from_url(&tag);
+        let users = users.map(|user| UserName::from_url(&user));
+
+        // Assert
+        assert_eq!(
+            article.map(|slug| slug.to_string()),
+            Ok("що-таке-rust-abc12".to_owned())
+        );
+        assert_eq!(
+            tag.map(|slug| slug.to_string()),
+            Ok("технології".to_owned())
+        );
+        for user in users {
+            assert_eq!(user.map(|name| name.to_string()), Ok("іван".to_owned()));
+        }
+    }
+
+    #[test]
+    fn undecodable_segment_should_be_rejected() {
+        // Arrange
+        let source = url("https://drukarnia.com.ua/articles/%FF%FE-abc12");
+
+        // Act
+        let slug = ArticleSlug::from_url(&source);
+
+        // Assert
+        assert_eq!(
+            slug,
+            Err(SlugFromUrlError::BadPathEncoding("%FF%FE-abc12".to_owned()))
+        );
+    }
+
+    #[test]
+    fn wrong_host_should_be_rejected() {
+        // Arrange
+        let source = url("https://example.com/articles/some-article-aBcDe");
+
+        // Act
+        let slug = ArticleSlug::from_url(&source);
+
+        // Assert
+        assert_eq!(
+            slug,
+            Err(SlugFromUrlError::WrongHost {
+                expected: "drukarnia.com.ua".to_owned(),
+                found: Some("example.com".to_owned()),
+            })
+        );
+        assert!(ArticleSlug::from_url_at(&source, "example.com").is_ok());
+    }
+
+    #[test]
+    fn wrong_path_should_be_rejected() {
+        // Arrange
+        let urls = [
+            "https://drukarnia.com.ua/tags/tekhnologiyi",
+            "https://drukarnia.com.ua/articles",
+            "https://drukarnia.com.ua/articles/some-article-aBcDe/comments",
+            "https://drukarnia.com.ua/",
+        ];
+
+        for source in urls {
+            // Act
+            let slug = ArticleSlug::from_url(&url(source));
+
+            // Assert
+            assert!(
+                matches!(slug, Err(SlugFromUrlError::UnexpectedPath(_))),
+                "{source}"
+            );
+        }
+        assert!(UserName::from_url(&url("https://drukarnia.com.ua/@")).is_err());
+    }
+}
+
+mod accessors {
+    use futures::{executor::block_on, stream, StreamExt};
+    use time::macros::datetime;
+    use type_matrux::{
+        client::{Error, ObjectStreamExt},
+        object::{
+            sort_newest_first, FullArticle, FullUser, HasCreatedAt, HasId, HasSlug, ReplyComment,
+        },
+    };
+
+    use crate::fixture;
+
+    fn ids<T: HasId>(objects: &[T]) -> Vec<[u8; 12]> {
+        objects.iter().map(|object| *object.raw_id()).collect()
+    }
+
+    #[test]
+    fn accessors_should_agree_with_getters() {
+        // Arrange
+        let article: FullArticle = fixture("full_article.json");
+        let user: FullUser = fixture("full_user.json");
+
+        // Act, Assert
+        assert_eq!(article.raw_id(), article.id().as_ref());
+        assert_eq!(article.slug_str(), article.slug().as_ref());
+        assert_eq!(HasCreatedAt::created_at(&article), *article.created_at());
+        assert_eq!(user.slug_str(), user.username().as_ref());
+        assert_eq!(
+            article.comments()[0].raw_id(),
+            article.comments()[0].id().as_ref()
+        );
+        assert_eq!(
+            article.tags()[1].slug_str(),
+            article.tags()[1].slug().as_ref()
+        );
+    }
+
+    #[test]
+    fn objects_should_sort_newest_first() {
+        // Arrange
+        let mut replies: Vec<ReplyComment> = fixture("comments/replies.json");
+
+        // Act
+        sort_newest_first(&mut replies);
+
+        // Assert
+        let created: Vec<_> = replies.iter().map(HasCreatedAt::created_at).collect();
+        assert!(created.windows(2).all(|pair| pair[0] >= pair[1]));
+        assert_eq!(replies[0].id().to_string(), "651ae7dc280f4421026b12d4");
+    }
+
+    #[test]
+    fn stream_dedup_should_skip_repeated_objects() {
+        // Arrange
+        let replies: Vec<ReplyComment> = fixture("comments/replies.json");
+        let repeated = [&replies[..2], &replies[..], &replies[1..3]].concat();
+        let repeated = stream::iter(repeated.into_iter().map(Ok::<_, Error>));
+
+        // Act
+        let deduped: Vec<ReplyComment> =
+            block_on(repeated.dedup().map(|reply| reply.unwrap()).collect());
+
+        // Assert
+        assert_eq!(ids(&deduped), ids(&replies));
+    }
+
+    #[test]
+    fn stream_should_stop_at_older_object() {
+        // Arrange
+        let mut replies: Vec<ReplyComment> = fixture("comments/replies.json");
+        sort_newest_first(&mut replies);
+        let newest_first = stream::iter(replies.clone().into_iter().map(Ok::<_, Error>));
+
+        // Act
+        let recent: Vec<ReplyComment> = block_on(
+            newest_first
+                .until_older_than(datetime!(2023-10-02 17:00 UTC))
+                .map(|reply| reply.unwrap())
+                .collect(),
+        );
+
+        // Assert
+        assert_eq!(ids(&recent), ids(&replies[..3]));
+    }
+}
+
+/// Site's API might introduce new fields at any moment
+///
+/// By default, they are rejected, and with `lenient` feature they are collected instead
+mod drift {
+    use type_matrux::object::{FeedArticle, PopularTag};
+
+    use crate::fixture_text;
+
+    #[cfg(not(feature = "lenient"))]
+    #[test]
+    fn unknown_field_should_be_rejected() {
+        // Arrange
+        let json = fixture_text("drift/popular_tag.json");
+
+        // Act
+        let tag = serde_json::from_str::<PopularTag>(&json);
+
+        // Assert
+        let err = tag.expect_err("Unknown field should be rejected");
+        assert!(err.to_string().contains("unknown field `color`"), "{err}");
+    }
+
+    #[cfg(feature = "lenient")]
+    #[test]
+    fn unknown_field_should_be_collected() {
+        // Arrange
+        let json = fixture_text("drift/popular_tag.json");
+
+        // Act
+        let tag = serde_json::from_str::<PopularTag>(&json).expect("Should be lenient");
+
+        // Assert
+        assert_eq!(tag.unknown_fields().len(), 1);
+        assert_eq!(tag.unknown_fields()["color"], "#ffcc00");
+        let serialized = serde_json::to_value(&tag).expect("Should serialize");
+        assert_eq!(serialized["color"], "#ffcc00");
+    }
+
+    #[test]
+    fn lenient_type_should_collect_unknown_field() {
+        // Arrange
+        let json = fixture_text("drift/feed_article.json");
+
+        // Act
+        let article = serde_json::from_str::<FeedArticle>(&json).expect("Feed should be lenient");
+
+        // Assert
+        assert_eq!(article.unknown_fields().len(), 1);
+        assert_eq!(article.unknown_fields()["promoted"], true);
+        assert_eq!(*article.like_num(), 42);
+    }
+
+    #[test]
+    fn unknown_fields_should_be_ordered_by_name() {
+        // Arrange
+        let mut json: serde_json::Value =
+            serde_json::from_str(&fixture_text("drift/feed_article.json")).expect("Should be JSON");
+        json["zeta"] = 1.into();
+        json["alpha"] = 2.into();
+
+        // Act
+        let article = serde_json::from_value::<FeedArticle>(json).expect("Feed should be lenient");
+
+        // Assert
+        let names: Vec<_> = article
+            .unknown_fields()
+            .keys()
+            .map(String::as_str)
+            .collect();
+        assert_eq!(names, ["alpha", "promoted", "zeta"]);
+    }
+
+    #[cfg(not(feature = "lenient"))]
+    #[test]
+    fn strict_type_should_reject_same_field() {
+        // Arrange
+        let mut json: serde_json::Value =
+            serde_json::from_str(&fixture_text("popular_tags.json")).expect("Should be valid json");
+        json[0]["promoted"] = true.into();
+
+        // Act
+        let tags = serde_json::from_value::<Vec<PopularTag>>(json);
+
+        // Assert
+        let err = tags.expect_err("Unknown field should be rejected");
+        assert!(
+            err.to_string().contains("unknown field `promoted`"),
+            "{err}"
+        );
+    }
+}
+
+mod search {
+    use futures::{executor::block_on, stream, StreamExt};
+    use type_matrux::{
+        client::{Error, ObjectStreamExt},
+        object::{AsArticleSummary, MaybeUrl, RecommendedArticle, SearchArticle},
+    };
+
+    use crate::fixture;
+
+    #[test]
+    fn result_without_id_should_not_break_page() {
+        // Arrange, Act
+        let page: Vec<RecommendedArticle> = fixture("search/recommended_page.json");
+
+        // Assert
+        assert_eq!(page.len(), 3);
+        assert_eq!(
+            page[0].id().map(ToString::to_string).as_deref(),
+            Some("6511e036280f4421025f0a02")
+        );
+        assert!(page[1].id().is_none());
+        assert_eq!(page[1].slug().as_ref(), "stattya-bez-identifikatora-z0Z0z");
+        assert!(page[2].id().is_some());
+        assert!(page[1].as_summary().id().is_none());
+        assert_eq!(page[1].as_summary().slug(), page[1].slug());
+    }
+
+    #[test]
+    fn results_without_id_should_dedup_by_slug() {
+        // Arrange
+        let page: Vec<RecommendedArticle> = fixture("search/recommended_page.json");
+        let repeated = [&page[..], &page[1..]].concat();
+        let repeated = stream::iter(repeated.into_iter().map(Ok::<_, Error>));
+
+        // Act
+        let deduped: Vec<RecommendedArticle> = block_on(
+            repeated
+                .dedup_by_slug()
+                .map(|article| article.unwrap())
+                .collect(),
+        );
+
+        // Assert
+        assert_eq!(deduped, page);
+    }
+
+    #[test]
+    fn canonical_url_should_be_valid_only() {
+        // Arrange, Act
+        let articles: Vec<SearchArticle> = fixture("search/canonical.json");
+
+        // Assert
+        assert!(articles[0].canonical().is_none());
+        assert!(articles[0].canonical_url().is_none());
+        assert_eq!(
+            articles[1].canonical_url().map(|url| url.as_str()),
+            Some("https://blog.example.com/posts/rust-basics")
+        );
+        assert!(matches!(
+            articles[2].canonical(),
+            Some(MaybeUrl::BadUrl(source, _)) if source == "not a url at all"
+        ));
+        assert!(articles[2].canonical_url().is_none());
+    }
+}
+
+mod text {
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+    use type_matrux::object::{
+        text::{excerpt, ELLIPSIS},
+        FullArticle,
+    };
+
+    use crate::fixture;
+
+    #[test]
+    fn short_text_should_only_collapse_whitespace() {
+        // Arrange
+        let text = "  Привіт,\n\n  світе!\t";
+
+        // Act
+        let excerpt = excerpt(text, 100);
+
+        // Assert
+        assert_eq!(excerpt, "Привіт, світе!");
+    }
+
+    #[test]
+    fn long_text_should_be_cut_at_word_boundary() {
+        // Arrange
+        let text = "Привіт, світе! Як справи?";
+
+        // Act
+        let excerpts: Vec<String> = [15, 10, 5, 1, 0]
+            .into_iter()
+            .map(|max| excerpt(text, max))
+            .collect();
+
+        // Assert
+        assert_eq!(excerpts, ["Привіт, світе…", "Привіт…", "Прив…", "…", ""]);
+    }
+
+    #[test]
+    fn combining_marks_should_stay_with_base() {
+        // Arrange
+        // "й" written as "и" followed by combining breve
+        let text = "аи\u{0306}б";
+
+        // Act
+        let excerpt = excerpt(text, 3);
+
+        // Assert
+        assert_eq!(excerpt, "а…");
+    }
+
+    #[test]
+    fn article_excerpt_should_prefer_description() {
+        // Arrange
+        let article: FullArticle = fixture("full_article.json");
+
+        // Act
+        let excerpt = article.excerpt(1000);
+
+        // Assert
+        assert_eq!(excerpt, article.description().as_ref().trim());
+    }
+
+    #[test]
+    fn random_cuts_should_be_bounded_and_valid() {
+        // Arrange
+        const WORDS: &[&str] = &[
+            "пам’ять",
+            "ї\u{0308}жак",
+            "код",
+            "self-hosted",
+            "Україна",
+            "👩\u{200D}💻",
+            "ґанок",
+            "—",
+        ];
+        let mut rng = StdRng::seed_from_u64(1908);
+
+        for _ in 0..500 {
+            let words = rng.gen_range(0..30);
+            let text = (0..words)
+                .map(|_| WORDS[rng.gen_range(0..WORDS.len())])
+                .collect::<Vec<_>>()
+                .join(if rng.gen_bool(0.5) { " " } else { " \n\t " });
+            let max_chars = rng.gen_range(0..80);
+
+            // Act
+            let excerpt = excerpt(&text, max_chars);
+
+            // Assert
+            assert!(
+                excerpt.chars().count() <= max_chars,
+                "{excerpt:?} for {max_chars}"
+            );
+            assert!(std::str::from_utf8(excerpt.as_bytes()).is_ok());
+            let collapsed = text.split_whitespace().collect::<Vec<_>>().join(" ");
+            let prefix = excerpt.strip_suffix(ELLIPSIS).unwrap_or(&excerpt);
+            assert!(
+                collapsed.starts_with(prefix),
+                "{excerpt:?} for {collapsed:?}"
+            );
+            let rest = &collapsed[prefix.len()..];
+            assert!(
+                !rest.starts_with(['\u{0306}', '\u{0308}', '\u{200D}']),
+                "{excerpt:?} splits a grapheme of {collapsed:?}"
+            );
+            assert!(!prefix.ends_with('\u{200D}'));
+        }
+    }
+}
+
+mod aged {
+    use std::time::Duration;
+
+    use type_matrux::object::{Aged, FullArticle, FullUser, PopularTag};
+
+    use crate::fixture;
+
+    fn oldest<T: Aged>(items: &[T]) -> Option<&T> {
+        items.iter().min_by_key(|item| item.fetched_at())
+    }
+
+    #[test]
+    fn oldest_should_be_fetched_first() {
+        // Arrange
+        let mut tags: Vec<PopularTag> = Vec::new();
+        for _ in 0..3 {
+            tags.extend(
+                fixture::<Vec<PopularTag>>("popular_tags.json")
+                    .into_iter()
+                    .take(1),
+            );
+            std::thread::sleep(Duration::from_millis(2));
+        }
+
+        // Act
+        let oldest = oldest(&tags).expect("Tags are not empty");
+
+        // Assert
+        assert_eq!(oldest.fetched_at(), tags[0].fetched_at());
+        assert!(tags.iter().all(|tag| tag.get_age() <= oldest.get_age()));
+        assert!(self::oldest::<PopularTag>(&[]).is_none());
+    }
+
+    #[test]
+    fn trait_should_agree_with_inherent_method() {
+        // Arrange
+        let article: FullArticle = fixture("full_article.json");
+        let user: FullUser = fixture("full_user.json");
+
+        // Act
+        let aged: [&dyn Aged; 2] = [&article, &user];
+
+        // Assert
+        for object in aged {
+            assert!(object.get_age() >= time::Duration::ZERO);
+            assert!(object.get_age() < time::Duration::SECOND);
+        }
+        assert!(Aged::get_age(&article) <= article.get_age());
+    }
+}
+
+mod serialization {
+    use serde::{de::DeserializeOwned, Serialize};
+    use serde_json::Value;
+    use type_matrux::object::{FullArticle, FullUser, PopularTag};
+
+    use crate::fixture;
+
+    /// Deserializes fixture, serializes it back and deserializes again, returning both serialized forms
+    fn round_trip<T: DeserializeOwned + Serialize>(name: &str) -> (Value, Value) {
+        let first: T = fixture(name);
+        let serialized = serde_json::to_string(&first).expect("Should be able to serialize");
+        let second: T =
+            serde_json::from_str(&serialized).expect("Serialized object should deserialize back");
+        let reserialized = serde_json::to_value(&second).expect("Should be able to serialize");
+        let serialized =
+            serde_json::from_str(&serialized).expect("Serialized should be valid json");
+        (serialized, reserialized)
+    }
+
+    #[test]
+    fn full_article_should_round_trip() {
+        // Arrange, Act
+        let (serialized, reserialized) = round_trip::<FullArticle>("full_article.json");
+
+        // Assert
+        assert_eq!(serialized, reserialized);
+        assert_eq!(serialized["_id"], "6511e036280f4421025f09fd");
+        assert_eq!(serialized["readTime"], 240);
+        assert_eq!(serialized["isLiked"], false);
+        assert_eq!(serialized["createdAt"], "2023-09-25T19:30:30.616Z");
+        assert_eq!(
+            serialized["owner"]["socials"]["telegram"],
+            "https://t.me/ostap"
+        );
+        assert_eq!(
+            serialized["comments"][0]["comment"],
+            "<p>Дуже цікаво, дякую!</p>"
+        );
+        assert_eq!(serialized["content"]["version"], "2.26.5");
+        assert!(serialized.get("fetched_at").is_none());
+    }
+
+    #[test]
+    fn full_user_should_round_trip() {
+        // Arrange, Act
+        let (serialized, reserialized) = round_trip::<FullUser>("full_user.json");
+
+        // Assert
+        assert_eq!(serialized, reserialized);
+        assert_eq!(serialized["descriptionShort"], "Пишу про технології");
+        assert_eq!(serialized["authorTags"][1]["slug"], "programuvannya");
+        assert_eq!(serialized["articles"][0]["likeNum"], 42);
+    }
+
+    #[test]
+    fn value_should_deserialize_back() {
+        // Arrange
+        let article: FullArticle = fixture("full_article.json");
+        let value = serde_json::to_value(&article).expect("Should be able to serialize");
+
+        // Act
+        let back: FullArticle =
+            serde_json::from_value(value.clone()).expect("Value should deserialize back");
+
+        // Assert
+        assert_eq!(back, article);
+        assert_eq!(value["owner"]["_id"], "643af9fc1272bd9066a1ffdb");
+    }
+
+    #[test]
+    fn popular_tags_should_round_trip() {
+        // Arrange, Act
+        let (serialized, reserialized) = round_trip::<Vec<PopularTag>>("popular_tags.json");
+
+        // Assert
+        assert_eq!(serialized, reserialized);
+        assert_eq!(serialized, crate::fixture::<Value>("popular_tags.json"));
+    }
+}
+
+mod equality {
+    use type_matrux::object::{FullArticle, FullUser, PopularTag, ReplyComment};
+
+    use crate::fixture;
+
+    #[test]
+    fn refetched_objects_should_be_equal() {
+        // Arrange
+        let article: FullArticle = fixture("full_article.json");
+        let user: FullUser = fixture("full_user.json");
+        let replies: Vec<ReplyComment> = fixture("comments/replies.json");
+        std::thread::sleep(std::time::Duration::from_millis(5));
+
+        // Act
+        let refetched_article: FullArticle = fixture("full_article.json");
+        let refetched_user: FullUser = fixture("full_user.json");
+        let refetched_replies: Vec<ReplyComment> = fixture("comments/replies.json");
+
+        // Assert
+        assert_ne!(article.get_age(), refetched_article.get_age());
+        assert_eq!(article, refetched_article);
+        assert_eq!(user, refetched_user);
+        assert_eq!(replies, refetched_replies);
+    }
+
+    #[test]
+    fn different_objects_should_not_be_equal() {
+        // Arrange
+        let tags: Vec<PopularTag> = fixture("popular_tags.json");
+        let replies: Vec<ReplyComment> = fixture("comments/replies.json");
+
+        // Act, Assert
+        assert_ne!(tags[0], tags[1]);
+        assert_ne!(replies[0], replies[1]);
+    }
+}
+
+mod refresh {
+    use std::{
+        num::NonZeroUsize,
+        sync::atomic::{AtomicUsize, Ordering},
+    };
+
+    use async_trait::async_trait;
+    use type_matrux::{
+        client::{
+            ArticlesApi, BaseApi, CommentsApi, Error, FeedApi, ImageBytes, Refresh, TagsApi,
+            UsersApi,
+        },
+        object::{
+            Aged, ArticleSlug, ArticleTitle, CommentId, FeedArticle, FollowerUser, FullArticle,
+            FullTag, FullUser, MaybeUrl, PopularTag, RecommendedArticle, ReplyComment, ShortUser,
+            TagSlug, UserId, UserName,
+        },
+    };
+
+    use crate::fixture;
+
+    /// Serves fixtures, counting the refetches
+    #[derive(Default)]
+    struct StubApi {
+        articles: AtomicUsize,
+        users: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl BaseApi for StubApi {
+        type Auth = ();
+    }
+
+    #[async_trait]
+    impl UsersApi for StubApi {
+        async fn get_user(&self, _name: &UserName) -> Result<FullUser, Error> {
+            self.users.fetch_add(1, Ordering::SeqCst);
+            Ok(fixture("full_user.json"))
+        }
+
+        async fn search_user_page(
+            &self,
+            _name: &UserName,
+            _page: NonZeroUsize,
+        ) -> Result<Vec<ShortUser>, Error> {
+            unimplemented!()
+        }
+
+        async fn get_followers_page(
+            &self,
+            _id: &UserId,
+            _page: NonZeroUsize,
+        ) -> Result<Vec<FollowerUser>, Error> {
+            unimplemented!()
+        }
+    }
+
+    #[async_trait]
+    impl ArticlesApi for StubApi {
+        async fn get_article(&self, _slug: &ArticleSlug) -> Result<FullArticle, Error> {
+            self.articles.fetch_add(1, Ordering::SeqCst);
+            Ok(fixture("full_article.json"))
+        }
+
+        async fn search_article_page(
+            &self,
+            _name: &ArticleTitle,
+            _page: NonZeroUsize,
+        ) -> Result<Vec<RecommendedArticle>, Error> {
+            unimplemented!()
+        }
+
+        async fn download_image(&self, _url: &MaybeUrl) -> Result<ImageBytes, Error> {
+            unimplemented!()
+        }
+    }
+
+    #[async_trait]
+    impl TagsApi for StubApi {
+        async fn popular_tags(&self) -> Result<Vec<PopularTag>, Error> {
+            unimplemented!()
+        }
+
+        async fn get_tag(&self, _slug: &TagSlug) -> Result<FullTag, Error> {
+            unimplemented!()
+        }
+    }
+
+    #[async_trait]
+    impl CommentsApi for StubApi {
+        async fn get_replies(&self, _comment: &CommentId) -> Result<Vec<ReplyComment>, Error> {
+            unimplemented!()
+        }
+    }
+
+    #[async_trait]
+    impl FeedApi for StubApi {
+        async fn feed_page(&self, _page: NonZeroUsize) -> Result<Vec<FeedArticle>, Error> {
+            unimplemented!()
+        }
+    }
+
+    #[tokio::test]
+    async fn fresh_objects_should_not_be_refetched() {
+        // Arrange
+        let api = StubApi::default();
+        let mut article: FullArticle = fixture("full_article.json");
+        let mut user: FullUser = fixture("full_user.json");
+
+        // Act
+        let article_refreshed = article
+            .refresh_if_older_than(time::Duration::HOUR, &api)
+            .await
+            .expect("Stub should not fail");
+        let user_refreshed = user
+            .refresh_if_older_than(time::Duration::HOUR, &api)
+            .await
+            .expect("Stub should not fail");
+
+        // Assert
+        assert!(!article_refreshed);
+        assert!(!user_refreshed);
+        assert_eq!(api.articles.load(Ordering::SeqCst), 0);
+        assert_eq!(api.users.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn stale_objects_should_be_refetched() {
+        // Arrange
+        let api = StubApi::default();
+        let mut article: FullArticle = fixture("full_article.json");
+        let mut user: FullUser = fixture("full_user.json");
+        let fetched_at = article.fetched_at();
+        std::thread::sleep(std::time::Duration::from_millis(2));
+
+        // Act
+        let article_refreshed = article
+            .refresh_if_older_than(time::Duration::MILLISECOND, &api)
+            .await
+            .expect("Stub should not fail");
+        let user_refreshed = user
+            .refresh_if_older_than(time::Duration::MILLISECOND, &api)
+            .await
+            .expect("Stub should not fail");
+
+        // Assert
+        assert!(article_refreshed);
+        assert!(user_refreshed);
+        assert_eq!(api.articles.load(Ordering::SeqCst), 1);
+        assert_eq!(api.users.load(Ordering::SeqCst), 1);
+        assert!(article.fetched_at() > fetched_at);
+        assert!(!article.is_older_than(time::Duration::MILLISECOND));
+    }
+
+    #[tokio::test]
+    async fn refresh_should_always_refetch() {
+        // Arrange
+        let api = StubApi::default();
+        let mut article: FullArticle = fixture("full_article.json");
+
+        // Act
+        article.refresh(&api).await.expect("Stub should not fail");
+        article.refresh(&api).await.expect("Stub should not fail");
+
+        // Assert
+        assert_eq!(api.articles.load(Ordering::SeqCst), 2);
+    }
+}
+
+mod ids {
+    use std::{fmt::Display, str::FromStr};
+
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+    use serde::{de::DeserializeOwned, Serialize};
+    use type_matrux::{
+        id_hex,
+        object::{
+            ArticleId, BookmarkId, CommentId, HexIdParseError, ListId, NotificationId, TagId,
+            UserId,
+        },
+    };
+
+    #[test]
+    fn id_literals_should_match_parsed_ids() {
+        // Arrange
+        const USER: UserId = id_hex!(UserId, "643af9fc1272bd9066a1ffdb");
+        let hex = "651ae7dc280f4421026b12c5";
+
+        // Act
+        let article = id_hex!(ArticleId, "651ae7dc280f4421026b12c5");
+        let tag = id_hex!(TagId, "651AE7DC280F4421026B12C5");
+        let bytes = id_hex!("000102030405060708090a0b");
+
+        // Assert
+        assert_eq!(USER.to_string(), "643af9fc1272bd9066a1ffdb");
+        assert_eq!(article, hex.parse().expect("Should be a valid id"));
+        assert_eq!(tag.as_bytes(), article.as_bytes());
+        assert_eq!(bytes, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11]);
+    }
+
+    #[test]
+    fn parsed_id_should_display_same() {
+        // Arrange
+        let hex = "651ae7dc280f4421026b12c5";
+
+        // Act
+        let id: CommentId = hex.parse().expect("Should be a valid id");
+
+        // Assert
+        assert_eq!(id.to_string(), hex);
+        assert_eq!(
+            <[u8; 12]>::from(id),
+            [0x65, 0x1a, 0xe7, 0xdc, 0x28, 0x0f, 0x44, 0x21, 0x02, 0x6b, 0x12, 0xc5]
+        );
+    }
+
+    #[test]
+    fn bytes_should_round_trip() {
+        // Arrange
+        const BYTES: [u8; 12] = [
+            0x64, 0x3a, 0xf9, 0xfc, 0x12, 0x72, 0xbd, 0x90, 0x66, 0xa1, 0xff, 0xdb,
+        ];
+        const ID: UserId = UserId::from_bytes(BYTES);
+
+        // Act
+        let converted = UserId::from(BYTES);
+
+        // Assert
+        assert_eq!(ID.as_bytes(), &BYTES);
+        assert_eq!(converted, ID);
+        assert_eq!(ID.to_string(), "643af9fc1272bd9066a1ffdb");
+    }
+
+    #[test]
+    fn uppercase_hex_should_parse() {
+        // Arrange
+        let hex = "643AF9FC1272BD9066A1FFDB";
+
+        // Act
+        let id = UserId::try_from(hex).expect("Should be a valid id");
+
+        // Assert
+        assert_eq!(id.to_string(), hex.to_lowercase());
+    }
+
+    #[test]
+    fn deserialized_id_should_parse_back() {
+        // Arrange
+        let id: ArticleId =
+            serde_json::from_str("\"6511e036280f4421025f09fd\"").expect("Should deserialize");
+
+        // Act
+        let parsed: ArticleId = id.to_string().parse().expect("Should be a valid id");
+
+        // Assert
+        assert_eq!(parsed, id);
+    }
+
+    #[test]
+    fn id_should_serialize_as_hex_string() {
+        // Arrange
+        let id = id_hex!(CommentId, "651ae7dc280f4421026b12c5");
+
+        // Act
+        let value = serde_json::to_value(&id).expect("Should be able to serialize");
+        let back: CommentId =
+            serde_json::from_value(value.clone()).expect("Should deserialize back");
+
+        // Assert
+        assert_eq!(value, "651ae7dc280f4421026b12c5");
+        assert_eq!(back, id);
+    }
+
+    #[test]
+    fn malformed_id_should_not_deserialize() {
+        // Arrange
+        let json = "\"651ae7dc280f4421026b12\"";
+
+        // Act
+        let res = serde_json::from_str::<CommentId>(json);
+
+        // Assert
+        let err = res.expect_err("Should not deserialize a short id");
+        assert!(err.to_string().contains("found 22 characters"), "{err}");
+    }
+
+    #[test]
+    fn malformed_ids_should_error() {
+        // Arrange
+        let cases = [
+            ("", HexIdParseError::WrongLength(0)),
+            ("651ae7dc280f4421026b12c", HexIdParseError::WrongLength(23)),
+            (
+                "651ae7dc280f4421026b12c50",
+                HexIdParseError::WrongLength(25),
+            ),
+            (
+                "651ae7dc280f4421026b12g5",
+                HexIdParseError::InvalidCharacter {
+                    character: 'g',
+                    position: 22,
+                },
+            ),
+            (
+                "651ae7dc280f4421026b12ї5",
+                HexIdParseError::InvalidCharacter {
+                    character: 'ї',
+                    position: 22,
+                },
+            ),
+        ];
+
+        for (input, expected) in cases {
+            // Act
+            let res = input.parse::<CommentId>();
+
+            // Assert
+            assert_eq!(res, Err(expected), "Input: {input:?}");
+        }
+    }
+
+    /// Bytes that are easy to get wrong: zeros, single-digit bytes and the largest ones
+    const EDGE_BYTES: &[[u8; 12]] = &[[0x00; 12], [0x0f; 12], [0x10; 12], [0xff; 12]];
+
+    fn random_bytes(rng: &mut StdRng) -> [u8; 12] {
+        let mut bytes = [0u8; 12];
+        rng.fill(&mut bytes);
+        // biased to small values every now and then, to hit zero-padding often
+        if rng.gen_bool(0.5) {
+            bytes.iter_mut().for_each(|b| *b &= 0x0f);
+        }
+        bytes
+    }
+
+    fn assert_round_trip<Id>(bytes: [u8; 12])
+    where
+        Id: From<[u8; 12]>
+            + Into<[u8; 12]>
+            + Display
+            + FromStr<Err = HexIdParseError>
+            + Serialize
+            + DeserializeOwned
+            + Clone,
+    {
+        let id = Id::from(bytes);
+
+        let hex = id.to_string();
+        assert_eq!(hex.len(), 24, "{hex:?} should be 24 characters long");
+        assert!(
+            hex.bytes().all(|c| matches!(c, b'0'..=b'9' | b'a'..=b'f')),
+            "{hex:?} should be lowercase hex"
+        );
+        let expected: String = bytes.iter().map(|b| format!("{b:02x}")).collect();
+        assert_eq!(hex, expected);
+
+        let parsed: Id = hex.parse().expect("Displayed id should parse");
+        assert_eq!(Into::<[u8; 12]>::into(parsed), bytes);
+        let parsed: Id = hex
+            .to_uppercase()
+            .parse()
+            .expect("Uppercase id should parse");
+        assert_eq!(Into::<[u8; 12]>::into(parsed), bytes);
+
+        let json = serde_json::to_value(&id).expect("Id should serialize");
+        assert_eq!(json, serde_json::Value::String(hex));
+        let deserialized: Id = serde_json::from_value(json).expect("Id should deserialize");
+        assert_eq!(Into::<[u8; 12]>::into(deserialized), bytes);
+    }
+
+    fn assert_all_round_trip(bytes: [u8; 12]) {
+        assert_round_trip::<ArticleId>(bytes);
+        assert_round_trip::<BookmarkId>(bytes);
+        assert_round_trip::<CommentId>(bytes);
+        assert_round_trip::<ListId>(bytes);
+        assert_round_trip::<NotificationId>(bytes);
+        assert_round_trip::<TagId>(bytes);
+        assert_round_trip::<UserId>(bytes);
+    }
+
+    #[test]
+    fn edge_ids_should_round_trip() {
+        for &bytes in EDGE_BYTES {
+            assert_all_round_trip(bytes);
+        }
+    }
+
+    #[test]
+    fn random_ids_should_round_trip() {
+        let mut rng = StdRng::seed_from_u64(1953);
+
+        for _ in 0..500 {
+            assert_all_round_trip(random_bytes(&mut rng));
+        }
+    }
+
+    #[test]
+    fn random_malformed_ids_should_fail_to_parse() {
+        const NOT_HEX: &[char] = &['g', 'z', 'G', ' ', '-', 'х', 'ї', '０'];
+        let mut rng = StdRng::seed_from_u64(1953);
+
+        for _ in 0..500 {
+            // Arrange
+            let hex = UserId::from(random_bytes(&mut rng)).to_string();
+            let mut chars: Vec<char> = hex.chars().collect();
+            let position = rng.gen_range(0..chars.len());
+            let character = NOT_HEX[rng.gen_range(0..NOT_HEX.len())];
+            chars[position] = character;
+            let bad_char: String = chars.iter().collect();
+            let length = rng.gen_range(0..48);
+            let bad_length: String = hex.chars().cycle().take(length).collect();
+
+            // Act
+            let bad_char = bad_char.parse::<UserId>();
+            let bad_length = bad_length.parse::<UserId>();
+
+            // Assert
+            assert_eq!(
+                bad_char,
+                Err(HexIdParseError::InvalidCharacter {
+                    character,
+                    position
+                })
+            );
+            if length == 24 {
+                assert!(bad_length.is_ok());
+            } else {
+                assert_eq!(bad_length, Err(HexIdParseError::WrongLength(length)));
+            }
+        }
+    }
+}
+
+#[cfg(feature = "test-constructors")]
+mod constructors {
+    use time::macros::datetime;
+    use type_matrux::object::{ArticleId, AsArticleSummary, ShortArticle, TagId, TagSlug, UserId};
+
+    #[test]
+    fn short_article_should_be_constructible_without_json() {
+        // Arrange
+        let tag_id = TagId::from_bytes([1; 12]);
+
+        // Act
+        let article = ShortArticle::new_for_tests(
+            ArticleId::from_bytes([2; 12]),
+            "Заголовок".parse().expect("Title is infallible"),
+            "Опис".parse().expect("Description is infallible"),
+            "zagolovok-a1b2c".parse().expect("Slug is infallible"),
+            UserId::from_bytes([3; 12]),
+            None,
+            "Rust".parse().expect("Tag name is infallible"),
+            "rust".parse::<TagSlug>().expect("Tag slug is infallible"),
+            tag_id.clone(),
+            vec![tag_id],
+            false,
+            42,
+            7,
+            time::Duration::minutes(4),
+            datetime!(2023-09-25 19:30:30 UTC),
+            false,
+        );
+
+        // Assert
+        let summary = article.as_summary();
+        assert_eq!(summary.title().to_string(), "Заголовок");
+        assert_eq!(summary.like_num(), Some(42));
+        assert_eq!(summary.comment_num(), Some(7));
+        assert_eq!(article.tags().len(), 1);
+        assert!(article.get_age() < time::Duration::SECOND);
+    }
+}
+
+mod introspection {
+    use serde_json::Value;
+    use type_matrux::object::{FullArticle, FullList};
+
+    use crate::fixture;
+
+    #[test]
+    fn list_fields_should_reflect_renames() {
+        // Arrange, Act
+        let fields = FullList::FIELDS;
+
+        // Assert
+        assert_eq!(
+            fields,
+            &[
+                ("id", "_id"),
+                ("name", "name"),
+                ("articles_num", "articlesNum"),
+                ("owner", "owner"),
+            ]
+        );
+        assert_eq!(FullList::field_count(), 4);
+    }
+
+    #[test]
+    fn serialized_keys_should_be_listed() {
+        // Arrange
+        let article: FullArticle = fixture("full_article.json");
+
+        // Act
+        let Value::Object(serialized) =
+            serde_json::to_value(&article).expect("Should be able to serialize")
+        else {
+            panic!("Article should serialize into an object");
+        };
+
+        // Assert
+        assert!(FullArticle::FIELDS.contains(&("read_time", "readTime")));
+        for key in serialized.keys() {
+            assert!(
+                FullArticle::FIELDS.iter().any(|(_, serde)| serde == key),
+                "{key} is not listed"
+            );
+        }
+    }
+}
+
+mod credentials {
+    use secrecy::ExposeSecret;
+    use type_matrux::object::{Credentials, LoginIdentifier};
+
+    #[test]
+    fn debug_should_not_leak() {
+        let credentials = Credentials::create("reader@example.com", "hunter2-secret")
+            .expect("Should be a valid email");
+
+        let debug = format!("{credentials:?}");
+
+        assert!(!debug.contains("reader"), "Email leaked: {debug}");
+        assert!(!debug.contains("hunter2"), "Password leaked: {debug}");
+    }
+
+    #[test]
+    fn clone_should_keep_secrets() {
+        let credentials = Credentials::create("reader@example.com", "hunter2-secret")
+            .expect("Should be a valid email");
+
+        let clone = credentials.clone();
+        drop(credentials);
+
+        assert_eq!(clone.identifier().to_string(), "reader@example.com");
+        assert_eq!(clone.password().expose_secret(), "hunter2-secret");
+    }
+
+    #[test]
+    fn username_credentials_should_have_no_email() {
+        let credentials = Credentials::create_with_username(
+            "reader".parse().expect("Should be a valid username"),
+            "hunter2-secret",
+        );
+
+        assert!(credentials.email().is_none());
+        assert!(matches!(
+            credentials.identifier(),
+            LoginIdentifier::Username(username) if username.as_ref() == "reader"
+        ));
+        assert!(!format!("{credentials:?}").contains("reader"));
+    }
+
+    #[test]
+    fn invalid_email_should_be_rejected() {
+        let res = Credentials::create("not an email", "hunter2-secret");
+
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn either_identifier_should_deserialize() {
+        // Act
+        let by_email: Credentials =
+            serde_json::from_str(r#"{"email": "reader@example.com", "password": "secret"}"#)
+                .expect("Should accept email");
+        let by_username: Credentials =
+            serde_json::from_str(r#"{"username": "reader", "password": "secret"}"#)
+                .expect("Should accept username");
+        let bad_email = serde_json::from_str::<Credentials>(
+            r#"{"email": "not an email", "password": "secret"}"#,
+        );
+
+        // Assert
+        assert_eq!(
+            by_email.email().map(|email| email.as_str()),
+            Some("reader@example.com")
+        );
+        assert_eq!(by_username.identifier().to_string(), "reader");
+        assert!(by_username.email().is_none());
+        assert!(bad_email.is_err());
+    }
+}
+
+mod likes {
+    use type_matrux::object::{LikeAmount, LikeAmountError};
+
+    #[test]
+    fn like_amount_should_be_at_most_ten() {
+        // Act
+        let max = LikeAmount::try_from(10);
+        let over = LikeAmount::try_from(11);
+
+        // Assert
+        assert_eq!(max, Ok(LikeAmount::MAX));
+        assert_eq!(over, Err(LikeAmountError(11)));
+        assert_eq!(LikeAmount::saturating(200), LikeAmount::MAX);
+        assert_eq!(LikeAmount::saturating(1), LikeAmount::ONE);
+        assert_eq!(LikeAmount::default(), LikeAmount::ZERO);
+    }
+
+    #[test]
+    fn like_amount_should_serialize_as_number() {
+        let body = serde_json::json!({ "likes": LikeAmount::saturating(3) });
+
+        assert_eq!(body.to_string(), r#"{"likes":3}"#);
+    }
+}
+
+mod timestamps {
+    use serde_json::Value;
+    use time::{format_description::well_known::Rfc3339, macros::datetime, OffsetDateTime};
+    use type_matrux::object::{AuthorArticle, AuthorizedUser};
+
+    use crate::fixture;
+
+    /// Variants site emits, all for the same instant
+    const VARIANTS: &[&str] = &[
+        "2023-09-26T08:00:00.000Z",
+        "2023-09-26T08:00:00Z",
+        "2023-09-26T08:00:00+00:00",
+        "2023-09-26T08:00:00.000+00:00",
+        "2023-09-26T11:00:00.000+03:00",
+        "2023-09-26T08:00:00.000",
+    ];
+
+    fn with_created_at(created_at: &str) -> Result<AuthorArticle, serde_json::Error> {
+        let mut article: Value = fixture("corpus/author_article.json");
+        article["createdAt"] = created_at.into();
+        serde_json::from_value(article)
+    }
+
+    fn with_pin_time(pin_time: Option<Value>) -> Result<AuthorArticle, serde_json::Error> {
+        let mut article: Value = fixture("corpus/author_article.json");
+        match pin_time {
+            Some(pin_time) => article["pinCreatedAt"] = pin_time,
+            None => {
+                article
+                    .as_object_mut()
+                    .expect("Article should be an object")
+                    .remove("pinCreatedAt");
+            }
+        }
+        serde_json::from_value(article)
+    }
+
+    fn serialized_pin_time(article: &AuthorArticle) -> Value {
+        serde_json::to_value(article).expect("Should serialize")["pinCreatedAt"].clone()
+    }
+
+    #[test]
+    fn missing_optional_time_should_be_none() {
+        // Act
+        let null = with_pin_time(Some(Value::Null)).expect("Null should be accepted");
+        let absent = with_pin_time(None).expect("Absent field should be accepted");
+
+        // Assert
+        assert_eq!(serialized_pin_time(&null), Value::Null);
+        assert_eq!(serialized_pin_time(&absent), Value::Null);
+    }
+
+    #[test]
+    fn created_at_should_accept_site_variants() {
+        for source in VARIANTS {
+            // Act
+            let article = with_created_at(source)
+                .unwrap_or_else(|err| panic!("{source} should be accepted: {err}"));
+
+            // Assert
+            assert_eq!(
+                *article.created_at(),
+                datetime!(2023-09-26 08:00 UTC),
+                "{source}"
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_created_at_should_list_attempted_formats() {
+        // Act
+        let err = with_created_at("26.09.2023 08:00").expect_err("Should reject the time");
+
+        // Assert
+        let message = err.to_string();
+        assert!(message.contains("26.09.2023 08:00"), "{message}");
+        assert!(message.contains("RFC 3339"), "{message}");
+        assert!(message.contains("ISO 8601"), "{message}");
+    }
+
+    #[test]
+    fn first_published_at_should_accept_site_variants() {
+        for source in VARIANTS
+            .iter()
+            .copied()
+            .map(Value::from)
+            .chain([Value::Null])
+        {
+            // Arrange
+            let mut user: Value = fixture("users/authorized.json");
+            user["firstPublishedAt"] = source.clone();
+
+            // Act
+            let user: AuthorizedUser = serde_json::from_value(user)
+                .unwrap_or_else(|err| panic!("{source} should be accepted: {err}"));
+
+            // Assert
+            let expected = (!source.is_null()).then_some(datetime!(2023-09-26 08:00 UTC));
+            assert_eq!(*user.first_published_at(), expected, "{source}");
+        }
+    }
+
+    #[test]
+    fn optional_time_should_accept_site_formats() {
+        for source in VARIANTS.iter().copied() {
+            // Act
+            let article = with_pin_time(Some(source.into())).expect("Should be a valid time");
+
+            // Assert
+            let serialized = serialized_pin_time(&article);
+            let pinned_at = OffsetDateTime::parse(
+                serialized.as_str().expect("Should serialize as a string"),
+                &Rfc3339,
+            )
+            .expect("Should serialize as RFC 3339");
+            assert_eq!(pinned_at, datetime!(2023-09-26 08:00 UTC), "{source}");
+        }
+    }
+
+    #[test]
+    fn garbage_optional_time_should_error() {
+        // Act
+        let err = with_pin_time(Some("yesterday".into())).expect_err("Should reject garbage");
+
+        // Assert
+        assert!(err.to_string().contains("yesterday"), "{err}");
+    }
+}
+
+mod flags {
+    use serde_json::Value;
+    use type_matrux::object::{FullArticle, ReplyComment};
+
+    use crate::fixture;
+
+    fn reply_liked(is_liked: Value) -> Result<bool, serde_json::Error> {
+        let mut reply: Value = fixture("corpus/reply_comment.json");
+        reply["isLiked"] = is_liked;
+        serde_json::from_value::<ReplyComment>(reply).map(|reply| *reply.is_liked())
+    }
+
+    #[test]
+    fn is_liked_should_accept_booleans_numbers_and_null() {
+        for (is_liked, expected) in [
+            (Value::from(true), true),
+            (Value::from(false), false),
+            (Value::from(0), false),
+            (Value::from(3), true),
+            (Value::Null, false),
+        ] {
+            // Act
+            let liked = reply_liked(is_liked.clone());
+
+            // Assert
+            assert_eq!(
+                liked.unwrap_or_else(|err| panic!("{is_liked} should be accepted: {err}")),
+                expected,
+                "{is_liked}"
+            );
+        }
+    }
+
+    #[test]
+    fn is_liked_should_reject_other_values() {
+        for is_liked in [Value::from(-1), Value::from("yes")] {
+            assert!(reply_liked(is_liked.clone()).is_err(), "{is_liked}");
+        }
+    }
+
+    #[test]
+    fn article_is_liked_might_be_boolean() {
+        // Arrange
+        let mut article: Value = fixture("full_article.json");
+        article["isLiked"] = true.into();
+
+        // Act
+        let article: FullArticle = serde_json::from_value(article).expect("Should accept boolean");
+
+        // Assert
+        assert!(*article.is_liked());
+    }
+}
+
+mod durations {
+    use serde_json::Value;
+    use type_matrux::object::RecommendedArticle;
+
+    use crate::fixture;
+
+    fn with_read_time(read_time: Value) -> Result<RecommendedArticle, serde_json::Error> {
+        let mut article: Value = fixture("corpus/recommended_article.json");
+        article["readTime"] = read_time;
+        serde_json::from_value(article)
+    }
+
+    #[test]
+    fn read_time_should_accept_whole_and_fractional_seconds() {
+        for (read_time, expected) in [
+            (Value::from(120), time::Duration::minutes(2)),
+            (Value::from(3.5), time::Duration::milliseconds(3500)),
+            (Value::from(0), time::Duration::ZERO),
+        ] {
+            // Act
+            let article = with_read_time(read_time.clone())
+                .unwrap_or_else(|err| panic!("{read_time} should be accepted: {err}"));
+
+            // Assert
+            assert_eq!(*article.read_time(), expected, "{read_time}");
+            let serialized = serde_json::to_value(&article).expect("Should serialize");
+            assert_eq!(serialized["readTime"], read_time);
+        }
+    }
+
+    #[test]
+    fn negative_read_time_should_error() {
+        for read_time in [Value::from(-1), Value::from(-0.5)] {
+            // Act
+            let err = with_read_time(read_time.clone()).expect_err("Should reject negative");
+
+            // Assert
+            assert!(
+                err.to_string().contains("non-negative"),
+                "{read_time}: {err}"
+            );
+        }
+    }
+}
+
+mod relationships {
+    use serde::de::DeserializeOwned;
+    use serde_json::Value;
+    use type_matrux::object::{
+        FollowerUser, FullTag, FullUser, Relationships, ShortUser, TagArticle,
+    };
+
+    use crate::fixture;
+
+    fn with_and_without<T: DeserializeOwned>(name: &str) -> (T, T) {
+        let mut value: Value = fixture(name);
+        let present = serde_json::from_value(value.clone()).expect("Should deserialize");
+        value
+            .as_object_mut()
+            .expect("Fixture should be an object")
+            .remove("relationships")
+            .expect("Fixture should have relationships");
+        let absent =
+            serde_json::from_value(value).expect("Should deserialize without relationships");
+        (present, absent)
+    }
+
+    #[test]
+    fn short_user_should_tolerate_missing_relationships() {
+        // Arrange, Act
+        let (present, absent): (ShortUser, ShortUser) = with_and_without("corpus/short_user.json");
+
+        // Assert
+        assert!(*present.relationships().is_subscribed());
+        assert_eq!(absent.relationships(), &Relationships::default());
+    }
+
+    #[test]
+    fn follower_should_tolerate_missing_relationships() {
+        // Arrange, Act
+        let (present, absent): (FollowerUser, FollowerUser) =
+            with_and_without("corpus/follower_user.json");
+
+        // Assert
+        assert!(*present.relationships().is_subscribed());
+        assert_eq!(absent.relationships(), &Relationships::default());
+    }
+
+    #[test]
+    fn full_user_should_tolerate_missing_relationships() {
+        // Arrange, Act
+        let (present, absent): (FullUser, FullUser) = with_and_without("corpus/full_user.json");
+
+        // Assert
+        assert!(*present.relationships().is_subscribed());
+        assert_eq!(absent.relationships(), &Relationships::default());
+        assert_eq!(absent.username(), present.username());
+    }
+
+    #[test]
+    fn tag_article_should_tolerate_missing_relationships() {
+        // Arrange, Act
+        let (present, absent): (TagArticle, TagArticle) =
+            with_and_without("corpus/tag_article.json");
+
+        // Assert
+        assert!(!*present.relationships().is_subscribed());
+        assert!(!*present.relationships().is_blocked());
+        assert_eq!(absent.relationships(), &Relationships::default());
+        assert_eq!(absent.id(), present.id());
+    }
+
+    #[test]
+    fn full_tag_should_tolerate_missing_relationships() {
+        // Arrange, Act
+        let (present, absent): (FullTag, FullTag) = with_and_without("corpus/full_tag.json");
+
+        // Assert
+        assert!(!*present.relationships().is_blocked());
+        assert_eq!(absent.relationships(), &Relationships::default());
+        assert_eq!(absent.slug(), present.slug());
+    }
+
+    #[test]
+    fn default_relationships_should_be_all_false() {
+        // Act
+        let relationships = Relationships::default();
+
+        // Assert
+        assert!(!*relationships.is_subscribed());
+        assert!(!*relationships.is_blocked());
+    }
+}
@@ -0,0 +1,68 @@
+//! Tests of the object-safe accessor trait.
+#![cfg(feature = "mock")]
+
+use type_matrux::{
+    client::{CachedDrukarnia, DrukarniaRead, Error, MockDrukarnia},
+    object::FullUser,
+    ReqwestApi,
+};
+
+mod support;
+
+use support::fixture;
+
+fn user() -> FullUser {
+    fixture("full_user.json")
+}
+
+/// An example of library code, accepting any accessor
+async fn display_name(api: &dyn DrukarniaRead, user: &FullUser) -> Result<String, Error> {
+    let user = api.get_user(user.username()).await?;
+    Ok(user.name().as_ref().to_owned())
+}
+
+#[tokio::test]
+async fn boxed_accessor_should_get_user() {
+    // Arrange
+    let user = user();
+    let mock = MockDrukarnia::new();
+    mock.expect_user(user.clone());
+    let api: Box<dyn DrukarniaRead> = Box::new(mock.clone());
+
+    // Act
+    let name = display_name(api.as_ref(), &user).await;
+
+    // Assert
+    assert_eq!(
+        name.expect("Should get user"),
+        user.name().as_ref().as_str()
+    );
+    assert_eq!(mock.calls("get_user"), 1);
+}
+
+#[tokio::test]
+async fn accessors_with_different_auth_should_be_stored_together() {
+    // Arrange
+    let user = user();
+    let mock = MockDrukarnia::new();
+    mock.expect_user(user.clone());
+    let accessors: Vec<Box<dyn DrukarniaRead>> = vec![
+        Box::new(ReqwestApi::new()),
+        Box::new(mock.clone()),
+        Box::new(CachedDrukarnia::new(mock.clone(), time::Duration::MINUTE)),
+    ];
+
+    // Act
+    let mut names = Vec::new();
+    for api in &accessors[1..] {
+        names.push(display_name(api.as_ref(), &user).await);
+    }
+
+    // Assert
+    assert_eq!(
+        accessors[0].base_url().as_str(),
+        "https://drukarnia.com.ua/"
+    );
+    assert!(names.iter().all(Result::is_ok));
+    assert_eq!(mock.calls("get_user"), 2);
+}
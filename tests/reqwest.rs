@@ -5,7 +5,9 @@ use reqwest::Client;
 use tracing::{subscriber::set_global_default, Subscriber};
 use tracing_bunyan_formatter::{BunyanFormattingLayer, JsonStorageLayer};
 use tracing_subscriber::{fmt::MakeWriter, prelude::__tracing_subscriber_SubscriberExt, Registry};
-use type_matrux::{client::AuthDrukarnia, object::Credentials, DrukarniaApi};
+use type_matrux::{
+    client::AuthDrukarnia, client::BaseApi, id_hex, object::Credentials, DrukarniaClient,
+};
 
 macro_rules! auth_guard {
     () => {
@@ -16,6 +18,8 @@ macro_rules! auth_guard {
     };
 }
 
+mod support;
+
 fn nonzero_one() -> NonZeroUsize {
     NonZeroUsize::new(1).expect("1 != 0")
 }
@@ -30,41 +34,44 @@ fn get_credentials() -> Credentials {
 
 // TODO add verification for these "static claims"
 fn get_existing_user_id() -> type_matrux::object::UserId {
-    static EXISTING_USER_ID_BYTES: [u8; 12] = [
-        0x64, 0x3a, 0xf9, 0xfc, 0x12, 0x72, 0xbd, 0x90, 0x66, 0xa1, 0xff, 0xdb,
-    ];
-
-    unsafe { std::mem::transmute(EXISTING_USER_ID_BYTES) }
+    id_hex!(type_matrux::object::UserId, "643af9fc1272bd9066a1ffdb")
 }
 
 fn get_existing_article_id() -> type_matrux::object::ArticleId {
-    static EXISTING_ARTICLE_ID_BYTES: [u8; 12] = [
-        0x65, 0x11, 0xe0, 0x36, 0x28, 0x0f, 0x44, 0x21, 0x02, 0x5f, 0x09, 0xfd,
-    ];
-
-    unsafe { std::mem::transmute(EXISTING_ARTICLE_ID_BYTES) }
+    id_hex!(type_matrux::object::ArticleId, "6511e036280f4421025f09fd")
 }
 
 fn get_existing_comment_id() -> type_matrux::object::CommentId {
-    // 651ae7dc280f4421026b12c5
-    static EXISTING_COMMENT_ID_BYTES: [u8; 12] = [
-        0x65, 0x1a, 0xe7, 0xdc, 0x28, 0x0f, 0x44, 0x21, 0x02, 0x6b, 0x12, 0xc5,
-    ];
-
-    unsafe { std::mem::transmute(EXISTING_COMMENT_ID_BYTES) }
+    id_hex!(type_matrux::object::CommentId, "651ae7dc280f4421026b12c5")
 }
 
+const NON_EXISTING_ARTICLE_ID: type_matrux::object::ArticleId =
+    id_hex!(type_matrux::object::ArticleId, "8811e036000f4421115f09fd");
+
+const NON_EXISTING_LIST_ID: type_matrux::object::ListId =
+    id_hex!(type_matrux::object::ListId, "8811e036000f4421115f09fd");
+
 fn get_non_existing_comment_id() -> type_matrux::object::CommentId {
-    static NON_EXISTING_COMMENT_ID_BYTES: [u8; 12] = [
-        0x65, 0x1a, 0x00, 0xdc, 0x28, 0x0f, 0x00, 0x21, 0x02, 0x00, 0x12, 0xc5,
-    ];
+    id_hex!(type_matrux::object::CommentId, "651a00dc280f0021020012c5")
+}
 
-    unsafe { std::mem::transmute(NON_EXISTING_COMMENT_ID_BYTES) }
+/// Accessor data representation tests call the API with
+///
+/// Set `DRUKARNIA_BASE_URL` to run them against a mirror or a mock server, instead of the site.
+fn api() -> DrukarniaClient {
+    match std::env::var("DRUKARNIA_BASE_URL") {
+        Ok(base_url) => DrukarniaClient::with_base_url(
+            base_url
+                .parse()
+                .expect("DRUKARNIA_BASE_URL should be a valid url"),
+        ),
+        Err(_) => DrukarniaClient::from(Client::new()),
+    }
 }
 
 async fn get_auth() -> type_matrux::client::ReqwestAuth {
     let valid_credentials = get_credentials();
-    let client = Client::new();
+    let client = api();
     client
         .login(valid_credentials)
         .await
@@ -119,11 +126,17 @@ fn setup_log() {
 mod data_representation {
 
     use futures::{StreamExt, TryStreamExt};
-    use reqwest::Client;
-    use type_matrux::{client::AuthDrukarnia, DrukarniaApi};
+    use type_matrux::{
+        client::{
+            ArticlesApi, AuthDrukarnia, BaseApi, CommentsApi, FeedApi, ReqwestAuth, TagsApi,
+            UsersApi,
+        },
+        object::{FeedArticle, LikeAmount},
+        DrukarniaApi,
+    };
 
     use crate::{
-        get_auth, get_auth_list_id, get_credentials, get_existing_article_id,
+        api, get_auth, get_auth_list_id, get_credentials, get_existing_article_id,
         get_existing_comment_id, get_existing_user_id, nonzero_one, setup_log,
     };
 
@@ -131,7 +144,7 @@ mod data_representation {
     async fn popular_tags_should_succeed() {
         setup_log();
         // Arrange
-        let client = Client::new();
+        let client = api();
 
         // Act
         let popular_tags = client.popular_tags().await;
@@ -149,7 +162,7 @@ mod data_representation {
         setup_log();
         // Arrange
         static EXISTING_USER: &str = "OstanniyCapitalist";
-        let client = Client::new();
+        let client = api();
 
         // Act
         let user_name = EXISTING_USER.parse().expect("Should be a valid username");
@@ -163,12 +176,31 @@ mod data_representation {
         );
     }
 
+    #[tokio::test]
+    async fn get_author_portfolio_should_succeed() {
+        setup_log();
+        // Arrange
+        static EXISTING_USER: &str = "OstanniyCapitalist";
+        let client = api();
+
+        // Act
+        let user_name = EXISTING_USER.parse().expect("Should be a valid username");
+        let portfolio = client.get_author_portfolio(&user_name).await;
+
+        // Assert
+        assert!(
+            portfolio.is_ok(),
+            "Should be able to get author portfolio: {}",
+            portfolio.unwrap_err()
+        );
+    }
+
     #[tokio::test]
     async fn search_users_should_succeed() {
         setup_log();
         // Arrange
         static VALID_USER_QUERY: &str = "Poroshenko";
-        let client = Client::new();
+        let client = api();
 
         // Act
         let user_query = VALID_USER_QUERY
@@ -189,7 +221,7 @@ mod data_representation {
         setup_log();
         // Arrange
         static POPULAR_USER_QUERY: &str = "Іван";
-        let client = Client::new();
+        let client = api();
 
         // Act
         let user_query = POPULAR_USER_QUERY
@@ -214,7 +246,7 @@ mod data_representation {
         setup_log();
         // Arrange
         static EXISTING_TAG_SLUG: &str = "istoriya";
-        let client = Client::new();
+        let client = api();
 
         // Act
         let tag_slug = EXISTING_TAG_SLUG.parse().expect("Should be valid tag slug");
@@ -234,7 +266,7 @@ mod data_representation {
         // Arrange
         static EXISTING_ARTICLE_SLUG: &str =
             "gitlab-istoriya-uspikhu-ukrayinskogo-konkurenta-github-t7agP";
-        let client = Client::new();
+        let client = api();
 
         // Act
         let article_slug = EXISTING_ARTICLE_SLUG
@@ -250,13 +282,109 @@ mod data_representation {
         );
     }
 
-    // FIXME MISSING FIELD _id
+    #[tokio::test]
+    async fn get_article_by_url_should_succeed() {
+        setup_log();
+        // Arrange
+        static EXISTING_ARTICLE_URL: &str =
+            "https://drukarnia.com.ua/articles/gitlab-istoriya-uspikhu-ukrayinskogo-konkurenta-github-t7agP";
+        let client = api();
+
+        // Act
+        let url = EXISTING_ARTICLE_URL.parse().expect("Should be valid url");
+        let article = client.get_article_by_url(&url).await;
+
+        // Assert
+        assert!(
+            article.is_ok(),
+            "Should be able to get article by url: {}",
+            article.unwrap_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn download_article_cover_should_succeed() {
+        setup_log();
+        // Arrange
+        static EXISTING_ARTICLE_SLUG: &str =
+            "gitlab-istoriya-uspikhu-ukrayinskogo-konkurenta-github-t7agP";
+        let client = api();
+        let article_slug = EXISTING_ARTICLE_SLUG
+            .parse()
+            .expect("Should be valid article slug");
+        let article = client
+            .get_article(&article_slug)
+            .await
+            .expect("Should be able to get article");
+        let thumbnail = article
+            .thumb_picture()
+            .as_ref()
+            .expect("Article should have a thumbnail");
+
+        // Act
+        let image = client.download_image(thumbnail).await;
+
+        // Assert
+        let image = image.expect("Should be able to download thumbnail");
+        assert!(!image.bytes().is_empty(), "Image should not be empty");
+        assert_eq!(
+            image.content_type().as_ref().map(mime::Mime::type_),
+            Some(mime::IMAGE),
+            "Should be an image"
+        );
+    }
+
+    #[tokio::test]
+    async fn get_article_thread_should_succeed() {
+        setup_log();
+        // Arrange
+        static EXISTING_ARTICLE_SLUG: &str =
+            "gitlab-istoriya-uspikhu-ukrayinskogo-konkurenta-github-t7agP";
+        let client = api();
+
+        // Act
+        let article_slug = EXISTING_ARTICLE_SLUG
+            .parse()
+            .expect("Should be valid article slug");
+        let thread = client.get_article_thread(&article_slug).await;
+
+        // Assert
+        assert!(
+            thread.is_ok(),
+            "Should be able to get article thread by slug: {}",
+            thread.unwrap_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn get_article_with_replies_should_succeed() {
+        setup_log();
+        // Arrange
+        static EXISTING_ARTICLE_SLUG: &str =
+            "gitlab-istoriya-uspikhu-ukrayinskogo-konkurenta-github-t7agP";
+        let client = api();
+
+        // Act
+        let article_slug = EXISTING_ARTICLE_SLUG
+            .parse()
+            .expect("Should be valid article slug");
+        let article = client.get_article_with_replies(&article_slug, 4).await;
+
+        // Assert
+        let article = article.expect("Should be able to get article with replies");
+        assert!(
+            article.partial_errors().is_empty(),
+            "Should be able to get all of the replies: {:?}",
+            article.partial_errors()
+        );
+    }
+
     #[tokio::test]
     async fn search_article_should_succeed() {
         setup_log();
         // Arrange
         static VALID_ARTICLE_NAME: &str = "Дія";
-        let client = Client::new();
+        let client = api();
 
         // Act
         let article_name = VALID_ARTICLE_NAME
@@ -278,7 +406,7 @@ mod data_representation {
     async fn get_followers_should_succeed() {
         setup_log();
         // Arrange
-        let client = Client::new();
+        let client = api();
         let existing_user_id = get_existing_user_id();
 
         // Act
@@ -298,7 +426,7 @@ mod data_representation {
     async fn get_replies_should_succeed() {
         setup_log();
         // Arrange
-        let client = Client::new();
+        let client = api();
         let existing_comment_id = get_existing_comment_id();
 
         // Act
@@ -316,7 +444,7 @@ mod data_representation {
     async fn get_feed_should_succeed() {
         setup_log();
         // Arrange
-        let client = Client::new();
+        let client = api();
 
         // Act
         let feed_articles = client.feed_page(nonzero_one()).await;
@@ -333,7 +461,7 @@ mod data_representation {
     async fn get_feed_flat_100_should_succeed() {
         setup_log();
         // Arrange
-        let client = Client::new();
+        let client = api();
 
         // Act
         let feed = client.feed().flat();
@@ -348,13 +476,34 @@ mod data_representation {
         );
     }
 
+    #[tokio::test]
+    async fn feed_within_session_should_be_stable() {
+        setup_log();
+        // Arrange
+        let client = api();
+        let session = client
+            .feed_session()
+            .await
+            .expect("Should be able to establish feed session");
+
+        // Act
+        let first = client.feed_page_with(&session, nonzero_one()).await;
+        let second = client.feed_page_with(&session, nonzero_one()).await;
+
+        // Assert
+        let ids = |page: Vec<FeedArticle>| page.iter().map(|a| a.id().clone()).collect::<Vec<_>>();
+        let first = ids(first.expect("Should be able to get feed"));
+        let second = ids(second.expect("Should be able to get feed"));
+        assert_eq!(first, second, "Same page within a session should match");
+    }
+
     #[tokio::test]
     async fn login_should_succeed() {
         setup_log();
         auth_guard!();
         // Arrange
         let valid_credentials = get_credentials();
-        let client = Client::new();
+        let client = api();
 
         // Act
         let auth = client.login(valid_credentials).await;
@@ -368,7 +517,6 @@ mod data_representation {
     }
 
     #[tokio::test]
-    // TODO make a coherence test
     async fn follow_should_succeed() {
         setup_log();
         auth_guard!();
@@ -389,7 +537,6 @@ mod data_representation {
     }
 
     #[tokio::test]
-    // TODO make a coherence test
     async fn nollow_should_succeed() {
         setup_log();
         auth_guard!();
@@ -408,6 +555,90 @@ mod data_representation {
         );
     }
 
+    #[tokio::test]
+    async fn session_should_resume_from_token() {
+        setup_log();
+        auth_guard!();
+        // Arrange
+        let auth = get_auth().await;
+        let token = auth.session_token().clone();
+        let user = auth.authorized_user().clone();
+
+        // Act
+        let resumed = ReqwestAuth::from_token(api(), user, token)
+            .await
+            .expect("Should be able to resume a session");
+        let res = resumed.get_bookmark_lists().await;
+
+        // Assert
+        assert!(
+            res.is_ok(),
+            "Should be able to get bookmarks: {:?}",
+            res.unwrap_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn downgraded_client_should_call_anonymously() {
+        setup_log();
+        auth_guard!();
+        // Arrange
+        let auth = get_auth().await;
+
+        // Act
+        let (client, _, _) = auth.into_parts();
+        let res = client.popular_tags().await;
+
+        // Assert
+        assert!(
+            res.is_ok(),
+            "Should be able to get popular tags: {:?}",
+            res.unwrap_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn session_should_survive_splitting() {
+        setup_log();
+        auth_guard!();
+        // Arrange
+        let auth = get_auth().await;
+
+        // Act
+        let (client, user, token) = auth.into_parts();
+        let resumed = ReqwestAuth::from_token(client, user, token).await;
+
+        // Assert
+        assert!(
+            resumed.is_ok(),
+            "Split session should not be logged out: {:?}",
+            resumed.unwrap_err()
+        );
+    }
+
+    #[cfg(feature = "session-store")]
+    #[tokio::test]
+    async fn session_should_survive_saving() {
+        setup_log();
+        auth_guard!();
+        // Arrange
+        let auth = get_auth().await;
+        let path = std::env::temp_dir().join(format!("type-matrux-{}.json", std::process::id()));
+
+        // Act
+        auth.save_session(&path)
+            .expect("Should be able to save session");
+        let stored = std::fs::read_to_string(&path).expect("Should be able to read session");
+        let resumed = ReqwestAuth::restore_session(api(), &path).await;
+        std::fs::remove_file(&path).ok();
+
+        // Assert
+        let password = secrecy::ExposeSecret::expose_secret(get_credentials().password()).clone();
+        assert!(!stored.contains(&password), "Password should not be stored");
+        let resumed = resumed.expect("Should be able to restore session");
+        assert!(resumed.get_bookmark_lists().await.is_ok());
+    }
+
     #[tokio::test]
     async fn get_bookmarks_should_succeed() {
         setup_log();
@@ -493,7 +724,9 @@ mod data_representation {
         let existing_article_id = get_existing_article_id();
 
         // Act
-        let res = auth.like_article(&existing_article_id, 1).await;
+        let res = auth
+            .like_article(&existing_article_id, LikeAmount::ONE)
+            .await;
 
         // Assert
         assert!(
@@ -512,7 +745,7 @@ mod data_representation {
         let existing_article_id = get_existing_article_id();
 
         // Act
-        let res = auth.like_article(&existing_article_id, 0).await; // TODO not really sure what this like num thing means
+        let res = auth.unlike_article(&existing_article_id).await;
 
         // Assert
         assert!(
@@ -522,6 +755,60 @@ mod data_representation {
         );
     }
 
+    #[tokio::test]
+    async fn like_amount_should_be_set_not_added() {
+        setup_log();
+        auth_guard!();
+        // Arrange
+        static EXISTING_ARTICLE_SLUG: &str =
+            "gitlab-istoriya-uspikhu-ukrayinskogo-konkurenta-github-t7agP";
+        let auth = get_auth().await;
+        let slug = EXISTING_ARTICLE_SLUG
+            .parse()
+            .expect("Should be valid article slug");
+        let like_num = || async {
+            auth.get_article(&slug)
+                .await
+                .expect("Should be able to get article")
+                .like_num()
+                .to_owned()
+        };
+        let id = auth
+            .get_article(&slug)
+            .await
+            .expect("Should be able to get article")
+            .id()
+            .clone();
+        auth.unlike_article(&id)
+            .await
+            .expect("Should be able to unlike an article");
+        let unliked = like_num().await;
+
+        // Act
+        auth.like_article(&id, LikeAmount::ONE)
+            .await
+            .expect("Should be able to like an article");
+        let liked_once = like_num().await;
+        auth.like_article(&id, LikeAmount::ONE)
+            .await
+            .expect("Should be able to like an article");
+        let liked_twice = like_num().await;
+        auth.like_article(&id, LikeAmount::MAX)
+            .await
+            .expect("Should be able to like an article");
+        let liked_max = like_num().await;
+        auth.unlike_article(&id)
+            .await
+            .expect("Should be able to unlike an article");
+        let unliked_again = like_num().await;
+
+        // Assert
+        assert_eq!(liked_once, unliked + 1);
+        assert_eq!(liked_twice, liked_once, "Same amount should not add up");
+        assert_eq!(liked_max, unliked + usize::from(LikeAmount::MAX.get()));
+        assert_eq!(unliked_again, unliked);
+    }
+
     #[tokio::test]
     async fn like_comment_should_succeed() {
         setup_log();
@@ -565,6 +852,36 @@ mod data_representation {
             res.unwrap_err()
         );
     }
+
+    #[tokio::test]
+    async fn comment_like_should_toggle_count() {
+        setup_log();
+        auth_guard!();
+        // Arrange
+        let auth = get_auth().await;
+        let comment = get_existing_comment_id();
+        let article = get_existing_article_id();
+        let unliked = auth
+            .set_comment_liked(&article, &comment, false)
+            .await
+            .expect("Should be able to unlike a comment");
+
+        // Act
+        let liked = auth
+            .set_comment_liked(&article, &comment, true)
+            .await
+            .expect("Should be able to like a comment");
+        let unliked_again = auth
+            .set_comment_liked(&article, &comment, false)
+            .await
+            .expect("Should be able to unlike a comment");
+
+        // Assert
+        assert!(!unliked.is_liked());
+        assert!(liked.is_liked());
+        assert_eq!(*liked.likes_num(), unliked.likes_num() + 1);
+        assert_eq!(unliked_again, unliked);
+    }
 }
 
 /// This group of tests attempts various malformed queries to site API and check that they indeed return an error
@@ -575,13 +892,13 @@ mod data_representation {
 mod error_representation {
     use reqwest::Client;
     use type_matrux::{
-        client::{AuthDrukarnia, Error},
-        object::Credentials,
-        DrukarniaApi,
+        client::{ArticlesApi, AuthDrukarnia, BaseApi, CommentsApi, Error, TagsApi, UsersApi},
+        object::{Credentials, LikeAmount, MaybeUrl},
     };
 
     use crate::{
-        get_auth, get_auth_list_id, get_existing_article_id, get_non_existing_comment_id, setup_log,
+        get_auth, get_auth_list_id, get_existing_article_id, get_non_existing_comment_id,
+        setup_log, NON_EXISTING_ARTICLE_ID, NON_EXISTING_LIST_ID,
     };
 
     #[tokio::test]
@@ -608,6 +925,28 @@ mod error_representation {
         );
     }
 
+    #[tokio::test]
+    async fn download_invalid_image_url_should_error() {
+        setup_log();
+        // Arrange
+        let client = Client::new();
+        let url: MaybeUrl =
+            serde_json::from_str("\"images/avatar.png\"").expect("Should be a string");
+
+        // Act
+        let image = client.download_image(&url).await;
+
+        // Assert
+        let real = image.expect_err("Should not allow downloading an invalid url");
+        let exp = Error::InvalidUrl(String::new(), String::new());
+        assert!(
+            std::mem::discriminant(&real) == std::mem::discriminant(&exp),
+            "Error type is not correct. Real: {}, Exp: {}",
+            real,
+            exp
+        );
+    }
+
     #[tokio::test]
     async fn get_non_existing_tag_should_error() {
         setup_log();
@@ -705,15 +1044,12 @@ mod error_representation {
 
     #[tokio::test]
     async fn non_exiting_article_bookmark_should_error() {
-        static NON_EXISTING_ARTICLE_ID_BYTES: [u8; 12] = [
-            0x88, 0x11, 0xe0, 0x36, 0x00, 0x0f, 0x44, 0x21, 0x11, 0x5f, 0x09, 0xfd,
-        ];
         setup_log();
         auth_guard!();
         // Arrange
         let auth = get_auth().await;
         let list_id = get_auth_list_id(&auth).await;
-        let non_exiting_article_id = unsafe { std::mem::transmute(NON_EXISTING_ARTICLE_ID_BYTES) };
+        let non_exiting_article_id = NON_EXISTING_ARTICLE_ID;
 
         // Act
         let res = auth
@@ -746,15 +1082,12 @@ mod error_representation {
 
     #[tokio::test]
     async fn non_exiting_list_bookmark_should_error() {
-        static NON_EXISTING_LIST_ID_BYTES: [u8; 12] = [
-            0x88, 0x11, 0xe0, 0x36, 0x00, 0x0f, 0x44, 0x21, 0x11, 0x5f, 0x09, 0xfd,
-        ];
         setup_log();
         auth_guard!();
         // Arrange
         let auth = get_auth().await;
         let exiting_article_id = get_existing_article_id();
-        let non_exiting_list_id = unsafe { std::mem::transmute(NON_EXISTING_LIST_ID_BYTES) };
+        let non_exiting_list_id = NON_EXISTING_LIST_ID;
 
         // Act
         let res = auth
@@ -787,14 +1120,11 @@ mod error_representation {
 
     #[tokio::test]
     async fn get_non_exiting_list_articles_should_error() {
-        static NON_EXISTING_LIST_ID_BYTES: [u8; 12] = [
-            0x88, 0x11, 0xe0, 0x36, 0x00, 0x0f, 0x44, 0x21, 0x11, 0x5f, 0x09, 0xfd,
-        ];
         setup_log();
         auth_guard!();
         // Arrange
         let auth = get_auth().await;
-        let non_exiting_list_id = unsafe { std::mem::transmute(NON_EXISTING_LIST_ID_BYTES) };
+        let non_exiting_list_id = NON_EXISTING_LIST_ID;
 
         // Act
         let res = auth.get_list_articles(&non_exiting_list_id).await;
@@ -812,17 +1142,16 @@ mod error_representation {
 
     #[tokio::test]
     async fn like_non_existing_article_should_error() {
-        static NON_EXISTING_ARTICLE_ID_BYTES: [u8; 12] = [
-            0x88, 0x11, 0xe0, 0x36, 0x00, 0x0f, 0x44, 0x21, 0x11, 0x5f, 0x09, 0xfd,
-        ];
         setup_log();
         auth_guard!();
         // Arrange
         let auth = get_auth().await;
-        let non_existing_article_id = unsafe { std::mem::transmute(NON_EXISTING_ARTICLE_ID_BYTES) };
+        let non_existing_article_id = NON_EXISTING_ARTICLE_ID;
 
         // Act
-        let res = auth.like_article(&non_existing_article_id, 1).await;
+        let res = auth
+            .like_article(&non_existing_article_id, LikeAmount::ONE)
+            .await;
 
         // Assert
         let real = res.expect_err("Should not allow like non existing article");
@@ -837,17 +1166,14 @@ mod error_representation {
 
     #[tokio::test]
     async fn unlike_non_existing_article_should_error() {
-        static NON_EXISTING_ARTICLE_ID_BYTES: [u8; 12] = [
-            0x88, 0x11, 0xe0, 0x36, 0x00, 0x0f, 0x44, 0x21, 0x11, 0x5f, 0x09, 0xfd,
-        ];
         setup_log();
         auth_guard!();
         // Arrange
         let auth = get_auth().await;
-        let non_existing_article_id = unsafe { std::mem::transmute(NON_EXISTING_ARTICLE_ID_BYTES) };
+        let non_existing_article_id = NON_EXISTING_ARTICLE_ID;
 
         // Act
-        let res = auth.like_article(&non_existing_article_id, 0).await;
+        let res = auth.unlike_article(&non_existing_article_id).await;
 
         // Assert
         let real = res.expect_err("Should not allow unlike non existing article");
@@ -926,7 +1252,7 @@ mod structure_enforcement {
     use futures::StreamExt;
     use rand::seq::IteratorRandom;
     use reqwest::Client;
-    use type_matrux::DrukarniaApi;
+    use type_matrux::client::{ArticlesApi, FeedApi, TagsApi, UsersApi};
 
     use crate::setup_log;
 
@@ -982,7 +1308,7 @@ mod structure_enforcement {
             let full_tag = client.get_tag(&tag_slug).await;
             match full_tag {
                 Ok(full_tag) => {
-                    articles.extend(full_tag.articles().into_iter().map(|a| a.slug().clone()))
+                    articles.extend(full_tag.articles().iter().map(|a| a.slug().clone()))
                 }
                 Err(err) => {
                     error_count += 1;
@@ -1103,7 +1429,163 @@ mod structure_enforcement {
 /// The idea is to prevent calls that take unexpected effect, or better yet -- take no effect despite success responses
 ///
 /// None is expecting any sort of error
-mod coherence {}
+///
+/// Each test undoes it's writes with a [`Defer`](crate::support::Defer) guard, so that a failed assertion does not leave them behind.
+mod coherence {
+    use futures::StreamExt;
+    use type_matrux::{
+        client::{ArticlesApi, AuthDrukarnia, ReqwestAuth, UsersApi},
+        object::{ArticleId, ArticleSlug, LikeAmount, ListId, UserId},
+    };
+
+    use crate::{
+        get_auth, get_auth_list_id, get_existing_article_id, get_existing_user_id, setup_log,
+        support::{verify_eventually, Defer, POLL_INTERVAL, TIMEOUT},
+    };
+
+    async fn is_following(auth: &ReqwestAuth, user: &UserId) -> bool {
+        let me = auth.authorized_user().id();
+        auth.get_followers(user.clone())
+            .flat()
+            .any(|follower| async move { follower.is_ok_and(|follower| follower.id() == Some(me)) })
+            .await
+    }
+
+    async fn is_bookmarked(auth: &ReqwestAuth, list: &ListId, article: &ArticleId) -> bool {
+        auth.get_list_articles(list)
+            .await
+            .expect("Should be able to get listed articles")
+            .iter()
+            .any(|listed| listed.id() == article)
+    }
+
+    async fn like_num(auth: &ReqwestAuth, slug: &ArticleSlug) -> usize {
+        *auth
+            .get_article(slug)
+            .await
+            .expect("Should be able to get article")
+            .like_num()
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn follow_should_be_visible_among_followers() {
+        setup_log();
+        auth_guard!();
+        // Arrange
+        let auth = get_auth().await;
+        let user = get_existing_user_id();
+        let _unfollow = Defer::new(async {
+            let _ = auth.user_set_following(&user, false).await;
+        });
+
+        // Act
+        auth.user_set_following(&user, true)
+            .await
+            .expect("Should be able to follow");
+        let followed =
+            verify_eventually(|| is_following(&auth, &user), TIMEOUT, POLL_INTERVAL).await;
+        auth.user_set_following(&user, false)
+            .await
+            .expect("Should be able to unfollow");
+        let unfollowed = verify_eventually(
+            || async { !is_following(&auth, &user).await },
+            TIMEOUT,
+            POLL_INTERVAL,
+        )
+        .await;
+
+        // Assert
+        assert!(followed, "Should be listed among followers");
+        assert!(unfollowed, "Should not be listed among followers anymore");
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn bookmark_should_be_visible_in_list() {
+        setup_log();
+        auth_guard!();
+        // Arrange
+        let auth = get_auth().await;
+        let list = get_auth_list_id(&auth).await;
+        let article = get_existing_article_id();
+        let _unbookmark = Defer::new(async {
+            let _ = auth.unbookmark_article(&article).await;
+        });
+
+        // Act
+        auth.bookmark_article(&list, &article)
+            .await
+            .expect("Should be able to bookmark an article");
+        let bookmarked = verify_eventually(
+            || is_bookmarked(&auth, &list, &article),
+            TIMEOUT,
+            POLL_INTERVAL,
+        )
+        .await;
+        auth.unbookmark_article(&article)
+            .await
+            .expect("Should be able to unbookmark an article");
+        let unbookmarked = verify_eventually(
+            || async { !is_bookmarked(&auth, &list, &article).await },
+            TIMEOUT,
+            POLL_INTERVAL,
+        )
+        .await;
+
+        // Assert
+        assert!(bookmarked, "Should be listed");
+        assert!(unbookmarked, "Should not be listed anymore");
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn like_should_change_like_num() {
+        setup_log();
+        auth_guard!();
+        // Arrange
+        static EXISTING_ARTICLE_SLUG: &str =
+            "gitlab-istoriya-uspikhu-ukrayinskogo-konkurenta-github-t7agP";
+        let auth = get_auth().await;
+        let slug: ArticleSlug = EXISTING_ARTICLE_SLUG
+            .parse()
+            .expect("Should be valid article slug");
+        let article = auth
+            .get_article(&slug)
+            .await
+            .expect("Should be able to get article")
+            .id()
+            .clone();
+        let _unlike = Defer::new(async {
+            let _ = auth.unlike_article(&article).await;
+        });
+        auth.unlike_article(&article)
+            .await
+            .expect("Should be able to unlike an article");
+        let before = like_num(&auth, &slug).await;
+
+        // Act
+        auth.like_article(&article, LikeAmount::ONE)
+            .await
+            .expect("Should be able to like an article");
+        let liked = verify_eventually(
+            || async { like_num(&auth, &slug).await == before + 1 },
+            TIMEOUT,
+            POLL_INTERVAL,
+        )
+        .await;
+        auth.unlike_article(&article)
+            .await
+            .expect("Should be able to unlike an article");
+        let unliked = verify_eventually(
+            || async { like_num(&auth, &slug).await == before },
+            TIMEOUT,
+            POLL_INTERVAL,
+        )
+        .await;
+
+        // Assert
+        assert!(liked, "Like should be counted");
+        assert!(unliked, "Like should not be counted anymore");
+    }
+}
 
 /// This group of tests aim to ensure that returned data is interpreted correctly
 ///
@@ -1115,8 +1597,8 @@ mod correctness {
     use reqwest::Client;
     use time::{Date, Duration, Month};
     use type_matrux::{
+        client::{ArticlesApi, TagsApi, UsersApi},
         object::{ArticleSlug, TagSlug},
-        DrukarniaApi,
     };
 
     use crate::setup_log;
@@ -1190,5 +1672,62 @@ mod correctness {
     }
 }
 
+/// This group of tests aim to ensure that JSON kept with `raw-json` feature is the one objects were parsed from
+///
+/// None is expecting any sort of error
+#[cfg(feature = "raw-json")]
+mod raw_json {
+    use reqwest::Client;
+    use type_matrux::{
+        client::{ArticlesApi, TagsApi},
+        object::{ArticleSlug, FullArticle, PopularTag},
+    };
+
+    use crate::setup_log;
+
+    #[tokio::test]
+    async fn article_raw_json_should_match() {
+        // Arrange
+        setup_log();
+        let article_slug: ArticleSlug =
+            "gitlab-istoriya-uspikhu-ukrayinskogo-konkurenta-github-t7agP"
+                .parse()
+                .expect("Should be valid article slug");
+        let client = Client::new();
+
+        // Act
+        let article = client
+            .get_article(&article_slug)
+            .await
+            .expect("Should be able to get article");
+
+        // Assert
+        let raw = article.raw_json().expect("Raw json should be attached");
+        let reparsed: FullArticle = serde_json::from_str(raw).expect("Raw json should parse");
+        assert_eq!(reparsed, article);
+        assert!(article.comments().iter().all(|c| c.raw_json().is_none()));
+    }
+
+    #[tokio::test]
+    async fn page_elements_raw_json_should_match() {
+        // Arrange
+        setup_log();
+        let client = Client::new();
+
+        // Act
+        let tags = client
+            .popular_tags()
+            .await
+            .expect("Should be able to get popular tags");
+
+        // Assert
+        for tag in tags {
+            let raw = tag.raw_json().expect("Raw json should be attached");
+            let reparsed: PopularTag = serde_json::from_str(raw).expect("Raw json should parse");
+            assert_eq!(reparsed, tag);
+        }
+    }
+}
+
 /// Other sort of tests I couldn't categorize
 mod other {}
@@ -0,0 +1,244 @@
+#![cfg(feature = "rss")]
+
+use time::{format_description::well_known::Rfc2822, OffsetDateTime};
+use type_matrux::{
+    export::rss::{author_feed, tag_feed},
+    object::{FullTag, FullUser},
+};
+use url::Url;
+
+mod support;
+
+use support::{fixture, fixture_edited};
+
+fn base() -> Url {
+    "https://drukarnia.com.ua/"
+        .parse()
+        .expect("Should be a valid url")
+}
+
+/// Element of a parsed XML document
+#[derive(Debug, Default)]
+struct Element {
+    name: String,
+    text: String,
+    children: Vec<Element>,
+}
+
+impl Element {
+    fn child(&self, name: &str) -> Option<&Element> {
+        self.children.iter().find(|child| child.name == name)
+    }
+
+    fn children<'e>(&'e self, name: &'e str) -> impl Iterator<Item = &'e Element> {
+        self.children.iter().filter(move |child| child.name == name)
+    }
+}
+
+/// Decodes text, allowing only the predefined XML entities and character references
+fn decode(text: &str) -> String {
+    let mut res = String::new();
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        res.push_str(&rest[..start]);
+        let end = rest[start..]
+            .find(';')
+            .expect("Entity should be terminated")
+            + start;
+        let entity = &rest[start + 1..end];
+        let c = match entity {
+            "amp" => '&',
+            "lt" => '<',
+            "gt" => '>',
+            "quot" => '"',
+            "apos" => '\'',
+            _ => entity
+                .strip_prefix('#')
+                .and_then(|code| code.parse().ok())
+                .and_then(char::from_u32)
+                .unwrap_or_else(|| panic!("Entity {entity} is not defined in XML")),
+        };
+        res.push(c);
+        rest = &rest[end + 1..];
+    }
+    assert!(!rest.contains(['<', '>']), "Text should be escaped: {rest}");
+    res.push_str(rest);
+    res
+}
+
+/// Parses a well-formed XML document, without attributes and namespaces, panicking otherwise
+fn parse(xml: &str) -> Element {
+    let xml = xml
+        .strip_prefix("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n")
+        .expect("Should start with XML declaration");
+    let mut stack = vec![Element::default()];
+    let mut rest = xml;
+    while let Some(start) = rest.find('<') {
+        let text = decode(&rest[..start]);
+        stack
+            .last_mut()
+            .expect("Should be inside of an element")
+            .text += &text;
+        let end = rest[start..].find('>').expect("Tag should be closed") + start;
+        let tag = &rest[start + 1..end];
+        rest = &rest[end + 1..];
+        if let Some(name) = tag.strip_prefix('/') {
+            let element = stack.pop().expect("Should close an open element");
+            assert_eq!(element.name, name, "Elements should be balanced");
+            stack
+                .last_mut()
+                .expect("Should not close the root")
+                .children
+                .push(element);
+        } else {
+            let name = tag.split_whitespace().next().expect("Tag should be named");
+            stack.push(Element {
+                name: name.to_owned(),
+                ..Default::default()
+            });
+        }
+    }
+    assert!(rest.trim().is_empty(), "Should not have trailing text");
+    let mut document = stack.pop().expect("Should have a document");
+    assert!(stack.is_empty(), "All of the elements should be closed");
+    assert_eq!(document.children.len(), 1, "Should have a single root");
+    document.children.remove(0)
+}
+
+/// Checks elements required by RSS 2.0, returning the channel
+fn check_rss(xml: &str) -> Element {
+    let rss = parse(xml);
+    assert_eq!(rss.name, "rss");
+    assert!(xml.contains("<rss version=\"2.0\">"), "Should be RSS 2.0");
+    assert_eq!(
+        rss.children("channel").count(),
+        1,
+        "Should have a single channel"
+    );
+    let channel = rss.child("channel").expect("Checked");
+    for required in ["title", "link", "description"] {
+        assert!(
+            channel.child(required).is_some(),
+            "Channel should have {required}"
+        );
+    }
+    Url::parse(&channel.child("link").expect("Checked").text).expect("Link should be a url");
+    for item in channel.children("item") {
+        assert!(
+            item.child("title").is_some() || item.child("description").is_some(),
+            "Item should have title or description"
+        );
+        Url::parse(&item.child("link").expect("Item should have a link").text)
+            .expect("Link should be a url");
+        if let Some(date) = item.child("pubDate") {
+            OffsetDateTime::parse(&date.text, &Rfc2822).expect("Date should be RFC 2822");
+        }
+    }
+    rss.children
+        .into_iter()
+        .find(|child| child.name == "channel")
+        .expect("Checked")
+}
+
+fn titles(channel: &Element) -> Vec<&str> {
+    channel
+        .children("item")
+        .map(|item| item.child("title").expect("Checked").text.as_str())
+        .collect()
+}
+
+#[test]
+fn author_feed_should_be_valid() {
+    // Arrange
+    let user: FullUser = fixture("full_user.json");
+
+    // Act
+    let feed = author_feed(&user, user.articles(), &base());
+
+    // Assert
+    let channel = check_rss(&feed);
+    assert_eq!(
+        channel.child("title").expect("Checked").text,
+        "Остап Капіталіст"
+    );
+    assert_eq!(
+        channel.child("link").expect("Checked").text,
+        "https://drukarnia.com.ua/@OstanniyCapitalist"
+    );
+    assert_eq!(
+        titles(&channel),
+        [
+            "GitLab: історія успіху українського конкурента GitHub",
+            "Rust для початківців"
+        ]
+    );
+}
+
+#[test]
+fn tag_feed_should_be_valid() {
+    // Arrange
+    let tag: FullTag = fixture("tags/full_tag.json");
+
+    // Act
+    let feed = tag_feed(&tag, tag.articles(), &base());
+
+    // Assert
+    let channel = check_rss(&feed);
+    assert_eq!(
+        channel.child("link").expect("Checked").text,
+        "https://drukarnia.com.ua/tags/tekhnologiyi"
+    );
+    let newest = channel.child("item").expect("Should have items");
+    assert_eq!(
+        newest.child("title").expect("Checked").text,
+        "Rust & C++: «порівняння» <без> упереджень",
+        "Newest article should go first, with title escaped"
+    );
+    assert_eq!(
+        newest.child("link").expect("Checked").text,
+        "https://drukarnia.com.ua/articles/rust-and-cpp-x1Yz2"
+    );
+    assert_eq!(
+        newest.child("pubDate").expect("Checked").text,
+        "Mon, 02 Oct 2023 08:15:00 +0000"
+    );
+}
+
+#[test]
+fn empty_feed_should_be_valid() {
+    // Arrange
+    let user: FullUser = fixture("full_user.json");
+
+    // Act
+    let feed = author_feed(&user, &[], &base());
+
+    // Assert
+    let channel = check_rss(&feed);
+    assert!(channel.child("item").is_none());
+}
+
+#[test]
+fn unrepresentable_dates_should_be_left_out() {
+    // Arrange
+    let user: FullUser = fixture_edited("full_user.json", |value| {
+        value["articles"][0]["createdAt"] = "1899-12-31T00:00:00.000Z".into();
+    });
+
+    // Act
+    let feed = author_feed(&user, user.articles(), &base());
+
+    // Assert
+    let channel = check_rss(&feed);
+    let dates: Vec<_> = channel
+        .children("item")
+        .map(|item| item.child("pubDate").map(|date| date.text.as_str()))
+        .collect();
+    assert_eq!(dates, [Some("Wed, 20 Sep 2023 10:00:00 +0000"), None]);
+    assert_eq!(
+        channel
+            .child("lastBuildDate")
+            .expect("Should have a date")
+            .text,
+        "Wed, 20 Sep 2023 10:00:00 +0000"
+    );
+}
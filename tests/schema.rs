@@ -0,0 +1,74 @@
+//! Offline tests for schema version tracking.
+//!
+//! Schema versions are recorded globally, so these live in a separate test binary, away from other fixtures.
+
+use std::{
+    path::Path,
+    sync::{Arc, Mutex},
+};
+
+use tracing::{
+    field::{Field, Visit},
+    Event, Level, Subscriber,
+};
+use tracing_subscriber::{layer::Context, prelude::*, Layer, Registry};
+use type_matrux::object::{schema_versions, PopularTag};
+
+/// Collects `kind` field of every warning
+#[derive(Clone, Default)]
+struct WarningKinds(Arc<Mutex<Vec<String>>>);
+
+impl<S: Subscriber> Layer<S> for WarningKinds {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        struct KindVisitor(Option<String>);
+
+        impl Visit for KindVisitor {
+            fn record_str(&mut self, field: &Field, value: &str) {
+                if field.name() == "kind" {
+                    self.0 = Some(value.to_owned());
+                }
+            }
+
+            fn record_debug(&mut self, _field: &Field, _value: &dyn std::fmt::Debug) {}
+        }
+
+        if *event.metadata().level() == Level::WARN {
+            let mut visitor = KindVisitor(None);
+            event.record(&mut visitor);
+            if let Some(kind) = visitor.0 {
+                self.0.lock().unwrap().push(kind);
+            }
+        }
+    }
+}
+
+fn popular_tag(name: &str) -> PopularTag {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures/schema")
+        .join(name);
+    let text = std::fs::read_to_string(path).expect("Should be able to read fixture");
+    serde_json::from_str(&text).expect("Fixture should deserialize")
+}
+
+#[test]
+fn schema_change_should_warn_once() {
+    // Arrange
+    let warnings = WarningKinds::default();
+    let subscriber = Registry::default().with(warnings.clone());
+
+    // Act
+    tracing::subscriber::with_default(subscriber, || {
+        popular_tag("popular_tag_v0.json");
+        popular_tag("popular_tag_v0.json");
+        popular_tag("popular_tag_v1.json");
+        popular_tag("popular_tag_v1.json");
+    });
+
+    // Assert
+    let warnings = warnings.0.lock().unwrap();
+    assert_eq!(*warnings, ["type_matrux::object::tag::Popular"]);
+    assert_eq!(
+        schema_versions().get("type_matrux::object::tag::Popular"),
+        Some(&1)
+    );
+}
@@ -0,0 +1,74 @@
+//! Offline tests for session persistence.
+//!
+//! These never reach the site: stored sessions are rejected before token validation.
+#![cfg(feature = "session-store")]
+
+use std::path::PathBuf;
+
+use reqwest::Client;
+use type_matrux::client::{Error, ReqwestAuth};
+
+mod support;
+
+use support::fixture;
+
+/// Unique path in a temporary directory, removed on drop
+struct TempFile(PathBuf);
+
+impl TempFile {
+    fn new(name: &str) -> Self {
+        Self(std::env::temp_dir().join(format!("type-matrux-{}-{name}", std::process::id())))
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        std::fs::remove_file(&self.0).ok();
+    }
+}
+
+fn stored_session(token: &str) -> String {
+    let user: serde_json::Value = fixture("users/authorized.json");
+    serde_json::json!({ "token": token, "user": user }).to_string()
+}
+
+#[tokio::test]
+async fn missing_file_should_error() {
+    // Arrange
+    let file = TempFile::new("missing.json");
+
+    // Act
+    let res = ReqwestAuth::restore_session(Client::new(), &file.0).await;
+
+    // Assert
+    let err = res.expect_err("Should not restore a missing session");
+    assert!(matches!(err, Error::SessionFile(_)), "{err}");
+}
+
+#[tokio::test]
+async fn malformed_file_should_error() {
+    // Arrange
+    let file = TempFile::new("malformed.json");
+    std::fs::write(&file.0, r#"{"token": "token=abc"}"#).expect("Should be able to write");
+
+    // Act
+    let res = ReqwestAuth::restore_session(Client::new(), &file.0).await;
+
+    // Assert
+    let err = res.expect_err("Should not restore a session without user");
+    assert!(matches!(err, Error::BadJson(..)), "{err}");
+}
+
+#[tokio::test]
+async fn empty_token_should_not_be_trusted() {
+    // Arrange
+    let file = TempFile::new("empty.json");
+    std::fs::write(&file.0, stored_session("")).expect("Should be able to write");
+
+    // Act
+    let res = ReqwestAuth::restore_session(Client::new(), &file.0).await;
+
+    // Assert
+    let err = res.expect_err("Should not restore a session without token");
+    assert!(matches!(err, Error::NoToken), "{err}");
+}
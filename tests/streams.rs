@@ -0,0 +1,218 @@
+//! Offline tests for the paged streams, over a stub accessor.
+//!
+//! Stub serves three search pages of distinct articles, and might be told to fail once.
+
+use std::{
+    num::NonZeroUsize,
+    sync::atomic::{AtomicBool, AtomicUsize, Ordering},
+};
+
+use async_trait::async_trait;
+use futures::StreamExt;
+use type_matrux::{
+    client::{ArticlesApi, BaseApi, Error, ImageBytes, StreamCheckpoint},
+    object::{ArticleSlug, ArticleTitle, FullArticle, MaybeUrl, RecommendedArticle},
+};
+
+mod support;
+
+use support::fixture_edited;
+
+const PAGES: usize = 3;
+
+fn search_page(index: usize) -> Vec<RecommendedArticle> {
+    fixture_edited("search/recommended_page.json", |value| {
+        for (position, article) in value
+            .as_array_mut()
+            .expect("Fixture should be a page")
+            .iter_mut()
+            .enumerate()
+        {
+            article["slug"] = format!("article-{index}-{position}").into();
+        }
+    })
+}
+
+#[derive(Default)]
+struct PagedStub {
+    requests: AtomicUsize,
+    /// Fails the next request, if set
+    fail: AtomicBool,
+}
+
+#[async_trait]
+impl BaseApi for PagedStub {
+    type Auth = ();
+}
+
+#[async_trait]
+impl ArticlesApi for PagedStub {
+    async fn get_article(&self, _slug: &ArticleSlug) -> Result<FullArticle, Error> {
+        unimplemented!()
+    }
+
+    async fn search_article_page(
+        &self,
+        _name: &ArticleTitle,
+        page: NonZeroUsize,
+    ) -> Result<Vec<RecommendedArticle>, Error> {
+        self.requests.fetch_add(1, Ordering::SeqCst);
+        if self.fail.swap(false, Ordering::SeqCst) {
+            return Err(Error::NoObject);
+        }
+        if page.get() <= PAGES {
+            Ok(search_page(page.get()))
+        } else {
+            Ok(vec![])
+        }
+    }
+
+    async fn download_image(&self, _url: &MaybeUrl) -> Result<ImageBytes, Error> {
+        unimplemented!()
+    }
+}
+
+fn title() -> ArticleTitle {
+    serde_json::from_str("\"Git\"").expect("Should be a valid title")
+}
+
+fn slugs(articles: Vec<Result<RecommendedArticle, Error>>) -> Vec<String> {
+    articles
+        .into_iter()
+        .map(|article| article.expect("Should fetch an article").slug().to_string())
+        .collect()
+}
+
+fn page(index: usize) -> NonZeroUsize {
+    NonZeroUsize::new(index).expect("Page index should not be zero")
+}
+
+#[tokio::test]
+async fn flat_stream_should_resume_from_the_middle_of_a_page() {
+    // Arrange
+    let api = PagedStub::default();
+    let all = slugs(api.search_article(title()).flat().collect().await);
+    let mut stream = api.search_article(title()).flat();
+    let mut consumed = Vec::new();
+    for _ in 0..4 {
+        consumed.push(stream.next().await.expect("Should have an article"));
+    }
+    let checkpoint = stream.checkpoint();
+    drop(stream);
+
+    // Act
+    let stored = serde_json::to_string(&checkpoint).expect("Should serialize checkpoint");
+    let checkpoint: StreamCheckpoint =
+        serde_json::from_str(&stored).expect("Should deserialize checkpoint");
+    let rest: Vec<_> = api
+        .search_article(title())
+        .resume_from(checkpoint)
+        .flat()
+        .collect()
+        .await;
+
+    // Assert
+    assert_eq!(checkpoint, StreamCheckpoint::new(page(2), 1));
+    let mut resumed = slugs(consumed);
+    resumed.extend(slugs(rest));
+    assert_eq!(resumed, all);
+}
+
+#[tokio::test]
+async fn flat_stream_should_resume_at_page_boundary() {
+    // Arrange
+    let api = PagedStub::default();
+    let all = slugs(api.search_article(title()).flat().collect().await);
+    let mut stream = api.search_article(title()).flat();
+    let mut consumed = Vec::new();
+    for _ in 0..3 {
+        consumed.push(stream.next().await.expect("Should have an article"));
+    }
+    let checkpoint = stream.checkpoint();
+    drop(stream);
+    let requests_before = api.requests.load(Ordering::SeqCst);
+
+    // Act
+    let rest: Vec<_> = api
+        .search_article(title())
+        .resume_from(checkpoint)
+        .flat()
+        .collect()
+        .await;
+
+    // Assert
+    assert_eq!(checkpoint, StreamCheckpoint::new(page(2), 0));
+    // second, third and an empty page, but not the first one
+    assert_eq!(api.requests.load(Ordering::SeqCst) - requests_before, 3);
+    let mut resumed = slugs(consumed);
+    resumed.extend(slugs(rest));
+    assert_eq!(resumed, all);
+}
+
+#[tokio::test]
+async fn page_stream_should_report_progress() {
+    // Arrange
+    let api = PagedStub::default();
+    let mut stream = api.search_article(title());
+
+    // Act
+    let before = stream.checkpoint();
+    stream.next().await;
+    let after = stream.checkpoint();
+
+    // Assert
+    assert_eq!(before, StreamCheckpoint::new(page(1), 0));
+    assert_eq!(after, StreamCheckpoint::new(page(2), 0));
+    assert_eq!(stream.current_page(), page(2));
+    assert!(!stream.has_errored());
+}
+
+#[tokio::test]
+async fn crawl_should_resume_after_an_error() {
+    // Arrange
+    let api = PagedStub::default();
+    let all = slugs(api.search_article(title()).flat().collect().await);
+    let mut stream = api.search_article(title()).flat();
+    let mut consumed = Vec::new();
+    for _ in 0..3 {
+        consumed.push(stream.next().await.expect("Should have an article"));
+    }
+    api.fail.store(true, Ordering::SeqCst);
+
+    // Act
+    let failed = stream.next().await;
+    let ended = stream.next().await;
+    let checkpoint = stream.checkpoint();
+    let rest: Vec<_> = api
+        .search_article(title())
+        .resume_from(checkpoint)
+        .flat()
+        .collect()
+        .await;
+
+    // Assert
+    assert!(matches!(failed, Some(Err(Error::NoObject))));
+    assert!(ended.is_none());
+    assert!(stream.has_errored());
+    let mut resumed = slugs(consumed);
+    resumed.extend(slugs(rest));
+    assert_eq!(resumed, all);
+}
+
+#[tokio::test]
+async fn offset_past_the_page_should_skip_it() {
+    // Arrange
+    let api = PagedStub::default();
+    let all = slugs(api.search_article(title()).flat().collect().await);
+
+    // Act
+    let rest: Vec<_> = api
+        .search_article(title())
+        .resume_from(StreamCheckpoint::new(page(1), 10))
+        .flat()
+        .collect()
+        .await;
+
+    // Assert
+    assert_eq!(slugs(rest), all[3..]);
+}
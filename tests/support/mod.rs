@@ -0,0 +1,98 @@
+//! Helpers shared by the test binaries: reading checked-in fixtures, and verifying the writes to the site.
+//!
+//! Every binary only uses some of these, so unused ones are not reported.
+#![allow(dead_code)]
+
+use std::{
+    future::Future,
+    panic::AssertUnwindSafe,
+    path::PathBuf,
+    pin::Pin,
+    time::{Duration, Instant},
+};
+
+use futures::FutureExt;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use tokio::runtime::Handle;
+
+/// Reads a fixture from `tests/fixtures/`
+pub fn fixture_text(name: &str) -> String {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(name);
+    std::fs::read_to_string(&path)
+        .unwrap_or_else(|err| panic!("Should be able to read fixture {}: {err}", path.display()))
+}
+
+/// Deserializes a fixture from `tests/fixtures/`
+pub fn fixture<T: DeserializeOwned>(name: &str) -> T {
+    serde_json::from_str(&fixture_text(name))
+        .unwrap_or_else(|err| panic!("Fixture {name} should deserialize: {err}"))
+}
+
+/// Same as [`fixture`], but lets `edit` the JSON before deserializing it
+pub fn fixture_edited<T: DeserializeOwned>(name: &str, edit: impl FnOnce(&mut Value)) -> T {
+    let mut value: Value = serde_json::from_str(&fixture_text(name))
+        .unwrap_or_else(|err| panic!("Fixture {name} should be JSON: {err}"));
+    edit(&mut value);
+    serde_json::from_value(value)
+        .unwrap_or_else(|err| panic!("Fixture {name} should deserialize: {err}"))
+}
+
+/// Time site is given to reflect a write in it's read endpoints
+pub const TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Time between the read attempts
+pub const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Polls `check` until it holds, or `timeout` passes
+///
+/// Site might serve stale data for a while after a write, so a single read is not enough to tell if the write took effect.
+///
+/// # Returns
+/// Whether the check held before the timeout. Check is always performed at least once.
+pub async fn verify_eventually<F, Fut>(check: F, timeout: Duration, poll_interval: Duration) -> bool
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = bool>,
+{
+    let deadline = Instant::now() + timeout;
+    loop {
+        if check().await {
+            return true;
+        }
+        if Instant::now() >= deadline {
+            return false;
+        }
+        tokio::time::sleep(poll_interval).await;
+    }
+}
+
+/// Undoes a write once dropped, even if test panics before that
+///
+/// Undo is driven to completion right in the `drop`, so the guard should only be used within
+/// `#[tokio::test(flavor = "multi_thread")]` tests. Undo should be idempotent, since the test might
+/// have undone the write itself. If undo panics, the panic is swallowed, so that a failing test is not aborted.
+pub struct Defer<'a> {
+    undo: Option<Pin<Box<dyn Future<Output = ()> + 'a>>>,
+}
+
+impl<'a> Defer<'a> {
+    /// Creates a guard, that will run `undo` once dropped
+    pub fn new(undo: impl Future<Output = ()> + 'a) -> Self {
+        Self {
+            undo: Some(Box::pin(undo)),
+        }
+    }
+}
+
+impl Drop for Defer<'_> {
+    fn drop(&mut self) {
+        if let Some(undo) = self.undo.take() {
+            tokio::task::block_in_place(|| {
+                let _ = Handle::current().block_on(AssertUnwindSafe(undo).catch_unwind());
+            });
+        }
+    }
+}